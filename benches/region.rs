@@ -0,0 +1,22 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use idcard::region;
+
+fn bench_query(c: &mut Criterion) {
+    let codes = ["110101", "330106", "511702", "654325", "999999"];
+    c.bench_function("region::query", |b| {
+        b.iter(|| {
+            for code in codes.iter() {
+                black_box(region::query(black_box(code)));
+            }
+        })
+    });
+}
+
+fn bench_rand_code_starts_with(c: &mut Criterion) {
+    c.bench_function("region::rand_code_starts_with", |b| {
+        b.iter(|| black_box(region::rand_code_starts_with(black_box("3301"))))
+    });
+}
+
+criterion_group!(benches, bench_query, bench_rand_code_starts_with);
+criterion_main!(benches);
@@ -0,0 +1,310 @@
+//! Generates the region tables from `data/regions.csv`, or from the file that
+//! `IDCARD_REGION_DATA` points to. Only the codes are generated with
+//! `region-minimal` alone, and no table at all without either feature. The
+//! coordinates are only generated with `geo`.
+//!
+//! With `ffi`, the C header is generated from `src/ffi.rs` into `OUT_DIR`
+//! too, the checked-in `include/idcard.h` is a copy of it, which is verified
+//! by the tests of the `ffi` module.
+//!
+//! The file is a CSV with the header
+//! `code,name,pinyin,postcode,area_code,lat,lng`, one region per line, e.g.
+//! `511702,四川省达州市通川区,tong chuan qu,,,,`. The columns after the name are
+//! optional, the pinyin is only used by `region::search` and the
+//! romanization. The postcodes are only given for the cities, and the regions
+//! without an area code or the coordinates fall back to their ancestors'.
+//!
+//! The history of the codes is generated from `data/region_history.csv`, or
+//! from the file that `IDCARD_REGION_HISTORY` points to, with the header
+//! `code,name,valid_from,valid_to,status,successor`, one name of a code per
+//! line, e.g. `632123,青海省海东地区乐都县,,2013,renamed,630202`. The name was
+//! in use from `valid_from`(empty if it predates the dataset) until the year
+//! before `valid_to`. The status is what became of the code after the last
+//! name, `renamed`, `merged`(both followed by the successor) or `abolished`,
+//! and it's empty for the earlier names and the codes still in use.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DATA_ENV: &str = "IDCARD_REGION_DATA";
+const DEFAULT_DATA: &str = "data/regions.csv";
+const HISTORY_ENV: &str = "IDCARD_REGION_HISTORY";
+const DEFAULT_HISTORY: &str = "data/region_history.csv";
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+
+    let names = env::var_os("CARGO_FEATURE_REGION_NAMES").is_some();
+    let codes = names || env::var_os("CARGO_FEATURE_REGION_MINIMAL").is_some();
+    let geo = env::var_os("CARGO_FEATURE_GEO").is_some();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("region_data.rs");
+    if !codes {
+        fs::write(&out, generate(&[], &[], false, false)).unwrap();
+        return;
+    }
+
+    let path = data_path(DATA_ENV, DEFAULT_DATA);
+    let data = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let mut regions = parse(&data).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    regions.sort_by_key(|r| r.code);
+    if let Some(w) = regions.windows(2).find(|w| w[0].code == w[1].code) {
+        panic!("{}: duplicate code {}", path.display(), w[0].code);
+    }
+
+    let path = data_path(HISTORY_ENV, DEFAULT_HISTORY);
+    let data = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let mut history = parse_history(&data).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    history.sort_by_key(|r| (r.code, r.valid_to));
+
+    fs::write(&out, generate(&regions, &history, names, geo)).unwrap();
+}
+
+/// Returns the path of a data file, which is overridden by the environment
+/// variable.
+fn data_path(var: &str, default: &str) -> PathBuf {
+    println!("cargo:rerun-if-env-changed={}", var);
+    let path = match env::var_os(var) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(default),
+    };
+    println!("cargo:rerun-if-changed={}", path.display());
+    path
+}
+
+/// Generates `idcard.h` in `OUT_DIR` by cbindgen.
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    let config = cbindgen::Config {
+        language: cbindgen::Language::C,
+        include_guard: Some("IDCARD_H".to_owned()),
+        header: Some(
+            "/*\n * C bindings of the idcard crate, built with\n * `cargo rustc --features ffi --crate-type cdylib`.\n *\n * The strings are NUL-terminated UTF-8, the strings returned must be released\n * by `idcard_free_string`.\n */".to_owned(),
+        ),
+        autogen_warning: Some(
+            "/* Generated by cbindgen from src/ffi.rs, do not edit. */".to_owned(),
+        ),
+        cpp_compat: true,
+        documentation_style: cbindgen::DocumentationStyle::C,
+        ..Default::default()
+    };
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(dir.join("src/ffi.rs"))
+        .generate()
+        .expect("failed to generate the C header")
+        .write_to_file(Path::new(&env::var("OUT_DIR").unwrap()).join("idcard.h"));
+}
+
+/// A line of the data file.
+struct Row {
+    code: u32,
+    name: String,
+    pinyin: String,
+    postcode: String,
+    area_code: String,
+    centroid: Option<(f64, f64)>,
+}
+
+/// Parses the lines into the rows.
+fn parse(data: &str) -> Result<Vec<Row>, String> {
+    let mut regions = Vec::new();
+    for (i, line) in data.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (i == 0 && line.starts_with("code")) {
+            continue;
+        }
+        let mut fields = line.splitn(7, ',').map(str::trim);
+        let code = fields.next().unwrap_or_default();
+        let name = fields.next().unwrap_or_default();
+        let pinyin = fields.next().unwrap_or_default();
+        let postcode = fields.next().unwrap_or_default();
+        let area_code = fields.next().unwrap_or_default();
+        let lat = fields.next().unwrap_or_default();
+        let lng = fields.next().unwrap_or_default();
+        if code.len() != 6 || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("line {}: invalid code {:?}", i + 1, code));
+        }
+        if name.is_empty() {
+            return Err(format!("line {}: missing name", i + 1));
+        }
+        if !postcode.is_empty() && !postcode.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("line {}: invalid postcode {:?}", i + 1, postcode));
+        }
+        if !area_code.is_empty() && !area_code.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("line {}: invalid area code {:?}", i + 1, area_code));
+        }
+        let centroid = match (lat, lng) {
+            ("", "") => None,
+            _ => match (lat.parse(), lng.parse()) {
+                (Ok(lat), Ok(lng)) => Some((lat, lng)),
+                _ => return Err(format!("line {}: invalid coordinates", i + 1)),
+            },
+        };
+        regions.push(Row {
+            code: code.parse().unwrap(),
+            name: name.to_owned(),
+            pinyin: pinyin.to_owned(),
+            postcode: postcode.to_owned(),
+            area_code: area_code.to_owned(),
+            centroid,
+        });
+    }
+    Ok(regions)
+}
+
+/// A line of the history file.
+struct HistoryRow {
+    code: u32,
+    name: String,
+    valid_to: u32,
+    status: String,
+}
+
+/// Parses the lines of the history file into the rows, the status is turned
+/// into the expression of `CodeStatus`.
+fn parse_history(data: &str) -> Result<Vec<HistoryRow>, String> {
+    let mut history = Vec::new();
+    for (i, line) in data.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (i == 0 && line.starts_with("code")) {
+            continue;
+        }
+        let mut fields = line.splitn(6, ',').map(str::trim);
+        let code = fields.next().unwrap_or_default();
+        let name = fields.next().unwrap_or_default();
+        let valid_from = fields.next().unwrap_or_default();
+        let valid_to = fields.next().unwrap_or_default();
+        let status = fields.next().unwrap_or_default();
+        let successor = fields.next().unwrap_or_default();
+        let is_code = |s: &str| s.len() == 6 && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_code(code) {
+            return Err(format!("line {}: invalid code {:?}", i + 1, code));
+        }
+        if name.is_empty() {
+            return Err(format!("line {}: missing name", i + 1));
+        }
+        let valid_to: u32 = valid_to
+            .parse()
+            .map_err(|_| format!("line {}: invalid year {:?}", i + 1, valid_to))?;
+        if !valid_from.is_empty() && valid_from.parse().map_or(true, |y: u32| y >= valid_to) {
+            return Err(format!("line {}: invalid year {:?}", i + 1, valid_from));
+        }
+        let status = match (status, successor) {
+            ("", "") => "CodeStatus::Active".to_owned(),
+            ("abolished", "") => "CodeStatus::Abolished".to_owned(),
+            ("renamed", next) if is_code(next) && next != code => {
+                format!("CodeStatus::Renamed({:?})", next)
+            }
+            ("merged", next) if is_code(next) && next != code => {
+                format!("CodeStatus::MergedInto({:?})", next)
+            }
+            _ => return Err(format!("line {}: invalid status {:?}", i + 1, status)),
+        };
+        history.push(HistoryRow {
+            code: code.parse().unwrap(),
+            name: name.to_owned(),
+            valid_to,
+            status,
+        });
+    }
+    Ok(history)
+}
+
+/// Generates the tables, only the codes are filled unless `names` is set, and
+/// the coordinates are only generated if `geo` is set.
+fn generate(regions: &[Row], history: &[HistoryRow], names: bool, geo: bool) -> String {
+    let len = regions.len();
+    let names_len = if names { len } else { 0 };
+    let mut out = String::new();
+    out.push_str("/// All the region codes concatenated in ascending order.\n");
+    out.push_str("pub(super) static CODES: &str = \"");
+    for r in regions {
+        write!(out, "{:06}", r.code).unwrap();
+    }
+    out.push_str("\";\n\n");
+
+    out.push_str("/// The numeric values of the codes, `KEYS[i]` is the i-th code in `CODES`.\n");
+    writeln!(out, "pub(super) static KEYS: [u32; {}] = [", len).unwrap();
+    for r in regions {
+        writeln!(out, "    {},", r.code).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("/// The region names, `NAMES[i]` is the name of the i-th code in `CODES`.\n");
+    writeln!(out, "pub(super) static NAMES: [&str; {}] = [", names_len).unwrap();
+    for r in &regions[..names_len] {
+        writeln!(out, "    {:?},", r.name).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("/// The pinyin of the region names without their ancestors.\n");
+    writeln!(out, "pub(super) static PINYIN: [&str; {}] = [", names_len).unwrap();
+    for r in &regions[..names_len] {
+        writeln!(out, "    {:?},", r.pinyin).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("/// The postcodes of the cities, empty for the other regions.\n");
+    writeln!(
+        out,
+        "pub(super) static POSTCODES: [&str; {}] = [",
+        names_len
+    )
+    .unwrap();
+    for r in &regions[..names_len] {
+        writeln!(out, "    {:?},", r.postcode).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("/// The telephone area codes, empty if the region shares its ancestor's.\n");
+    writeln!(
+        out,
+        "pub(super) static AREA_CODES: [&str; {}] = [",
+        names_len
+    )
+    .unwrap();
+    for r in &regions[..names_len] {
+        writeln!(out, "    {:?},", r.area_code).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("/// The names of the abolished(or reused) codes before the given year and what\n/// became of them, sorted by code and year. A code is looked up here before\n/// the current table when the year is earlier.\n");
+    writeln!(
+        out,
+        "pub(super) static HISTORY: [(&str, u32, &str, CodeStatus); {}] = [",
+        history.len()
+    )
+    .unwrap();
+    for r in history {
+        let name = if names { r.name.as_str() } else { "" };
+        writeln!(
+            out,
+            "    (\"{:06}\", {}, {:?}, {}),",
+            r.code, r.valid_to, name, r.status
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+
+    if geo {
+        out.push_str("\n/// The approximate coordinates(latitude, longitude) of the seats, `None` if\n/// the region shares its ancestor's.\n");
+        writeln!(
+            out,
+            "pub(super) static CENTROIDS: [Option<(f64, f64)>; {}] = [",
+            len
+        )
+        .unwrap();
+        for r in regions {
+            writeln!(out, "    {:?},", r.centroid).unwrap();
+        }
+        out.push_str("];\n");
+    }
+    out
+}
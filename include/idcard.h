@@ -0,0 +1,82 @@
+/*
+ * C bindings of the idcard crate, built with
+ * `cargo rustc --features ffi --crate-type cdylib`.
+ *
+ * The strings are NUL-terminated UTF-8, the strings returned must be released
+ * by `idcard_free_string`.
+ */
+
+#ifndef IDCARD_H
+#define IDCARD_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ Any gender, for `idcard_fake`.
+ */
+#define IDCARD_GENDER_ANY 0
+
+/*
+ Male, for `idcard_fake`.
+ */
+#define IDCARD_GENDER_MALE 1
+
+/*
+ Female, for `idcard_fake`.
+ */
+#define IDCARD_GENDER_FEMALE 2
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Checks if the 15/18-digit number is valid, NULL is invalid.
+
+ # Safety
+
+ `number` must be NULL or a valid NUL-terminated string.
+ */
+bool idcard_validate(const char *number);
+
+/*
+ Returns the detailed information of the number as a JSON string, or NULL if
+ the number is invalid.
+
+ # Safety
+
+ `number` must be NULL or a valid NUL-terminated string.
+ */
+char *idcard_info_json(const char *number);
+
+/*
+ Generates a fake number, or returns NULL if the options are invalid. The
+ `region` may be NULL, the `gender` is one of the `IDCARD_GENDER_*`, and a
+ `min_year` or `max_year` of 0 means no limit.
+
+ # Safety
+
+ `region` must be NULL or a valid NUL-terminated string.
+ */
+char *idcard_fake(const char *region, int gender, uint32_t min_year, uint32_t max_year);
+
+/*
+ Releases a string returned by this library, NULL is ignored.
+
+ # Safety
+
+ `s` must be NULL or a string returned by this library which has not been
+ released yet.
+ */
+void idcard_free_string(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* IDCARD_H */
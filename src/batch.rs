@@ -0,0 +1,188 @@
+//! Utilities for validating ID numbers in batches
+
+use crate::{normalize, validate_detailed, Identity, IdentityInfo, ValidationError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// The validation result of a single ID number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// The original input.
+    pub input: String,
+    /// The normalized number, `None` if the normalization changed nothing.
+    pub normalized: Option<String>,
+    /// Whether the number is valid.
+    pub valid: bool,
+    /// The reason of the failure if the number is invalid.
+    pub reason: Option<ValidationError>,
+}
+
+impl ValidationReport {
+    /// Validates the given number and creates a report.
+    pub fn new(input: &str) -> Self {
+        let normalized = normalize(input);
+        let result = validate_detailed(&normalized);
+        ValidationReport {
+            input: input.to_owned(),
+            normalized: if normalized != input {
+                Some(normalized)
+            } else {
+                None
+            },
+            valid: result.is_ok(),
+            reason: result.err(),
+        }
+    }
+}
+
+/// The aggregate statistics of a batch of validation reports.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// The number of the inputs.
+    pub total: usize,
+    /// The number of the valid inputs.
+    pub valid: usize,
+    /// The number of the invalid inputs.
+    pub invalid: usize,
+    /// The number of the inputs which were changed by the normalization.
+    pub normalized: usize,
+    /// The number of the invalid inputs grouped by the failure reason.
+    pub reasons: HashMap<ValidationError, usize>,
+}
+
+impl Summary {
+    /// Aggregates the given reports.
+    pub fn from_reports(reports: &[ValidationReport]) -> Self {
+        let mut summary = Summary::default();
+        for report in reports {
+            summary.add(report);
+        }
+        summary
+    }
+
+    /// Adds a report to the statistics.
+    pub fn add(&mut self, report: &ValidationReport) {
+        self.total += 1;
+        if report.valid {
+            self.valid += 1;
+        } else {
+            self.invalid += 1;
+        }
+        if report.normalized.is_some() {
+            self.normalized += 1;
+        }
+        if let Some(reason) = report.reason {
+            *self.reasons.entry(reason).or_insert(0) += 1;
+        }
+    }
+}
+
+/// Validates all the given numbers and returns a report for each of them, in
+/// the same order as the input.
+pub fn validate_all<'a, I>(iter: I) -> Vec<ValidationReport>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    iter.into_iter().map(ValidationReport::new).collect()
+}
+
+/// Parses all the given numbers and returns the detailed information of each
+/// of them(`None` for invalid numbers), in the same order as the input.
+pub fn info_all<'a, I>(iter: I) -> Vec<Option<IdentityInfo>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    iter.into_iter().map(|s| Identity::new(s).info()).collect()
+}
+
+/// Validates all the given numbers in parallel and returns a report for each
+/// of them, in the same order as the input.
+#[cfg(feature = "parallel")]
+pub fn par_validate_all<T>(inputs: &[T]) -> Vec<ValidationReport>
+where
+    T: AsRef<str> + Sync,
+{
+    inputs
+        .par_iter()
+        .map(|s| ValidationReport::new(s.as_ref()))
+        .collect()
+}
+
+/// Parses all the given numbers in parallel and returns the detailed
+/// information of each of them(`None` for invalid numbers), in the same order
+/// as the input.
+#[cfg(feature = "parallel")]
+pub fn par_info_all<T>(inputs: &[T]) -> Vec<Option<IdentityInfo>>
+where
+    T: AsRef<str> + Sync,
+{
+    inputs
+        .par_iter()
+        .map(|s| Identity::new(s.as_ref()).info())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_all() {
+        let inputs = [
+            "230127197908177456",
+            " 21021119810503545x",
+            "230127197908177457",
+            "2301271979",
+            "632123 820927 051",
+        ];
+        let reports = validate_all(inputs.iter().copied());
+        assert_eq!(reports.len(), 5);
+        assert_eq!(reports[0].input, "230127197908177456");
+        assert!(reports[0].valid);
+        assert_eq!(reports[0].normalized, None);
+        assert_eq!(reports[1].normalized.as_deref(), Some("21021119810503545X"));
+        assert!(reports[1].valid);
+        assert_eq!(reports[2].reason, Some(ValidationError::ChecksumMismatch));
+        assert_eq!(reports[3].reason, Some(ValidationError::WrongLength));
+        assert!(reports[4].valid);
+
+        let summary = Summary::from_reports(&reports);
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.valid, 3);
+        assert_eq!(summary.invalid, 2);
+        assert_eq!(summary.normalized, 2);
+        assert_eq!(
+            summary.reasons.get(&ValidationError::ChecksumMismatch),
+            Some(&1)
+        );
+        assert_eq!(summary.reasons.get(&ValidationError::WrongLength), Some(&1));
+    }
+
+    #[test]
+    fn test_info_all() {
+        let infos = info_all(vec!["632123820927051", "230127197908177457"]);
+        assert_eq!(infos[0].as_ref().unwrap().number, "632123198209270518");
+        assert!(infos[1].is_none());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_validate_all() {
+        let inputs: Vec<String> = (0..1000)
+            .map(|i| {
+                if i % 3 == 0 {
+                    "230127197908177457".to_string()
+                } else {
+                    "230127197908177456".to_string()
+                }
+            })
+            .collect();
+        let reports = par_validate_all(&inputs);
+        assert_eq!(reports.len(), inputs.len());
+        assert_eq!(reports, validate_all(inputs.iter().map(|s| s.as_str())));
+
+        let infos = par_info_all(&inputs);
+        assert_eq!(infos, info_all(inputs.iter().map(|s| s.as_str())));
+    }
+}
@@ -0,0 +1,339 @@
+//! The `batch` subcommand, which audits the ID numbers in a CSV or JSONL file.
+
+use idcard::batch::{Summary, ValidationReport};
+use idcard::Identity;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// The format of the input or output file, decided by the file extension,
+/// `.jsonl` and `.ndjson` are JSONL, the others are CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Jsonl,
+}
+
+impl Format {
+    fn of(path: &str) -> Format {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some("jsonl") | Some("ndjson") => Format::Jsonl,
+            _ => Format::Csv,
+        }
+    }
+}
+
+/// The column holding the ID numbers, either a 1-based index or a name(the
+/// CSV header or the JSON key).
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Column {
+    Index(usize),
+    Name(String),
+}
+
+impl Column {
+    fn parse(column: &str) -> Result<Column, String> {
+        match column.parse::<usize>() {
+            Ok(0) => Err("the column index starts from 1".to_owned()),
+            Ok(index) => Ok(Column::Index(index - 1)),
+            Err(_) => Ok(Column::Name(column.to_owned())),
+        }
+    }
+}
+
+/// The validation result of a row, enriched with the information extracted
+/// from the valid numbers.
+struct Row {
+    row: u64,
+    report: ValidationReport,
+    identity: Option<Identity>,
+}
+
+impl Row {
+    fn new(row: u64, input: &str) -> Row {
+        let report = ValidationReport::new(input);
+        let identity = if report.valid {
+            Some(Identity::new(
+                report.normalized.as_deref().unwrap_or(&report.input),
+            ))
+        } else {
+            None
+        };
+        Row {
+            row,
+            report,
+            identity,
+        }
+    }
+
+    const FIELDS: [&'static str; 9] = [
+        "row",
+        "input",
+        "valid",
+        "reason",
+        "region_code",
+        "province",
+        "region",
+        "gender",
+        "birth_date",
+    ];
+
+    fn values(&self) -> [Value; 9] {
+        let id = self.identity.as_ref();
+        [
+            json!(self.row),
+            json!(self.report.input),
+            json!(self.report.valid),
+            json!(self.report.reason.map(|reason| reason.to_string())),
+            json!(id.and_then(|id| id.region_code())),
+            json!(id.and_then(|id| id.province())),
+            json!(id.and_then(|id| id.region())),
+            json!(id.and_then(|id| id.gender()).map(|g| format!("{:?}", g))),
+            json!(id.and_then(|id| id.birth_date())),
+        ]
+    }
+}
+
+enum Sink {
+    Csv(Box<csv::Writer<Box<dyn Write>>>),
+    Jsonl(BufWriter<Box<dyn Write>>),
+}
+
+impl Sink {
+    fn new(output: Option<&str>) -> Result<Sink, String> {
+        let (writer, format): (Box<dyn Write>, _) = match output {
+            None | Some("-") => (Box::new(io::stdout()), Format::Jsonl),
+            Some(path) => {
+                let file = File::create(path).map_err(|e| format!("{}: {}", path, e))?;
+                (Box::new(file), Format::of(path))
+            }
+        };
+        match format {
+            Format::Csv => {
+                let mut writer = csv::Writer::from_writer(writer);
+                writer
+                    .write_record(Row::FIELDS)
+                    .map_err(|e| e.to_string())?;
+                Ok(Sink::Csv(Box::new(writer)))
+            }
+            Format::Jsonl => Ok(Sink::Jsonl(BufWriter::new(writer))),
+        }
+    }
+
+    fn write(&mut self, row: &Row) -> Result<(), String> {
+        let values = row.values();
+        match self {
+            Sink::Csv(writer) => {
+                let record = values.iter().map(|value| match value {
+                    Value::Null => String::new(),
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                });
+                writer.write_record(record).map_err(|e| e.to_string())
+            }
+            Sink::Jsonl(writer) => {
+                let object: Map<String, Value> = Row::FIELDS
+                    .iter()
+                    .map(|key| key.to_string())
+                    .zip(values)
+                    .collect();
+                writeln!(writer, "{}", Value::Object(object)).map_err(|e| e.to_string())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        match self {
+            Sink::Csv(writer) => writer.flush(),
+            Sink::Jsonl(writer) => writer.flush(),
+        }
+        .map_err(|e| e.to_string())
+    }
+}
+
+/// Validates the numbers in the given column of each row of the input(stdin
+/// if it's `-`), writes a result per row to the output(JSONL to stdout if it's
+/// `None`) and returns the summary.
+pub fn run(
+    input: &str,
+    column: &str,
+    output: Option<&str>,
+    no_header: bool,
+) -> Result<Summary, String> {
+    let column = Column::parse(column)?;
+    let (reader, format): (Box<dyn Read>, _) = if input == "-" {
+        (Box::new(io::stdin()), Format::Csv)
+    } else {
+        let file = File::open(input).map_err(|e| format!("{}: {}", input, e))?;
+        (Box::new(file), Format::of(input))
+    };
+    let mut sink = Sink::new(output)?;
+    let mut summary = Summary::default();
+    let mut emit = |row: Row| {
+        summary.add(&row.report);
+        sink.write(&row)
+    };
+    match format {
+        Format::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .has_headers(!no_header)
+                .flexible(true)
+                .from_reader(reader);
+            let index = match column {
+                Column::Index(index) => index,
+                Column::Name(name) if !no_header => reader
+                    .headers()
+                    .map_err(|e| e.to_string())?
+                    .iter()
+                    .position(|header| header == name)
+                    .ok_or_else(|| format!("no column named {:?}", name))?,
+                Column::Name(name) => {
+                    return Err(format!("no column named {:?} without header", name))
+                }
+            };
+            for (i, record) in reader.records().enumerate() {
+                let record = record.map_err(|e| e.to_string())?;
+                emit(Row::new(i as u64 + 1, record.get(index).unwrap_or("")))?;
+            }
+        }
+        Format::Jsonl => {
+            let reader = BufReader::new(reader);
+            for (i, line) in reader.lines().enumerate() {
+                let line = line.map_err(|e| e.to_string())?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let value: Value =
+                    serde_json::from_str(&line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+                let field = match (&column, &value) {
+                    (Column::Index(_), Value::Object(_)) => {
+                        return Err(format!(
+                            "line {}: the column of an object must be given by name",
+                            i + 1
+                        ))
+                    }
+                    (Column::Index(index), _) => value.get(index),
+                    (Column::Name(name), _) => value.get(name),
+                };
+                let input = match field {
+                    Some(Value::String(s)) => s.clone(),
+                    Some(Value::Number(n)) => n.to_string(),
+                    _ => String::new(),
+                };
+                emit(Row::new(i as u64 + 1, &input))?;
+            }
+        }
+    }
+    sink.flush()?;
+    Ok(summary)
+}
+
+/// Returns the summary as a JSON value, the failure reasons are sorted.
+pub fn summary_json(summary: &Summary) -> Value {
+    let reasons: BTreeMap<String, usize> = summary
+        .reasons
+        .iter()
+        .map(|(reason, count)| (reason.to_string(), *count))
+        .collect();
+    json!({
+        "total": summary.total,
+        "valid": summary.valid,
+        "invalid": summary.invalid,
+        "normalized": summary.normalized,
+        "reasons": reasons,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        assert_eq!(Column::parse("3"), Ok(Column::Index(2)));
+        assert_eq!(Column::parse("id"), Ok(Column::Name("id".to_owned())));
+        assert!(Column::parse("0").is_err());
+        assert_eq!(Format::of("ids.csv"), Format::Csv);
+        assert_eq!(Format::of("report.jsonl"), Format::Jsonl);
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = std::env::temp_dir().join(format!("idcard-batch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("ids.csv");
+        let output = dir.join("report.jsonl");
+        std::fs::write(
+            &input,
+            "name,age,id\n\
+             a,44,632123820927051\n\
+             b,47,230127197908177457\n\
+             c,30\n",
+        )
+        .unwrap();
+        let summary = run(
+            input.to_str().unwrap(),
+            "3",
+            Some(output.to_str().unwrap()),
+            false,
+        )
+        .unwrap();
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.valid, 1);
+        assert_eq!(summary.invalid, 2);
+
+        let report = std::fs::read_to_string(&output).unwrap();
+        let rows: Vec<Value> = report
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0]["valid"], json!(true));
+        assert_eq!(rows[0]["region_code"], json!("632123"));
+        assert_eq!(rows[0]["gender"], json!("Male"));
+        assert_eq!(rows[0]["birth_date"], json!("1982-09-27"));
+        assert_eq!(rows[1]["reason"], json!("Checksum Mismatch"));
+        assert_eq!(rows[2]["input"], json!(""));
+
+        let summary = summary_json(&summary);
+        assert_eq!(summary["reasons"]["Checksum Mismatch"], json!(1));
+        assert_eq!(summary["reasons"]["Wrong Length"], json!(1));
+
+        let input = dir.join("ids.jsonl");
+        std::fs::write(&input, "{\"id\":\"632123820927051\"}\n{\"other\":1}\n").unwrap();
+        let summary = run(
+            input.to_str().unwrap(),
+            "id",
+            Some(dir.join("report.csv").to_str().unwrap()),
+            false,
+        )
+        .unwrap();
+        assert_eq!((summary.total, summary.valid), (2, 1));
+        let report = std::fs::read_to_string(dir.join("report.csv")).unwrap();
+        assert!(report.starts_with("row,input,valid,reason,"));
+        assert!(report.contains("1,632123820927051,true,,632123,"));
+
+        let err = run(
+            input.to_str().unwrap(),
+            "1",
+            Some(dir.join("report.csv").to_str().unwrap()),
+            false,
+        )
+        .unwrap_err();
+        assert!(err.starts_with("line 1: "));
+
+        let input = dir.join("rows.jsonl");
+        std::fs::write(&input, "[\"a\",\"632123820927051\"]\n").unwrap();
+        let summary = run(
+            input.to_str().unwrap(),
+            "2",
+            Some(dir.join("report.csv").to_str().unwrap()),
+            false,
+        )
+        .unwrap();
+        assert_eq!((summary.total, summary.valid), (1, 1));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,283 @@
+//! The `idcard` command line tool.
+
+mod batch;
+mod redact;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use idcard::fake::{self, FakeOptions};
+use idcard::{Identity, IdentityInfo, MaskStyle};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process::ExitCode;
+
+/// Chinese Identity Card Utilities.
+#[derive(Debug, Parser)]
+#[command(name = "idcard", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Validates the numbers, exits with 1 if any of them is invalid.
+    Validate {
+        #[arg(required = true)]
+        numbers: Vec<String>,
+    },
+    /// Prints the detailed information of a number.
+    Info {
+        number: String,
+        /// Prints the information as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Generates fake numbers.
+    Fake {
+        /// The region code, 2..6 digits.
+        #[arg(long)]
+        region: Option<String>,
+        #[arg(long, value_enum)]
+        gender: Option<GenderArg>,
+        /// The minimum year of birth.
+        #[arg(long)]
+        min_year: Option<u32>,
+        /// The maximum year of birth.
+        #[arg(long)]
+        max_year: Option<u32>,
+        /// The number of the numbers to generate, the numbers are unique.
+        #[arg(short = 'n', long, default_value_t = 1)]
+        count: usize,
+    },
+    /// Upgrades a number from 15-digit to 18-digit.
+    Upgrade { number: String },
+    /// Validates the numbers in a column of a CSV or JSONL file, writes a
+    /// result per row and prints the summary to stderr.
+    Batch {
+        /// The input file, `-` for CSV from stdin.
+        #[arg(long, default_value = "-")]
+        input: String,
+        /// The 1-based index or the name of the column(the JSON key for JSONL
+        /// objects, the index only applies to JSONL arrays).
+        #[arg(long)]
+        column: String,
+        /// The output file, CSV unless it ends with `.jsonl` or `.ndjson`,
+        /// JSONL to stdout by default.
+        #[arg(long)]
+        output: Option<String>,
+        /// The CSV input has no header row.
+        #[arg(long)]
+        no_header: bool,
+    },
+    /// Replaces every valid ID number in the files(stdin by default) with its
+    /// masked form, the result is written to stdout.
+    Redact {
+        files: Vec<String>,
+        #[arg(long, value_enum, default_value_t = StyleArg::KeepFirst6Last4)]
+        style: StyleArg,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum GenderArg {
+    #[value(alias = "male")]
+    M,
+    #[value(alias = "female")]
+    F,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum StyleArg {
+    /// Keeps the first 6 and the last 4 characters.
+    KeepFirst6Last4,
+    /// Keeps only the year of birth.
+    KeepBirthYearOnly,
+}
+
+impl From<StyleArg> for MaskStyle {
+    fn from(style: StyleArg) -> Self {
+        match style {
+            StyleArg::KeepFirst6Last4 => MaskStyle::KeepFirst6Last4,
+            StyleArg::KeepBirthYearOnly => MaskStyle::KeepBirthYearOnly,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(code) => code,
+        Err(msg) => {
+            eprintln!("idcard: {}", msg);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode, String> {
+    match command {
+        Command::Validate { numbers } => {
+            let mut all_valid = true;
+            for number in &numbers {
+                match idcard::validate_detailed(number) {
+                    Ok(()) => println!("{}\tvalid", number),
+                    Err(reason) => {
+                        all_valid = false;
+                        println!("{}\tinvalid\t{}", number, reason);
+                    }
+                }
+            }
+            Ok(if all_valid {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::Info { number, json } => {
+            let id = Identity::new(&number);
+            let info = match id.info() {
+                Some(info) => info,
+                None => {
+                    let reason = idcard::validate_detailed(&number).err();
+                    return Err(match reason {
+                        Some(reason) => format!("invalid number {:?}: {}", number, reason),
+                        None => format!("invalid number {:?}", number),
+                    });
+                }
+            };
+            if json {
+                println!("{}", info.to_json_string());
+            } else {
+                print_info(&info);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Fake {
+            region,
+            gender,
+            min_year,
+            max_year,
+            count,
+        } => {
+            let mut options = FakeOptions::new();
+            if let Some(region) = region {
+                options = options.region(&region);
+            }
+            options = match gender {
+                Some(GenderArg::M) => options.male(),
+                Some(GenderArg::F) => options.female(),
+                None => options,
+            };
+            if let Some(year) = min_year {
+                options = options.min_year(year);
+            }
+            if let Some(year) = max_year {
+                options = options.max_year(year);
+            }
+            for number in fake::rand_many(count, &options).map_err(|e| e.to_string())? {
+                println!("{}", number);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Upgrade { number } => {
+            let upgraded = idcard::upgrade(&number).map_err(|e| e.to_string())?;
+            println!("{}", upgraded);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Batch {
+            input,
+            column,
+            output,
+            no_header,
+        } => {
+            let summary = batch::run(&input, &column, output.as_deref(), no_header)?;
+            eprintln!("{}", batch::summary_json(&summary));
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Redact { files, style } => {
+            let stdout = io::stdout();
+            let mut writer = io::BufWriter::new(stdout.lock());
+            if files.is_empty() {
+                redact::redact(io::stdin().lock(), &mut writer, style.into())
+                    .map_err(|e| e.to_string())?;
+            }
+            for path in &files {
+                let file = File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+                redact::redact(BufReader::new(file), &mut writer, style.into())
+                    .map_err(|e| format!("{}: {}", path, e))?;
+            }
+            io::Write::flush(&mut writer).map_err(|e| e.to_string())?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn print_info(info: &IdentityInfo) {
+    let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+    let age = info.age.map(|age| age.to_string()).unwrap_or_default();
+    let rows = [
+        ("number", info.number.clone()),
+        ("gender", format!("{:?}", info.gender)),
+        ("age", age),
+        ("birth_date", info.birth_date.clone()),
+        ("province", optional(&info.province)),
+        ("region", optional(&info.region)),
+        ("region_code", info.region_code.clone()),
+        ("constellation", optional(&info.constellation)),
+        (
+            "chinese_era",
+            info.chinese_era
+                .map(|era| era.to_string())
+                .unwrap_or_default(),
+        ),
+        ("chinese_zodiac", optional(&info.chinese_zodiac)),
+    ];
+    for (key, value) in &rows {
+        println!("{:<15}{}", key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from([
+            "idcard", "fake", "--region", "3301", "--gender", "f", "-n", "100",
+        ]);
+        assert!(matches!(
+            cli.unwrap().command,
+            Command::Fake { region: Some(ref region), gender: Some(GenderArg::F), count: 100, .. }
+                if region == "3301"
+        ));
+        let cli = Cli::try_parse_from(["idcard", "info", "632123820927051", "--json"]);
+        assert!(matches!(
+            cli.unwrap().command,
+            Command::Info { json: true, .. }
+        ));
+        assert!(Cli::try_parse_from(["idcard", "validate"]).is_err());
+        let cli = Cli::try_parse_from([
+            "idcard",
+            "batch",
+            "--input",
+            "ids.csv",
+            "--column",
+            "3",
+            "--output",
+            "report.jsonl",
+        ]);
+        assert!(matches!(
+            cli.unwrap().command,
+            Command::Batch { ref input, ref column, output: Some(_), no_header: false }
+                if input == "ids.csv" && column == "3"
+        ));
+        let cli = Cli::try_parse_from(["idcard", "redact", "--style", "keep-first6-last4"]);
+        assert!(matches!(
+            cli.unwrap().command,
+            Command::Redact { ref files, style: StyleArg::KeepFirst6Last4 } if files.is_empty()
+        ));
+    }
+}
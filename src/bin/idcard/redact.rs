@@ -0,0 +1,64 @@
+//! The `redact` subcommand, which masks the ID numbers in text.
+
+use idcard::{text, MaskStyle};
+use std::io::{self, BufRead, Write};
+
+/// Copies the reader to the writer line by line, replacing every valid ID
+/// number with its masked form, and returns the number of the lines changed.
+/// The lines are scanned as bytes, so the text may be in any ASCII compatible
+/// encoding, e.g. GBK.
+pub fn redact<R: BufRead, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+    style: MaskStyle,
+) -> io::Result<usize> {
+    let mut changed = 0;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(changed);
+        }
+        let redacted = text::redact_bytes(&line, style);
+        if redacted != line {
+            changed += 1;
+        }
+        writer.write_all(&redacted)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let input = "user=632123198209270518 ok\nnothing here\nold 632123820927051, bad 632123198209270519\n";
+        let mut output = Vec::new();
+        let changed = redact(input.as_bytes(), &mut output, MaskStyle::default()).unwrap();
+        assert_eq!(changed, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "user=632123********0518 ok\nnothing here\nold 632123*****7051, bad 632123198209270519\n"
+        );
+
+        let mut output = Vec::new();
+        redact(
+            &b"632123198209270518"[..],
+            &mut output,
+            MaskStyle::KeepBirthYearOnly,
+        )
+        .unwrap();
+        assert_eq!(output, b"******1982********");
+
+        // A GBK line, `身份证：632123198209270518`.
+        let input = b"\xc9\xed\xb7\xdd\xd6\xa4\xa3\xba632123198209270518\nok\n";
+        let mut output = Vec::new();
+        let changed = redact(&input[..], &mut output, MaskStyle::default()).unwrap();
+        assert_eq!(changed, 1);
+        assert_eq!(
+            output,
+            b"\xc9\xed\xb7\xdd\xd6\xa4\xa3\xba632123********0518\nok\n"
+        );
+    }
+}
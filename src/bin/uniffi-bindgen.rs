@@ -0,0 +1,6 @@
+//! Generates the Kotlin and Swift bindings, e.g.
+//! `cargo run --features uniffi-bindgen --bin uniffi-bindgen generate --library target/release/libidcard.so --language kotlin --out-dir out`.
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,43 @@
+//! Utilities for Medical Certificate of Birth(出生医学证明)
+//!
+//! Across the generations of the certificate, the number is a series letter
+//! followed by 9 digits, e.g. `P440123456`. The letter changes with the
+//! generations and the batches of printing, and there is no check digit, so
+//! only the format is validated.
+
+use crate::normalize;
+
+/// Validates the number.
+pub fn validate(number: &str) -> bool {
+    let number = normalize(number);
+    let bytes = number.as_bytes();
+    bytes.len() == 10 && bytes[0].is_ascii_uppercase() && bytes[1..].iter().all(u8::is_ascii_digit)
+}
+
+/// Returns the series letter of a valid number.
+pub fn series(number: &str) -> Option<char> {
+    if !validate(number) {
+        return None;
+    }
+    normalize(number).chars().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("P440123456"));
+        assert!(validate("o 510012345"));
+        assert!(!validate("P44012345"));
+        assert!(!validate("4401234567"));
+        assert!(!validate("PA40123456"));
+    }
+
+    #[test]
+    fn test_series() {
+        assert_eq!(series("p440123456"), Some('P'));
+        assert_eq!(series("P44012345"), None);
+    }
+}
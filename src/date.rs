@@ -0,0 +1,172 @@
+//! A minimal Gregorian date, so the numbers can be validated without chrono
+
+#[cfg(not(feature = "chrono"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A date in the proleptic Gregorian calendar, ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date, returns `None` if it doesn't exist in the calendar.
+    pub(crate) fn new(year: i32, month: u32, day: u32) -> Option<Date> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Date {
+                year,
+                month: month as u8,
+                day: day as u8,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Parses a date in `yyyymmdd`.
+    pub(crate) fn parse_ymd(s: &str) -> Option<Date> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 8 || !bytes.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let num = |range: std::ops::Range<usize>| {
+            bytes[range]
+                .iter()
+                .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'))
+        };
+        Date::new(num(0..4) as i32, num(4..6), num(6..8))
+    }
+
+    pub(crate) fn year(self) -> i32 {
+        self.year
+    }
+
+    pub(crate) fn month(self) -> u32 {
+        u32::from(self.month)
+    }
+
+    pub(crate) fn day(self) -> u32 {
+        u32::from(self.day)
+    }
+
+    /// Returns today's date in the local time zone, or in UTC without chrono.
+    pub(crate) fn today() -> Date {
+        #[cfg(feature = "chrono")]
+        {
+            chrono::Local::now().date_naive().into()
+        }
+        #[cfg(not(feature = "chrono"))]
+        {
+            let secs = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            Date::from_days((secs / 86_400) as i64)
+        }
+    }
+
+    /// Returns the date the given days after 1970-01-01, see
+    /// <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    pub(crate) fn from_days(days: i64) -> Date {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Date {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+
+    /// Returns the days since 1970-01-01, the inverse of `from_days`.
+    pub(crate) fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let month = i64::from(self.month);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        Date {
+            year: date.year(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Date> for chrono::NaiveDate {
+    fn from(date: Date) -> Self {
+        chrono::NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())
+            .expect("the date is out of the range of NaiveDate")
+    }
+}
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2024, 4, 31).is_none());
+        assert!(Date::new(2024, 13, 1).is_none());
+        assert!(Date::new(2024, 1, 0).is_none());
+        assert_eq!(Date::parse_ymd("19820927"), Date::new(1982, 9, 27));
+        assert_eq!(Date::parse_ymd("19820230"), None);
+        assert_eq!(Date::parse_ymd("1982092"), None);
+        assert_eq!(Date::parse_ymd("1982-9-7"), None);
+        assert!(Date::new(1982, 9, 27) < Date::new(1982, 10, 1));
+    }
+
+    #[test]
+    fn test_from_days() {
+        assert_eq!(Date::from_days(0), Date::new(1970, 1, 1).unwrap());
+        assert_eq!(Date::from_days(11_016), Date::new(2000, 2, 29).unwrap());
+        assert_eq!(Date::from_days(-1), Date::new(1969, 12, 31).unwrap());
+        assert_eq!(Date::from_days(19_782), Date::new(2024, 2, 29).unwrap());
+        assert!(Date::today() > Date::new(2024, 1, 1).unwrap());
+        for days in -800_000..800_000 {
+            assert_eq!(Date::from_days(days).to_days(), days);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naive_date() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+        let date = Date::from(naive);
+        assert_eq!(date, Date::new(2000, 2, 29).unwrap());
+        assert_eq!(chrono::NaiveDate::from(date), naive);
+    }
+}
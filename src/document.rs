@@ -0,0 +1,234 @@
+//! A unified representation of the supported documents.
+
+use crate::{detect, hk, mask_number, mo, tw, DocumentKind, Gender, Identity, MaskStyle};
+
+/// The operations shared by the supported documents.
+pub trait ChineseId {
+    /// Returns the number.
+    fn number(&self) -> &str;
+
+    /// Checks if the number is valid.
+    fn is_valid(&self) -> bool;
+
+    /// Returns the gender of the holder, or `None` if the number is invalid
+    /// or doesn't encode the gender.
+    fn gender(&self) -> Option<Gender>;
+
+    /// Returns the masked number.
+    fn mask(&self, style: MaskStyle) -> String {
+        mask_number(self.number(), style)
+    }
+}
+
+impl ChineseId for Identity {
+    fn number(&self) -> &str {
+        Identity::number(self)
+    }
+
+    fn is_valid(&self) -> bool {
+        Identity::is_valid(self)
+    }
+
+    fn gender(&self) -> Option<Gender> {
+        Identity::gender(self)
+    }
+
+    fn mask(&self, style: MaskStyle) -> String {
+        Identity::mask(self, style)
+    }
+}
+
+impl ChineseId for hk::Identity {
+    fn number(&self) -> &str {
+        hk::Identity::number(self)
+    }
+
+    fn is_valid(&self) -> bool {
+        hk::Identity::is_valid(self)
+    }
+
+    /// The Hong Kong ID doesn't encode the gender, it's always `None`.
+    fn gender(&self) -> Option<Gender> {
+        None
+    }
+}
+
+impl ChineseId for mo::Identity {
+    fn number(&self) -> &str {
+        mo::Identity::number(self)
+    }
+
+    fn is_valid(&self) -> bool {
+        mo::Identity::is_valid(self)
+    }
+
+    /// The Macau ID doesn't encode the gender, it's always `None`.
+    fn gender(&self) -> Option<Gender> {
+        None
+    }
+}
+
+impl ChineseId for tw::Identity {
+    fn number(&self) -> &str {
+        tw::Identity::number(self)
+    }
+
+    fn is_valid(&self) -> bool {
+        tw::Identity::is_valid(self)
+    }
+
+    fn gender(&self) -> Option<Gender> {
+        tw::Identity::gender(self)
+    }
+}
+
+/// A document of any supported kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Document {
+    /// A document numbered in the mainland 15/18-digit format, i.e. the
+    /// mainland ID, the residence permit or the new foreign permanent resident
+    /// ID. The kind is detected from the original number, as a 15-digit one is
+    /// upgraded in the identity object.
+    Mainland { kind: DocumentKind, id: Identity },
+    /// A Hong Kong ID.
+    HongKong(hk::Identity),
+    /// A Macau ID.
+    Macau(mo::Identity),
+    /// A Taiwan ID.
+    Taiwan(tw::Identity),
+    /// A document without a dedicated type, with its normalized number.
+    Other { kind: DocumentKind, number: String },
+}
+
+impl Document {
+    /// Parses the number of any supported kind, see `detect`. It returns `None`
+    /// if the number is invalid.
+    pub fn parse(number: &str) -> Option<Document> {
+        let kind = detect(number)?;
+        let number = crate::normalize(number);
+        match kind {
+            DocumentKind::Mainland15
+            | DocumentKind::Mainland18
+            | DocumentKind::ResidencePermit
+            | DocumentKind::ForeignPermanentResident
+                if number.bytes().all(|b| b.is_ascii_digit() || b == b'X') =>
+            {
+                Some(Document::Mainland {
+                    kind,
+                    id: Identity::new(&number),
+                })
+            }
+            DocumentKind::HongKong => Some(Document::HongKong(hk::Identity::new(&number))),
+            DocumentKind::Macau => Some(Document::Macau(mo::Identity::new(&number))),
+            DocumentKind::Taiwan => Some(Document::Taiwan(tw::Identity::new(&number))),
+            _ => Some(Document::Other { kind, number }),
+        }
+    }
+
+    /// Returns the kind of the document.
+    pub fn kind(&self) -> DocumentKind {
+        match self {
+            Document::Mainland { kind, .. } => *kind,
+            Document::HongKong(_) => DocumentKind::HongKong,
+            Document::Macau(_) => DocumentKind::Macau,
+            Document::Taiwan(_) => DocumentKind::Taiwan,
+            Document::Other { kind, .. } => *kind,
+        }
+    }
+}
+
+impl ChineseId for Document {
+    fn number(&self) -> &str {
+        match self {
+            Document::Mainland { id, .. } => id.number(),
+            Document::HongKong(id) => id.number(),
+            Document::Macau(id) => id.number(),
+            Document::Taiwan(id) => id.number(),
+            Document::Other { number, .. } => number,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            Document::Mainland { id, .. } => id.is_valid(),
+            Document::HongKong(id) => id.is_valid(),
+            Document::Macau(id) => id.is_valid(),
+            Document::Taiwan(id) => id.is_valid(),
+            Document::Other { .. } => true,
+        }
+    }
+
+    fn gender(&self) -> Option<Gender> {
+        match self {
+            Document::Mainland { id, .. } => id.gender(),
+            Document::HongKong(id) => id.gender(),
+            Document::Macau(id) => id.gender(),
+            Document::Taiwan(id) => id.gender(),
+            Document::Other { .. } => None,
+        }
+    }
+
+    fn mask(&self, style: MaskStyle) -> String {
+        match self {
+            Document::Mainland { id, .. } => id.mask(style),
+            Document::HongKong(id) => id.mask(style),
+            Document::Macau(id) => id.mask(style),
+            Document::Taiwan(id) => id.mask(style),
+            Document::Other { number, .. } => mask_number(number, style),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let doc = Document::parse("632123820927051").unwrap();
+        assert_eq!(doc.kind(), DocumentKind::Mainland15);
+        assert_eq!(doc.kind(), detect("632123820927051").unwrap());
+        assert_eq!(doc.number(), "632123198209270518");
+        assert!(doc.is_valid());
+        assert_eq!(doc.gender(), Some(Gender::Male));
+        assert_eq!(doc.mask(MaskStyle::default()), "632123********0518");
+
+        let doc = Document::parse("632123198209270518").unwrap();
+        assert_eq!(doc.kind(), DocumentKind::Mainland18);
+
+        let doc = Document::parse("830000199001011234").unwrap();
+        assert_eq!(doc.kind(), DocumentKind::ResidencePermit);
+
+        let doc = Document::parse("a123456789").unwrap();
+        assert_eq!(doc.kind(), DocumentKind::Taiwan);
+        assert!(
+            matches!(&doc, Document::Taiwan(id) if id.region() == Some(tw::Region::TaipeiCity))
+        );
+        assert_eq!(doc.number(), "A123456789");
+        assert_eq!(doc.gender(), Some(Gender::Male));
+
+        let doc = Document::parse("7431243(3)").unwrap();
+        assert_eq!(doc.kind(), DocumentKind::Macau);
+        assert!(
+            matches!(&doc, Document::Macau(id) if id.holder_type() == Some(mo::HolderType::NonPermanent))
+        );
+
+        let doc = Document::parse("USA119001011221").unwrap();
+        assert_eq!(doc.kind(), DocumentKind::ForeignPermanentResident);
+        assert_eq!(doc.gender(), None);
+
+        assert_eq!(Document::parse("230127197908177457"), None);
+    }
+
+    #[test]
+    fn test_trait_object() {
+        let docs: Vec<Box<dyn ChineseId>> = vec![
+            Box::new(Identity::new("230127197908177456")),
+            Box::new(Document::parse("g123456a").unwrap()),
+            Box::new(hk::Identity::new("AB987654(3)")),
+        ];
+        assert!(docs.iter().all(|d| d.is_valid()));
+        assert_eq!(docs[1].number(), "G123456(A)");
+        assert_eq!(docs[2].gender(), None);
+    }
+}
@@ -0,0 +1,61 @@
+//! Utilities for Exit-Entry Permit for Travelling to and from Hong Kong and
+//! Macao(往来港澳通行证)
+//!
+//! The number of the card issued since 2014 is `C` followed by 8 digits, e.g.
+//! `C12345678`, and the newer ones take a letter in place of the first digit,
+//! e.g. `CA1234567`. The number of the legacy booklet is `W` followed by 8
+//! digits, e.g. `W12345678`.
+
+use crate::normalize;
+
+/// The forms of the permit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Form {
+    /// The card issued since 2014.
+    Card,
+    /// The legacy booklet.
+    Booklet,
+}
+
+/// Validates the number of either form.
+pub fn validate(number: &str) -> bool {
+    form(number).is_some()
+}
+
+/// Returns the form of a valid number.
+pub fn form(number: &str) -> Option<Form> {
+    let number = normalize(number);
+    let bytes = number.as_bytes();
+    if bytes.len() != 9 || !bytes[2..].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    match (bytes[0], bytes[1]) {
+        (b'C', b'0'..=b'9') | (b'C', b'A'..=b'Z') => Some(Form::Card),
+        (b'W', b'0'..=b'9') => Some(Form::Booklet),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("C12345678"));
+        assert!(validate("ca1234567"));
+        assert!(validate("W12345678"));
+        assert!(!validate("WA1234567"));
+        assert!(!validate("C1234567"));
+        assert!(!validate("C123456789"));
+        assert!(!validate("H12345678"));
+    }
+
+    #[test]
+    fn test_form() {
+        assert_eq!(form("C12345678"), Some(Form::Card));
+        assert_eq!(form("CA1234567"), Some(Form::Card));
+        assert_eq!(form("W12345678"), Some(Form::Booklet));
+        assert_eq!(form("W1234567"), None);
+    }
+}
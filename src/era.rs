@@ -0,0 +1,280 @@
+//! The sexagenary cycle(干支) used to name the years.
+
+use crate::Zodiac;
+use std::fmt;
+
+/// The ten Heavenly Stems(天干), in the order of the cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HeavenlyStem {
+    /// 甲
+    Jia,
+    /// 乙
+    Yi,
+    /// 丙
+    Bing,
+    /// 丁
+    Ding,
+    /// 戊
+    Wu,
+    /// 己
+    Ji,
+    /// 庚
+    Geng,
+    /// 辛
+    Xin,
+    /// 壬
+    Ren,
+    /// 癸
+    Gui,
+}
+
+static STEM_NAMES: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+
+impl HeavenlyStem {
+    /// All the stems, in the order of the cycle.
+    pub const ALL: [HeavenlyStem; 10] = [
+        HeavenlyStem::Jia,
+        HeavenlyStem::Yi,
+        HeavenlyStem::Bing,
+        HeavenlyStem::Ding,
+        HeavenlyStem::Wu,
+        HeavenlyStem::Ji,
+        HeavenlyStem::Geng,
+        HeavenlyStem::Xin,
+        HeavenlyStem::Ren,
+        HeavenlyStem::Gui,
+    ];
+
+    /// Returns the Chinese name, e.g. `甲`.
+    pub fn name(self) -> &'static str {
+        STEM_NAMES[self as usize]
+    }
+}
+
+impl fmt::Display for HeavenlyStem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The twelve Earthly Branches(地支), in the order of the cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EarthlyBranch {
+    /// 子
+    Zi,
+    /// 丑
+    Chou,
+    /// 寅
+    Yin,
+    /// 卯
+    Mao,
+    /// 辰
+    Chen,
+    /// 巳
+    Si,
+    /// 午
+    Wu,
+    /// 未
+    Wei,
+    /// 申
+    Shen,
+    /// 酉
+    You,
+    /// 戌
+    Xu,
+    /// 亥
+    Hai,
+}
+
+static BRANCH_NAMES: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+
+impl EarthlyBranch {
+    /// All the branches, in the order of the cycle.
+    pub const ALL: [EarthlyBranch; 12] = [
+        EarthlyBranch::Zi,
+        EarthlyBranch::Chou,
+        EarthlyBranch::Yin,
+        EarthlyBranch::Mao,
+        EarthlyBranch::Chen,
+        EarthlyBranch::Si,
+        EarthlyBranch::Wu,
+        EarthlyBranch::Wei,
+        EarthlyBranch::Shen,
+        EarthlyBranch::You,
+        EarthlyBranch::Xu,
+        EarthlyBranch::Hai,
+    ];
+
+    /// Returns the Chinese name, e.g. `子`.
+    pub fn name(self) -> &'static str {
+        BRANCH_NAMES[self as usize]
+    }
+
+    /// Returns the Chinese Zodiac animal of the branch, e.g. `Rat` for `子`.
+    pub fn zodiac(self) -> Zodiac {
+        Zodiac::ALL[self as usize]
+    }
+}
+
+impl fmt::Display for EarthlyBranch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A year in the sexagenary cycle(干支纪年), e.g. `甲子`, ordered by the index
+/// in the cycle.
+///
+/// # Examples
+///
+/// ```
+/// use idcard::{ChineseEra, EarthlyBranch, HeavenlyStem};
+///
+/// let era = ChineseEra::from_year(2021);
+/// assert_eq!(era.stem(), HeavenlyStem::Xin);
+/// assert_eq!(era.branch(), EarthlyBranch::Chou);
+/// assert_eq!(era.index(), 38);
+/// assert_eq!(era.to_string(), "辛丑");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ChineseEra(u8);
+
+impl ChineseEra {
+    /// Creates the era from the stem and the branch, returns `None` if they
+    /// don't pair in the cycle(e.g. `甲丑`).
+    pub fn new(stem: HeavenlyStem, branch: EarthlyBranch) -> Option<ChineseEra> {
+        (0..60)
+            .find(|i| i % 10 == stem as u8 && i % 12 == branch as u8)
+            .map(ChineseEra)
+    }
+
+    /// Returns the era by the index in the cycle, from 1(`甲子`) to 60(`癸亥`).
+    pub fn from_index(index: u32) -> Option<ChineseEra> {
+        if (1..=60).contains(&index) {
+            Some(ChineseEra(index as u8 - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the era of the year, the year 4 is `甲子`. Note that the year
+    /// is reckoned from January 1st here, see also `chinese_zodiac_on`.
+    pub fn from_year(year: i32) -> ChineseEra {
+        ChineseEra((year - 4).rem_euclid(60) as u8)
+    }
+
+    /// Parses the name, e.g. `甲子`.
+    pub fn from_name(name: &str) -> Option<ChineseEra> {
+        let mut chars = name.chars();
+        let (stem, branch) = (chars.next()?, chars.next()?);
+        if chars.next().is_some() {
+            return None;
+        }
+        let find = |names: &[&str], c: char| names.iter().position(|n| n.starts_with(c));
+        ChineseEra::new(
+            HeavenlyStem::ALL[find(&STEM_NAMES, stem)?],
+            EarthlyBranch::ALL[find(&BRANCH_NAMES, branch)?],
+        )
+    }
+
+    /// Returns the Heavenly Stem.
+    pub fn stem(self) -> HeavenlyStem {
+        HeavenlyStem::ALL[usize::from(self.0 % 10)]
+    }
+
+    /// Returns the Earthly Branch.
+    pub fn branch(self) -> EarthlyBranch {
+        EarthlyBranch::ALL[usize::from(self.0 % 12)]
+    }
+
+    /// Returns the index in the cycle, from 1(`甲子`) to 60(`癸亥`).
+    pub fn index(self) -> u32 {
+        u32::from(self.0) + 1
+    }
+}
+
+impl fmt::Display for ChineseEra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.stem(), self.branch())
+    }
+}
+
+/// Serializes the era as the name, e.g. `"甲子"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChineseEra {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChineseEra {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        ChineseEra::from_name(&name)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid Chinese era {:?}", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chinese_era() {
+        let era = ChineseEra::from_index(1).unwrap();
+        assert_eq!(
+            (era.stem(), era.branch()),
+            (HeavenlyStem::Jia, EarthlyBranch::Zi)
+        );
+        assert_eq!(ChineseEra::from_index(60).unwrap().to_string(), "癸亥");
+        assert_eq!(ChineseEra::from_index(0), None);
+        assert_eq!(ChineseEra::from_index(61), None);
+        assert_eq!(ChineseEra::from_year(1984), era);
+        assert_eq!(ChineseEra::from_year(4), era);
+        assert_eq!(ChineseEra::from_year(3).index(), 60);
+        assert_eq!(ChineseEra::from_year(1992).to_string(), "壬申");
+        assert_eq!(
+            ChineseEra::from_year(1992).branch().zodiac(),
+            Zodiac::Monkey
+        );
+        assert_eq!(
+            ChineseEra::new(HeavenlyStem::Ren, EarthlyBranch::Shen),
+            Some(ChineseEra::from_year(1992))
+        );
+        assert_eq!(
+            ChineseEra::new(HeavenlyStem::Jia, EarthlyBranch::Chou),
+            None
+        );
+        for index in 1..=60 {
+            let era = ChineseEra::from_index(index).unwrap();
+            assert_eq!(era.index(), index);
+            assert_eq!(ChineseEra::new(era.stem(), era.branch()), Some(era));
+            assert_eq!(ChineseEra::from_name(&era.to_string()), Some(era));
+        }
+        assert_eq!(ChineseEra::from_name("甲"), None);
+        assert_eq!(ChineseEra::from_name("甲子年"), None);
+        assert_eq!(ChineseEra::from_name("任申"), None);
+        assert!(ChineseEra::from_index(1) < ChineseEra::from_index(2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let era = ChineseEra::from_year(2021);
+        assert_eq!(serde_json::to_string(&era).unwrap(), r#""辛丑""#);
+        assert_eq!(
+            serde_json::from_str::<ChineseEra>(r#""辛丑""#).unwrap(),
+            era
+        );
+        assert!(serde_json::from_str::<ChineseEra>(r#""辛子""#).is_err());
+    }
+}
@@ -1,9 +1,20 @@
 //! Utilities for generating fake ID numbers
 
-use crate::{get_check_code, get_weights_sum, region, string_to_integer_array, Error, Gender};
-use chrono::prelude::*;
+use crate::{
+    check_code_of, get_check_code, get_weights_sum, region, string_to_integer_array, Error, Gender,
+    Identity,
+};
 use chrono::{Datelike, Duration, Local, NaiveDate};
-use rand::{thread_rng, Rng};
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{thread_rng, Rng, SeedableRng};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+mod person;
+
+pub use person::{person, Person};
 
 /// Generates a new fake ID number.
 pub fn new(
@@ -12,6 +23,19 @@ pub fn new(
     month: u32,
     date: u32,
     gender: Gender,
+) -> Result<String, Error> {
+    new_with_rng(region, year, month, date, gender, &mut thread_rng())
+}
+
+/// Generates a new fake ID number, the sequence code is drawn from the given
+/// random number generator.
+pub fn new_with_rng<R: Rng + ?Sized>(
+    region: &str,
+    year: u32,
+    month: u32,
+    date: u32,
+    gender: Gender,
+    rng: &mut R,
 ) -> Result<String, Error> {
     if region.len() != 6 {
         return Err(Error::GenerateFakeIDError(
@@ -19,7 +43,6 @@ pub fn new(
         ));
     }
     
-    let mut rng = thread_rng();
     let mut seq = rng.gen_range(0..999);
     if gender == Gender::Male && seq % 2 == 0 {
         seq += 1;
@@ -48,6 +71,16 @@ pub fn new(
     }
 }
 
+/// The versions of the ID numbers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdVersion {
+    /// The legacy 15-digit number, only for those born in 1900~1999.
+    V1,
+    /// The 18-digit number.
+    #[default]
+    V2,
+}
+
 /// Options which can be used to configure how a fake ID number is generated.
 #[derive(Debug, Default, Clone)]
 pub struct FakeOptions {
@@ -55,6 +88,12 @@ pub struct FakeOptions {
     min_year: Option<u32>,
     max_year: Option<u32>,
     gender: Option<Gender>,
+    age: Option<(u32, u32)>,
+    sequence: Option<u16>,
+    version: IdVersion,
+    any_level: bool,
+    exclude_special: bool,
+    as_of: Option<NaiveDate>,
 }
 
 impl FakeOptions {
@@ -75,12 +114,65 @@ impl FakeOptions {
         self
     }
 
+    /// Sets the age as of today(or the date set by `as_of`), in full years.
+    pub fn age(self, age: u32) -> Self {
+        self.age_between(age, age)
+    }
+
+    /// Sets the range of the age as of today(or the date set by `as_of`), in
+    /// full years, both inclusive.
+    pub fn age_between(mut self, min: u32, max: u32) -> Self {
+        self.age = Some((min, max));
+        self
+    }
+
+    /// Allows the provincial-level and prefecture-level codes which have
+    /// subdivisions, e.g. `330000` and `330100`. By default only the codes
+    /// which appear on the issued cards are chosen, i.e. the county-level ones
+    /// and those without subdivisions(e.g. `441900`), unless the region code is
+    /// set to 6 digits.
+    pub fn any_level(mut self, yes: bool) -> Self {
+        self.any_level = yes;
+        self
+    }
+
+    /// Excludes the special codes, i.e. those of Taiwan(`71`), Hong Kong(`81`),
+    /// Macao(`82`), the residence permits(`810000`, `820000` and `830000`) and
+    /// the foreign permanent residents(`91`), so only the ordinary mainland
+    /// resident IDs are generated.
+    pub fn exclude_special(mut self, yes: bool) -> Self {
+        self.exclude_special = yes;
+        self
+    }
+
     /// Sets the region code, the length must be 2..6.
     pub fn region(mut self, code: &str) -> Self {
         self.region = Some(code.to_owned());
         self
     }
 
+    /// Sets the sequence code(0..=999), which must be odd for male and even for
+    /// female if the gender is set too, otherwise it decides the gender.
+    pub fn sequence(mut self, seq: u16) -> Self {
+        self.sequence = Some(seq);
+        self
+    }
+
+    /// Sets the version of the number, the years of birth are limited to
+    /// 1900~1999 for `IdVersion::V1`.
+    pub fn version(mut self, version: IdVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the date used in place of today, which bounds the dates of birth
+    /// and the ages, e.g. a seeded random number generator gives the same
+    /// numbers on any day with it.
+    pub fn as_of(mut self, date: NaiveDate) -> Self {
+        self.as_of = Some(date);
+        self
+    }
+
     /// Sets the gender to female.
     pub fn female(mut self) -> Self {
         self.gender = Some(Gender::Female);
@@ -94,104 +186,464 @@ impl FakeOptions {
     }
 }
 
+/// Generates valid options, so the generation fails only if there are no
+/// region codes.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FakeOptions {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let year = Local::now().year() as u32;
+        let mut options = FakeOptions::new();
+        if u.arbitrary()? {
+            let province = u.choose(&crate::Province::ALL)?;
+            if region::provider()
+                .codes_with_prefix(province.code())
+                .next()
+                .is_some()
+            {
+                options = options.region(province.code());
+            }
+        }
+        if u.arbitrary()? {
+            let min = u.int_in_range(1900..=year)?;
+            options = options.min_year(min);
+            if u.arbitrary()? {
+                options = options.max_year(u.int_in_range(min..=year)?);
+            }
+        }
+        if let Some(gender) = u.arbitrary::<Option<Gender>>()? {
+            options.gender = Some(gender);
+        }
+        Ok(options)
+    }
+}
+
 /// Generates a random fake ID number.
 pub fn rand() -> Result<String, Error> {
     let option = FakeOptions::new();
     rand_with(&option)
 }
 
+/// Generates a random legacy 15-digit fake ID number.
+pub fn rand_v1() -> Result<String, Error> {
+    rand_with(&FakeOptions::new().version(IdVersion::V1))
+}
+
 /// Generates a random fake ID number using the given options.
 pub fn rand_with(options: &FakeOptions) -> Result<String, Error> {
-    let region_code = if let Some(reg) = &options.region {
-        match region::rand_code_starts_with(&reg) {
-            Some(code) => code,
-            _ => {
-                return Err(Error::GenerateFakeIDError(
-                    "Invalid region code".to_string(),
-                ))
+    rand_with_rng(options, &mut thread_rng())
+}
+
+/// Generates a random fake ID number using the given options and random
+/// number generator, e.g. a seeded `StdRng` gives the same numbers every run
+/// if the date is fixed by `FakeOptions::as_of`(the dates of birth are bounded
+/// by today otherwise).
+pub fn rand_with_rng<R: Rng + ?Sized>(options: &FakeOptions, rng: &mut R) -> Result<String, Error> {
+    Ok(Plan::new(options)?.generate(options, rng)?.number)
+}
+
+/// Generates `n` unique random fake ID numbers using the given options, the
+/// options are checked once and the random number generator is shared.
+pub fn rand_many(n: usize, options: &FakeOptions) -> Result<Vec<String>, Error> {
+    let plan = Plan::new(options)?;
+    if n > plan.capacity(options) {
+        return Err(Error::GenerateFakeIDError(format!(
+            "Unable to generate {} unique numbers with the options",
+            n
+        )));
+    }
+
+    let mut rng = thread_rng();
+    let mut seen = HashSet::with_capacity(n);
+    let mut numbers = Vec::with_capacity(n);
+    while numbers.len() < n {
+        let number = plan.generate(options, &mut rng)?.number;
+        if seen.insert(number.clone()) {
+            numbers.push(number);
+        }
+    }
+    Ok(numbers)
+}
+
+/// Returns an infinite iterator of random fake ID numbers using the given
+/// options, it yields nothing if the options are invalid.
+pub fn iter(options: &FakeOptions) -> impl Iterator<Item = String> {
+    iter_with_rng(options, thread_rng())
+}
+
+/// Returns an infinite iterator of random fake ID numbers using the given
+/// options, which yields the same numbers for the same seed if the date is
+/// fixed by `FakeOptions::as_of`.
+pub fn iter_seeded(options: &FakeOptions, seed: u64) -> impl Iterator<Item = String> {
+    iter_with_rng(options, StdRng::seed_from_u64(seed))
+}
+
+fn iter_with_rng<R: Rng>(options: &FakeOptions, mut rng: R) -> impl Iterator<Item = String> {
+    let options = options.clone();
+    let plan = Plan::new(&options).ok();
+    std::iter::from_fn(move || {
+        let id = plan.as_ref()?.generate(&options, &mut rng).ok()?;
+        Some(id.number)
+    })
+}
+
+/// The candidates of the region codes and the dates of birth, computed from
+/// the options.
+#[derive(Debug, Clone)]
+struct Plan {
+    regions: Vec<&'static str>,
+    earliest: NaiveDate,
+    latest: NaiveDate,
+}
+
+impl Plan {
+    fn new(options: &FakeOptions) -> Result<Plan, Error> {
+        let provider = region::provider();
+        let mut regions: Vec<&'static str> = match options.region.as_deref() {
+            Some("") => Vec::new(),
+            prefix => provider
+                .codes_with_prefix(prefix.unwrap_or_default())
+                .collect(),
+        };
+        let exact = options.region.as_ref().is_some_and(|code| code.len() == 6);
+        if !options.any_level && !exact {
+            regions.retain(|code| is_leaf(provider, code));
+        }
+        if options.exclude_special {
+            regions.retain(|code| !is_special(code));
+        }
+        if regions.is_empty() {
+            return Err(Error::GenerateFakeIDError(
+                "Invalid region code".to_string(),
+            ));
+        }
+        let today = options.as_of.unwrap_or_else(|| Local::now().date_naive());
+
+        if let Some(value) = options.max_year {
+            if value > today.year() as u32 {
+                return Err(Error::GenerateFakeIDError(format!(
+                    "Max year must be less than or equal to {}",
+                    today.year()
+                )));
             }
         }
-    } else {
-        region::rand_code()
-    };
 
-    let mut rng = thread_rng();
-    let now = Local::now();
+        if let Some(value) = options.min_year {
+            if value > today.year() as u32 {
+                return Err(Error::GenerateFakeIDError(format!(
+                    "Min year must be less than or equal to {}",
+                    today.year()
+                )));
+            }
+        }
 
-    if let Some(value) = options.max_year {
-        if value > now.year() as u32 {
-            return Err(Error::GenerateFakeIDError(format!(
-                "Max year must be less than or equal to {}",
-                now.year()
-            )));
+        if options.min_year.is_some() && options.max_year.is_some() {
+            let min = options.min_year.unwrap();
+            let max = options.max_year.unwrap();
+            if max < min {
+                return Err(Error::GenerateFakeIDError(
+                    "Max year must be greater than or equal to min year".to_string(),
+                ));
+            }
         }
-    }
 
-    if let Some(value) = options.min_year {
-        if value > now.year() as u32 {
-            return Err(Error::GenerateFakeIDError(format!(
-                "Min year must be less than or equal to {}",
-                now.year()
-            )));
+        if let Some(seq) = options.sequence {
+            if seq > 999 {
+                return Err(Error::GenerateFakeIDError(
+                    "The sequence code must be less than 1000".to_string(),
+                ));
+            }
+            let gender = if seq % 2 == 1 {
+                Gender::Male
+            } else {
+                Gender::Female
+            };
+            if options.gender.as_ref().is_some_and(|g| *g != gender) {
+                return Err(Error::GenerateFakeIDError(
+                    "The parity of the sequence code doesn't match the gender".to_string(),
+                ));
+            }
         }
-    }
 
-    if options.min_year.is_some() && options.max_year.is_some() {
-        let min = options.min_year.unwrap();
-        let max = options.max_year.unwrap();
-        if max < min {
+        let mut earliest = match (options.min_year, options.age) {
+            (Some(year), _) => NaiveDate::from_ymd_opt(year as i32, 1, 1),
+            (None, Some(_)) => Some(NaiveDate::MIN),
+            (None, None) => NaiveDate::from_ymd_opt(today.year() - 100, 1, 1),
+        }
+        .unwrap_or(NaiveDate::MIN);
+        let mut latest = match options.max_year {
+            Some(year) => NaiveDate::from_ymd_opt(year as i32, 12, 31)
+                .unwrap_or(NaiveDate::MAX)
+                .min(today),
+            None => today,
+        };
+        if let Some((min_age, max_age)) = options.age {
+            if max_age < min_age {
+                return Err(Error::GenerateFakeIDError(
+                    "Max age must be greater than or equal to min age".to_string(),
+                ));
+            }
+            // Those born on the bound are of the age exactly, see
+            // `Identity::age_on`.
+            let before = |years: u32| {
+                years_before(today, years)
+                    .filter(|date| date.year() >= 0)
+                    .ok_or_else(|| {
+                        Error::GenerateFakeIDError(format!(
+                            "The age must be less than {}",
+                            today.year()
+                        ))
+                    })
+            };
+            latest = latest.min(before(min_age)?);
+            earliest = earliest.max(before(max_age.saturating_add(1))? + Duration::days(1));
+        }
+        if options.version == IdVersion::V1 {
+            earliest = earliest.max(NaiveDate::from_ymd_opt(1900, 1, 1).unwrap());
+            latest = latest.min(NaiveDate::from_ymd_opt(1999, 12, 31).unwrap());
+        }
+        if earliest > latest {
             return Err(Error::GenerateFakeIDError(
-                "Max year must be greater than or equal to min year".to_string(),
+                "No date of birth matches the years, the ages and the version".to_string(),
             ));
         }
+
+        Ok(Plan {
+            regions,
+            earliest,
+            latest,
+        })
     }
 
-    let min_age = if let Some(y) = options.max_year {
-        now.year() as u32 - y
-    } else {
-        0
-    };
-    let max_age = if let Some(y) = options.min_year {
-        now.year() as u32 - y
-    } else {
-        100
-    };
+    /// Returns the count of the distinct numbers which may be generated.
+    fn capacity(&self, options: &FakeOptions) -> usize {
+        let days = (self.latest - self.earliest).num_days() as usize + 1;
+        let sequences = match (options.sequence, &options.gender) {
+            (Some(_), _) => 1,
+            (None, Some(_)) => 500,
+            (None, None) => 1000,
+        };
+        self.regions
+            .len()
+            .saturating_mul(days)
+            .saturating_mul(sequences)
+    }
 
-    let age = if max_age == min_age {
-        max_age
-    } else {
-        rng.gen_range(min_age..=max_age)
-    };
+    fn generate<R: Rng + ?Sized>(
+        &self,
+        options: &FakeOptions,
+        rng: &mut R,
+    ) -> Result<FakeIdentity, Error> {
+        let region_code = *self.regions.choose(rng).unwrap();
+        let days = rng.gen_range(0..=(self.latest - self.earliest).num_days());
+        let birth = self.earliest + Duration::days(days);
+        let (gender, sequence) = match (options.sequence, &options.gender) {
+            (Some(seq), _) if seq % 2 == 1 => (Gender::Male, seq),
+            (Some(seq), _) => (Gender::Female, seq),
+            (None, gender) => {
+                let gender = gender.clone().unwrap_or_else(|| {
+                    if rng.gen() {
+                        Gender::Male
+                    } else {
+                        Gender::Female
+                    }
+                });
+                let seq = rng.gen_range(0..500) * 2 + u16::from(gender == Gender::Male);
+                (gender, seq)
+            }
+        };
+        let number = crate::compose(region_code, birth, sequence)?;
+        let number = match options.version {
+            IdVersion::V1 => crate::downgrade(&number)?,
+            IdVersion::V2 => number,
+        };
+        Ok(FakeIdentity {
+            number,
+            region_code,
+            region: region::provider().name(region_code),
+            birth_date: birth,
+            gender,
+            sequence,
+        })
+    }
+}
+
+/// A distribution of the random valid identities, generated using the options,
+/// e.g. `rng.sample(FakeDistribution::default())`.
+#[derive(Debug, Clone)]
+pub struct FakeDistribution {
+    options: FakeOptions,
+    plan: Option<Plan>,
+}
+
+impl FakeDistribution {
+    /// Creates a distribution using the given options, returns an error if the
+    /// options are invalid.
+    pub fn new(options: &FakeOptions) -> Result<Self, Error> {
+        Ok(FakeDistribution {
+            options: options.clone(),
+            plan: Some(Plan::new(options)?),
+        })
+    }
+}
+
+impl Default for FakeDistribution {
+    /// Creates a distribution using the default options, the sampling panics if
+    /// there are no region codes(i.e. neither `region-minimal` nor a custom
+    /// provider).
+    fn default() -> Self {
+        let options = FakeOptions::default();
+        let plan = Plan::new(&options).ok();
+        FakeDistribution { options, plan }
+    }
+}
 
-    let days = rng.gen_range(1..365);
-    let dt = Local.with_ymd_and_hms(now.year(), 1, 1, 0, 0, 0).unwrap();
-    let birth = dt - Duration::days((age * 365 - days) as i64);
-    let gender = if let Some(value) = &options.gender {
-        match value {
-            Gender::Male => Gender::Male,
-            Gender::Female => Gender::Female,
+impl Distribution<Identity> for FakeDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Identity {
+        let plan = self
+            .plan
+            .as_ref()
+            .expect("no region codes to generate from");
+        let id = plan
+            .generate(&self.options, rng)
+            .expect("failed to generate a fake identity");
+        id.to_identity()
+    }
+}
+
+/// A generated fake ID number with the information it's generated from, so
+/// there's no need to parse it again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeIdentity {
+    /// The ID number.
+    pub number: String,
+    /// The 6-digit region code.
+    pub region_code: &'static str,
+    /// The region name, it's `None` without the region names.
+    pub region: Option<&'static str>,
+    /// The date of birth.
+    pub birth_date: NaiveDate,
+    /// The gender.
+    pub gender: Gender,
+    /// The sequence code.
+    pub sequence: u16,
+}
+
+impl FakeIdentity {
+    /// Converts into an identity object.
+    pub fn to_identity(&self) -> Identity {
+        Identity::new(&self.number)
+    }
+}
+
+/// Generates a random fake identity using the given options.
+pub fn rand_identity(options: &FakeOptions) -> Result<FakeIdentity, Error> {
+    Plan::new(options)?.generate(options, &mut thread_rng())
+}
+
+/// The defects of the invalid fake ID numbers, each of them fails the
+/// validation for the reason of the same name in `ValidationError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefectKind {
+    /// The check digit doesn't match the first 17 digits.
+    WrongChecksum,
+    /// The date of birth doesn't exist, e.g. Feb 30, the check digit matches.
+    ImpossibleDate,
+    /// The region code doesn't exist, while the province does, so only the
+    /// strict validation fails.
+    UnknownRegion,
+    /// A digit is missing or redundant.
+    WrongLength,
+    /// A digit of the first 17 is replaced by a letter.
+    NonDigit,
+}
+
+/// Generates a random 18-digit ID number with the given defect, which looks
+/// realistic otherwise.
+pub fn invalid(kind: DefectKind) -> Result<String, Error> {
+    let mut rng = thread_rng();
+    // The special codes aren't looked up in the region database.
+    let options = FakeOptions::new().exclude_special(kind == DefectKind::UnknownRegion);
+    let number = rand_with_rng(&options, &mut rng)?;
+    let mut bytes = number.into_bytes();
+    match kind {
+        DefectKind::WrongChecksum => {
+            let check = bytes[17];
+            bytes[17] = *b"0123456789X"
+                .iter()
+                .filter(|&&b| b != check)
+                .choose(&mut rng)
+                .unwrap();
         }
-    } else {
-        let flag = rng.gen_range(0..10);
-        if flag % 2 == 0 {
-            Gender::Male
-        } else {
-            Gender::Female
+        DefectKind::ImpossibleDate => {
+            let mmdd = [
+                "0230", "0231", "0431", "0631", "0931", "1131", "1301", "0001", "0100",
+            ]
+            .choose(&mut rng)
+            .unwrap();
+            bytes[10..14].copy_from_slice(mmdd.as_bytes());
+            bytes[17] = check_code_of(&bytes[0..17]);
+        }
+        DefectKind::UnknownRegion => {
+            let provider = region::provider();
+            let prefix = String::from_utf8_lossy(&bytes[0..2]).into_owned();
+            let year = String::from_utf8_lossy(&bytes[6..10]).parse().unwrap_or(0);
+            let code = (0..10000)
+                .map(|suffix| format!("{}{:0>4}", prefix, suffix))
+                .filter(|code| !provider.contains_at(code, year))
+                .choose(&mut rng)
+                .ok_or_else(|| {
+                    Error::GenerateFakeIDError(format!(
+                        "No unknown region code starts with {}",
+                        prefix
+                    ))
+                })?;
+            bytes[0..6].copy_from_slice(code.as_bytes());
+            bytes[17] = check_code_of(&bytes[0..17]);
+        }
+        DefectKind::WrongLength => {
+            if rng.gen() {
+                bytes.remove(rng.gen_range(0..18));
+            } else {
+                bytes.insert(rng.gen_range(0..18), b'0' + rng.gen_range(0..10));
+            }
+        }
+        DefectKind::NonDigit => {
+            let letter = *b"ABCDEFGHIJKLMNOPQRSTUVWYZ".choose(&mut rng).unwrap();
+            bytes[rng.gen_range(0..17)] = letter;
         }
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Checks if the region code is not of the ordinary mainland residents.
+fn is_special(code: &str) -> bool {
+    matches!(&code[0..2], "71" | "81" | "82" | "83" | "91")
+        || crate::residence_permit_region(code).is_some()
+}
+
+/// Checks if the region has no subdivisions.
+fn is_leaf(provider: &dyn region::RegionProvider, code: &str) -> bool {
+    let prefix = if code.ends_with("0000") {
+        &code[0..2]
+    } else if code.ends_with("00") {
+        &code[0..4]
+    } else {
+        return true;
     };
-    new(
-        &region_code,
-        birth.year() as u32,
-        birth.month() as u32,
-        birth.day() as u32,
-        gender,
-    )
+    !provider.codes_with_prefix(prefix).any(|c| c != code)
+}
+
+/// Returns the date the given years before, Feb 29 gives Feb 28 in a common
+/// year, or `None` if it's out of the range of `NaiveDate`.
+fn years_before(date: NaiveDate, years: u32) -> Option<NaiveDate> {
+    let year = date.year().checked_sub(i32::try_from(years).ok()?)?;
+    date.with_year(year)
+        .or_else(|| NaiveDate::from_ymd_opt(year, 2, 28))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Identity;
 
     #[test]
     fn test_new() {
@@ -211,6 +663,262 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_rand_with_rng() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let opts = FakeOptions::new().region("3301").min_year(1990);
+        let gen = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..5)
+                .map(|_| rand_with_rng(&opts, &mut rng).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let nums = gen(42);
+        assert_eq!(nums, gen(42));
+        assert_ne!(nums, gen(43));
+        assert!(nums.iter().all(|num| crate::validate(num)));
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let a = new_with_rng("654325", 2018, 2, 28, Gender::Male, &mut rng).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let b = new_with_rng("654325", 2018, 2, 28, Gender::Male, &mut rng).unwrap();
+        assert_eq!(a, b);
+
+        let date = NaiveDate::from_ymd_opt(2000, 6, 15).unwrap();
+        let opts = FakeOptions::new().region("3301").age(30).as_of(date);
+        let nums = iter_seeded(&opts, 42).take(5).collect::<Vec<_>>();
+        assert_eq!(nums, iter_seeded(&opts, 42).take(5).collect::<Vec<_>>());
+        for num in nums {
+            assert_eq!(Identity::new(&num).age_on(date), Some(30));
+        }
+        assert!(rand_with(&FakeOptions::new().max_year(2001).as_of(date)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_age() {
+        let today = Local::now().date_naive();
+        let opts = FakeOptions::new().age(17);
+        for _ in 0..100 {
+            let id = Identity::new(&rand_with(&opts).unwrap());
+            assert_eq!(id.age_on(today), Some(17));
+        }
+
+        let opts = FakeOptions::new().age_between(0, 1).female();
+        for _ in 0..100 {
+            let id = Identity::new(&rand_with(&opts).unwrap());
+            assert!(id.age_on(today).unwrap() <= 1);
+        }
+
+        let year = today.year() as u32;
+        assert!(rand_with(&FakeOptions::new().age_between(30, 20)).is_err());
+        assert!(rand_with(&FakeOptions::new().age(30).max_year(year - 10)).is_ok());
+        assert!(rand_with(&FakeOptions::new().age(30).min_year(year - 10)).is_err());
+        let id = Identity::new(&rand_with(&FakeOptions::new().age(120)).unwrap());
+        assert_eq!(id.age_on(today), Some(120));
+        assert!(rand_with(&FakeOptions::new().age(u32::MAX)).is_err());
+        assert!(rand_with(&FakeOptions::new().age_between(0, u32::MAX)).is_err());
+        assert!(rand_with(&FakeOptions::new().age(today.year() as u32)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_sequence() {
+        let opts = FakeOptions::new().region("330106").sequence(7);
+        let id = Identity::new(&rand_with(&opts).unwrap());
+        assert!(id.is_valid());
+        assert_eq!(id.sequence_code(), Some("007"));
+        assert_eq!(id.gender(), Some(Gender::Male));
+
+        let opts = FakeOptions::new().sequence(120).female();
+        let id = Identity::new(&rand_with(&opts).unwrap());
+        assert_eq!(id.sequence_code(), Some("120"));
+
+        assert!(rand_with(&FakeOptions::new().sequence(120).male()).is_err());
+        assert!(rand_with(&FakeOptions::new().sequence(1000)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_rand_v1() {
+        for _ in 0..20 {
+            let num = rand_v1().unwrap();
+            assert_eq!(num.len(), 15);
+            assert!(crate::validate(&num));
+        }
+        let opts = FakeOptions::new()
+            .version(IdVersion::V1)
+            .min_year(1990)
+            .sequence(12);
+        let num = rand_with(&opts).unwrap();
+        assert!(num.ends_with("012"));
+        let id = Identity::new(&num);
+        assert!(id.year().unwrap() >= 1990 && id.year().unwrap() <= 1999);
+        assert!(rand_with(&opts.min_year(2000)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_invalid() {
+        use crate::{validate_detailed, validate_strict_detailed, ValidationError};
+
+        for _ in 0..20 {
+            let num = invalid(DefectKind::WrongChecksum).unwrap();
+            assert_eq!(
+                validate_detailed(&num),
+                Err(ValidationError::ChecksumMismatch)
+            );
+            let num = invalid(DefectKind::ImpossibleDate).unwrap();
+            assert_eq!(
+                validate_detailed(&num),
+                Err(ValidationError::InvalidBirthDate)
+            );
+            let num = invalid(DefectKind::UnknownRegion).unwrap();
+            assert_eq!(validate_detailed(&num), Ok(()));
+            assert_eq!(
+                validate_strict_detailed(&num),
+                Err(ValidationError::UnknownRegion)
+            );
+            let num = invalid(DefectKind::WrongLength).unwrap();
+            assert_eq!(validate_detailed(&num), Err(ValidationError::WrongLength));
+            let num = invalid(DefectKind::NonDigit).unwrap();
+            assert_eq!(
+                validate_detailed(&num),
+                Err(ValidationError::NonDigitCharacter)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_rand_many() {
+        let opts = FakeOptions::new().region("3301").age(30);
+        let nums = rand_many(1000, &opts).unwrap();
+        assert_eq!(nums.len(), 1000);
+        assert_eq!(nums.iter().collect::<HashSet<_>>().len(), 1000);
+        assert!(nums.iter().all(|num| crate::validate(num)));
+
+        let opts = FakeOptions::new()
+            .region("330106")
+            .min_year(2000)
+            .max_year(2000)
+            .sequence(1);
+        assert_eq!(rand_many(366, &opts).unwrap().len(), 366);
+        assert!(rand_many(367, &opts).is_err());
+        assert!(rand_many(0, &opts).unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_iter() {
+        let opts = FakeOptions::new().region("11").female();
+        let nums: Vec<String> = iter(&opts).take(10).collect();
+        assert_eq!(nums.len(), 10);
+        assert!(nums.iter().all(|num| crate::validate(num)));
+
+        let a: Vec<String> = iter_seeded(&opts, 7).take(5).collect();
+        let b: Vec<String> = iter_seeded(&opts, 7).take(5).collect();
+        assert_eq!(a, b);
+
+        let opts = FakeOptions::new().min_year(2000).max_year(1990);
+        assert_eq!(iter(&opts).next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_rand_identity() {
+        let opts = FakeOptions::new().region("511702").min_year(1990).male();
+        let fake = rand_identity(&opts).unwrap();
+        let id = fake.to_identity();
+        assert!(id.is_valid());
+        assert_eq!(fake.region_code, "511702");
+        assert_eq!(id.birth_naive_date(), Some(fake.birth_date));
+        assert_eq!(id.gender(), Some(fake.gender.clone()));
+        assert_eq!(fake.gender, Gender::Male);
+        assert_eq!(id.sequence_code(), Some(&*format!("{:0>3}", fake.sequence)));
+        #[cfg(feature = "region-names")]
+        assert_eq!(fake.region, Some("四川省达州市通川区"));
+
+        let fake = rand_identity(&opts.version(IdVersion::V1)).unwrap();
+        assert_eq!(fake.number.len(), 15);
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_any_level() {
+        let provider = region::provider();
+        assert!(!is_leaf(provider, "330000"));
+        assert!(!is_leaf(provider, "330100"));
+        assert!(is_leaf(provider, "330106"));
+        assert!(is_leaf(provider, "441900"));
+
+        let opts = FakeOptions::new().region("33");
+        for num in iter(&opts).take(200) {
+            assert!(is_leaf(provider, &num[0..6]), "{}", num);
+        }
+        let opts = FakeOptions::new().region("3301").any_level(true);
+        assert!(iter(&opts).take(500).any(|num| num.starts_with("330100")));
+        let opts = FakeOptions::new().region("330100");
+        assert!(rand_with(&opts).unwrap().starts_with("330100"));
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_exclude_special() {
+        assert!(is_special("710000"));
+        assert!(is_special("810000"));
+        assert!(!is_special("110101"));
+
+        let opts = FakeOptions::new().exclude_special(true);
+        assert!(rand_many(2000, &opts)
+            .unwrap()
+            .iter()
+            .all(|num| !is_special(&num[0..6])));
+        assert!(rand_with(&FakeOptions::new().region("81")).is_ok());
+        assert!(rand_with(&FakeOptions::new().region("81").exclude_special(true)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_distribution() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let id: Identity = rng.sample(FakeDistribution::default());
+        assert!(id.is_valid());
+
+        let dist = FakeDistribution::new(&FakeOptions::new().region("11").male()).unwrap();
+        let ids: Vec<Identity> = dist.sample_iter(&mut rng).take(10).collect();
+        assert!(ids
+            .iter()
+            .all(|id| id.is_valid() && id.gender() == Some(Gender::Male)));
+        assert!(FakeDistribution::new(&FakeOptions::new().region("")).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "arbitrary", feature = "region-minimal"))]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..50 {
+            let opts = FakeOptions::arbitrary(&mut u).unwrap();
+            assert!(rand_with(&opts).is_ok(), "{:?}", opts);
+        }
+    }
+
+    #[test]
+    fn test_years_before() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(years_before(date, 4), date.with_year(2020));
+        assert_eq!(years_before(date, 1), NaiveDate::from_ymd_opt(2023, 2, 28));
+        assert_eq!(years_before(date, 1_000_000), None);
+        assert_eq!(years_before(date, u32::MAX), None);
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
     fn test_rand() {
         for i in 1..=10 {
             let num = rand().unwrap();
@@ -219,6 +927,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "region-minimal")]
     fn test_rand_with_options() {
         let opts = FakeOptions::new()
             .region("3301")
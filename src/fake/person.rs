@@ -0,0 +1,124 @@
+//! Fake person profiles, the name, the address and the mobile number are
+//! generated to be consistent with the ID number.
+
+use super::{FakeIdentity, FakeOptions, Plan};
+use crate::{Error, Gender};
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+/// The common surnames, roughly in the order of the population.
+static SURNAMES: [&str; 60] = [
+    "王", "李", "张", "刘", "陈", "杨", "黄", "赵", "吴", "周", "徐", "孙", "马", "朱", "胡", "郭",
+    "何", "林", "罗", "高", "郑", "梁", "谢", "宋", "唐", "许", "韩", "冯", "邓", "曹", "彭", "曾",
+    "肖", "田", "董", "袁", "潘", "于", "蒋", "蔡", "余", "杜", "叶", "程", "苏", "魏", "吕", "丁",
+    "任", "沈", "姚", "卢", "姜", "崔", "钟", "谭", "陆", "汪", "范", "欧阳",
+];
+
+/// The characters common in the given names of males.
+static MALE_CHARS: [&str; 40] = [
+    "伟", "强", "磊", "军", "洋", "勇", "杰", "涛", "明", "超", "刚", "平", "辉", "鹏", "华", "飞",
+    "鑫", "波", "斌", "宇", "浩", "凯", "健", "俊", "帆", "帅", "旭", "宁", "龙", "林", "成", "建",
+    "国", "峰", "博", "文", "志", "海", "东", "然",
+];
+
+/// The characters common in the given names of females.
+static FEMALE_CHARS: [&str; 40] = [
+    "芳", "娜", "敏", "静", "丽", "艳", "娟", "霞", "秀", "燕", "玲", "婷", "慧", "莹", "雪", "琳",
+    "晶", "颖", "倩", "洁", "红", "梅", "萍", "欣", "怡", "佳", "悦", "思", "涵", "雨", "月", "蕾",
+    "薇", "婉", "琪", "菲", "瑶", "璐", "晓", "可",
+];
+
+/// The common road names.
+static ROADS: [&str; 20] = [
+    "人民", "解放", "中山", "建设", "和平", "新华", "文化", "胜利", "健康", "友谊", "光明", "幸福",
+    "长江", "黄河", "青年", "朝阳", "滨江", "东风", "迎宾", "振兴",
+];
+
+/// The prefixes of the mobile numbers of the carriers.
+static MOBILE_PREFIXES: [&str; 30] = [
+    "130", "131", "132", "133", "134", "135", "136", "137", "138", "139", "150", "151", "152",
+    "153", "155", "156", "157", "158", "159", "177", "180", "181", "182", "183", "185", "186",
+    "187", "188", "189", "199",
+];
+
+/// A fake person profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Person {
+    /// The name, matching the gender.
+    pub name: String,
+    /// The ID number and the information it's generated from.
+    pub identity: FakeIdentity,
+    /// The address in the region of the ID number, it's `None` without the
+    /// region names.
+    pub address: Option<String>,
+    /// The 11-digit mobile number.
+    pub mobile: String,
+}
+
+/// Generates a fake person profile using the given options.
+pub fn person(options: &FakeOptions) -> Result<Person, Error> {
+    let mut rng = thread_rng();
+    let identity = Plan::new(options)?.generate(options, &mut rng)?;
+    Ok(Person {
+        name: name(&identity.gender, &mut rng),
+        address: identity.region.map(|region| address(region, &mut rng)),
+        mobile: mobile(&mut rng),
+        identity,
+    })
+}
+
+fn name<R: Rng + ?Sized>(gender: &Gender, rng: &mut R) -> String {
+    let chars = match gender {
+        Gender::Male => &MALE_CHARS,
+        Gender::Female => &FEMALE_CHARS,
+    };
+    let mut name = SURNAMES.choose(rng).unwrap().to_string();
+    for _ in 0..rng.gen_range(1..=2) {
+        name.push_str(chars.choose(rng).unwrap());
+    }
+    name
+}
+
+fn address<R: Rng + ?Sized>(region: &str, rng: &mut R) -> String {
+    format!(
+        "{}{}路{}号",
+        region,
+        ROADS.choose(rng).unwrap(),
+        rng.gen_range(1..300)
+    )
+}
+
+fn mobile<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let mut mobile = MOBILE_PREFIXES.choose(rng).unwrap().to_string();
+    for _ in 0..8 {
+        mobile.push(char::from(b'0' + rng.gen_range(0..10)));
+    }
+    mobile
+}
+
+#[cfg(all(test, feature = "region-minimal"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_person() {
+        let opts = FakeOptions::new().region("511702").female();
+        for _ in 0..20 {
+            let p = person(&opts).unwrap();
+            assert!(p.identity.to_identity().is_valid());
+            assert_eq!(p.identity.gender, Gender::Female);
+            let len = p.name.chars().count();
+            assert!((2..=4).contains(&len));
+            assert!(FEMALE_CHARS.iter().any(|c| p.name.ends_with(c)));
+            assert_eq!(p.mobile.len(), 11);
+            assert!(p.mobile.starts_with('1'));
+            #[cfg(feature = "region-names")]
+            assert!(p
+                .address
+                .as_deref()
+                .unwrap()
+                .starts_with("四川省达州市通川区"));
+        }
+        assert!(person(&FakeOptions::new().region("")).is_err());
+    }
+}
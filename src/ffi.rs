@@ -0,0 +1,186 @@
+//! C bindings
+//!
+//! Build with `cargo rustc --release --features ffi --crate-type cdylib` and
+//! link against the `cdylib`(e.g. `libidcard.so`), the declarations are in
+//! `include/idcard.h`.
+//! The strings are NUL-terminated UTF-8, the strings returned must be released
+//! by `idcard_free_string`.
+
+use crate::fake::{self, FakeOptions};
+use crate::Identity;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+/// Any gender, for `idcard_fake`.
+pub const IDCARD_GENDER_ANY: c_int = 0;
+/// Male, for `idcard_fake`.
+pub const IDCARD_GENDER_MALE: c_int = 1;
+/// Female, for `idcard_fake`.
+pub const IDCARD_GENDER_FEMALE: c_int = 2;
+
+/// Borrows the C string as `&str`, `None` if it's NULL or not UTF-8.
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+fn into_raw(s: String) -> *mut c_char {
+    CString::new(s).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Checks if the 15/18-digit number is valid, NULL is invalid.
+///
+/// # Safety
+///
+/// `number` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn idcard_validate(number: *const c_char) -> bool {
+    to_str(number).is_some_and(crate::validate)
+}
+
+/// Returns the detailed information of the number as a JSON string, or NULL if
+/// the number is invalid.
+///
+/// # Safety
+///
+/// `number` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn idcard_info_json(number: *const c_char) -> *mut c_char {
+    match to_str(number).and_then(|number| Identity::new(number).to_json_string()) {
+        Some(json) => into_raw(json),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Generates a fake number, or returns NULL if the options are invalid. The
+/// `region` may be NULL, the `gender` is one of the `IDCARD_GENDER_*`, and a
+/// `min_year` or `max_year` of 0 means no limit.
+///
+/// # Safety
+///
+/// `region` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn idcard_fake(
+    region: *const c_char,
+    gender: c_int,
+    min_year: u32,
+    max_year: u32,
+) -> *mut c_char {
+    let mut options = FakeOptions::new();
+    if !region.is_null() {
+        match to_str(region) {
+            Some(region) => options = options.region(region),
+            None => return ptr::null_mut(),
+        }
+    }
+    options = match gender {
+        IDCARD_GENDER_ANY => options,
+        IDCARD_GENDER_MALE => options.male(),
+        IDCARD_GENDER_FEMALE => options.female(),
+        _ => return ptr::null_mut(),
+    };
+    if min_year != 0 {
+        options = options.min_year(min_year);
+    }
+    if max_year != 0 {
+        options = options.max_year(max_year);
+    }
+    match fake::rand_with(&options) {
+        Ok(number) => into_raw(number),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Releases a string returned by this library, NULL is ignored.
+///
+/// # Safety
+///
+/// `s` must be NULL or a string returned by this library which has not been
+/// released yet.
+#[no_mangle]
+pub unsafe extern "C" fn idcard_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cstr(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    /// Takes the string returned by the library.
+    unsafe fn take(s: *mut c_char) -> Option<String> {
+        if s.is_null() {
+            return None;
+        }
+        let owned = CStr::from_ptr(s).to_str().unwrap().to_owned();
+        idcard_free_string(s);
+        Some(owned)
+    }
+
+    #[test]
+    fn test_ffi() {
+        unsafe {
+            assert!(idcard_validate(cstr("632123820927051").as_ptr()));
+            assert!(!idcard_validate(cstr("632123198209270519").as_ptr()));
+            assert!(!idcard_validate(ptr::null()));
+
+            let json = take(idcard_info_json(cstr("632123820927051").as_ptr())).unwrap();
+            assert!(json.starts_with(r#"{"number":"632123198209270518","gender":"Male""#));
+            assert_eq!(take(idcard_info_json(cstr("123").as_ptr())), None);
+
+            let number = take(idcard_fake(
+                cstr("3301").as_ptr(),
+                IDCARD_GENDER_FEMALE,
+                1990,
+                2000,
+            ));
+            let id = Identity::new(&number.unwrap());
+            assert!(id.number().starts_with("3301"));
+            assert_eq!(id.gender(), Some(crate::Gender::Female));
+            assert!((1990..=2000).contains(&id.year().unwrap()));
+            assert!(take(idcard_fake(ptr::null(), IDCARD_GENDER_ANY, 0, 0)).is_some());
+            assert_eq!(take(idcard_fake(ptr::null(), 3, 0, 0)), None);
+            assert_eq!(
+                take(idcard_fake(cstr("00").as_ptr(), IDCARD_GENDER_ANY, 0, 0)),
+                None
+            );
+
+            idcard_free_string(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_header() {
+        let header = include_str!("../include/idcard.h");
+        for name in [
+            "idcard_validate",
+            "idcard_info_json",
+            "idcard_fake",
+            "idcard_free_string",
+            "IDCARD_GENDER_ANY 0",
+            "IDCARD_GENDER_MALE 1",
+            "IDCARD_GENDER_FEMALE 2",
+        ] {
+            assert!(header.contains(name), "{} is not declared", name);
+        }
+    }
+
+    #[test]
+    fn test_header_up_to_date() {
+        let generated = concat!(env!("OUT_DIR"), "/idcard.h");
+        assert!(
+            include_str!(concat!(env!("OUT_DIR"), "/idcard.h"))
+                == include_str!("../include/idcard.h"),
+            "include/idcard.h is out of date, copy {} over it",
+            generated
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Utilities for Foreign Permanent Resident ID Card
+//!
+//! Two generations of the cards are in use: the 18-digit one issued since 2023
+//! which shares the format(and the check digit) with the mainland ID, and the
+//! legacy 15-character one issued since 2004, e.g. `USA119001011221`:
+//!
+//! - 1~3: the nationality(ISO 3166-1 alpha-3)
+//! - 4~5: the code of the issuing province
+//! - 6~11: the date of birth(yymmdd)
+//! - 12~13: the sequence code
+//! - 14: the check digit
+//! - 15: the version code
+
+use crate::date::Date;
+use crate::{normalize, Province};
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+const LEGACY_LEN: usize = 15;
+
+/// The weights of the check digit of a legacy number, repeated over the first
+/// 13 characters.
+static LEGACY_WEIGHTS: [u32; 3] = [7, 3, 1];
+
+/// Validates the number of either generation.
+pub fn validate(number: &str) -> bool {
+    let number = normalize(number);
+    if number.len() == LEGACY_LEN {
+        validate_legacy(&number)
+    } else {
+        number.starts_with('9') && crate::validate(&number)
+    }
+}
+
+/// Validates a legacy 15-character number.
+pub fn validate_legacy(number: &str) -> bool {
+    let number = normalize(number);
+    let bytes = number.as_bytes();
+    if bytes.len() != LEGACY_LEN
+        || !bytes[0..3].iter().all(u8::is_ascii_uppercase)
+        || !bytes[3..].iter().all(u8::is_ascii_digit)
+    {
+        return false;
+    }
+    if Province::from_code(&number[3..5]).is_none() || birth_date_of(&number).is_none() {
+        return false;
+    }
+
+    let sum: u32 = bytes[0..13]
+        .iter()
+        .zip(LEGACY_WEIGHTS.iter().cycle())
+        .map(|(&b, w)| char_value(b) * w)
+        .sum();
+    u32::from(bytes[13] - b'0') == sum % 10
+}
+
+/// Returns the nationality of a valid number, the alpha-3 code for a legacy
+/// number(e.g. `USA`) and the numeric code for an 18-digit one(e.g. `840`).
+pub fn nationality_code(number: &str) -> Option<String> {
+    if !validate(number) {
+        return None;
+    }
+    let number = normalize(number);
+    if number.len() == LEGACY_LEN {
+        Some(number[0..3].to_owned())
+    } else {
+        Some(number[3..6].to_owned())
+    }
+}
+
+/// Returns the date of birth of a valid number.
+#[cfg(feature = "chrono")]
+pub fn birth_date(number: &str) -> Option<NaiveDate> {
+    if !validate(number) {
+        return None;
+    }
+    let number = normalize(number);
+    if number.len() == LEGACY_LEN {
+        birth_date_of(&number).map(NaiveDate::from)
+    } else {
+        crate::Identity::new(&number).birth_naive_date()
+    }
+}
+
+/// Parses the yymmdd date of birth of a legacy number, the century is taken
+/// as the latest one that doesn't put it in the future.
+fn birth_date_of(number: &str) -> Option<Date> {
+    let yy: i32 = number.get(5..7)?.parse().ok()?;
+    let month = number.get(7..9)?.parse().ok()?;
+    let day = number.get(9..11)?.parse().ok()?;
+    let today = Date::today();
+    [2000, 1900]
+        .iter()
+        .filter_map(|century| Date::new(century + yy, month, day))
+        .find(|&date| date <= today)
+}
+
+/// Returns the value of a character in the check digit, `A` to `Z` are 10 to
+/// 35.
+fn char_value(b: u8) -> u32 {
+    if b.is_ascii_digit() {
+        u32::from(b - b'0')
+    } else {
+        u32::from(b - b'A') + 10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_legacy() {
+        assert!(validate_legacy("USA119001011221"));
+        assert!(validate_legacy("usa 119001011221"));
+        assert!(validate_legacy("CAN318512310101"));
+        assert!(validate_legacy("GBR440002290501"));
+        assert!(!validate_legacy("USA119001011231"));
+        assert!(!validate_legacy("USA109001011221"));
+        assert!(!validate_legacy("USA119002301221"));
+        assert!(!validate_legacy("US1119001011221"));
+        assert!(!validate_legacy("USA11900101122"));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("USA119001011221"));
+        assert!(validate("91184019900101123X"));
+        assert!(!validate("230127197908177456"));
+        assert!(!validate("91184019900101123"));
+    }
+
+    #[test]
+    fn test_info() {
+        assert_eq!(nationality_code("USA119001011221").as_deref(), Some("USA"));
+        assert_eq!(
+            nationality_code("91184019900101123X").as_deref(),
+            Some("840")
+        );
+        assert_eq!(nationality_code("USA119001011231"), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            birth_date("USA119001011221"),
+            NaiveDate::from_ymd_opt(1990, 1, 1)
+        );
+        assert_eq!(
+            birth_date("GBR440002290501"),
+            NaiveDate::from_ymd_opt(2000, 2, 29)
+        );
+        assert_eq!(
+            birth_date("91184019900101123X"),
+            NaiveDate::from_ymd_opt(1990, 1, 1)
+        );
+    }
+}
@@ -1,91 +1,308 @@
 //! Utilities for Hong Kong Identity Card
 
-use regex::Regex;
-use std::collections::HashMap;
-
-lazy_static! {
-    static ref PREFIX_LETTERS: HashMap<&'static str, u32> = {
-        let mut map = HashMap::new();
-        map.insert("A", 1);
-        map.insert("B", 2);
-        map.insert("C", 3);
-        map.insert("R", 18);
-        map.insert("U", 21);
-        map.insert("Z", 26);
-        map.insert("X", 24);
-        map.insert("W", 23);
-        map.insert("O", 15);
-        map.insert("N", 14);
-        map
-    };
-    static ref PATTERN: Regex = Regex::new(r"^[A-Z]{1,2}[0-9]{6}\(?[0-9A]\)?$").unwrap();
-    static ref REMOVAL_PATTERN: Regex = Regex::new(r"[\(|\)]").unwrap();
-}
+use crate::Error;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// The prefix letters in alphabetical order, with the known notes on their
+/// issuance. Any letter is accepted by `validate`.
+static PREFIX_LETTERS: [(char, Option<&str>); 26] = [
+    ('A', Some("Issued between 1949 and 1962")),
+    ('B', Some("Issued between 1955 and 1960 in the city offices")),
+    ('C', Some("Issued between 1960 and 1983 in the New Territories offices")),
+    ('D', Some("Issued between 1960 and 1983 in the Hong Kong Island office")),
+    ('E', Some("Issued between 1955 and 1969 in the Kowloon office")),
+    ('F', Some("Issued to the first-time applicants since 24 February 2020")),
+    ('G', Some("Issued between 1967 and 1983 in the Kowloon office")),
+    ('H', Some("Issued between 1979 and 1983 in the Hong Kong Island office")),
+    ('I', None),
+    ('J', Some("Issued to the consular officers")),
+    ('K', Some("Issued to the first-time applicants between 28 March 1983 and 31 July 1990")),
+    ('L', Some("Issued between 1983 and 2003 when the computer system was down")),
+    ('M', Some("Issued to the first-time applicants between 1 August 2011 and 23 February 2020")),
+    ('N', Some("Births registered in Hong Kong since 1 June 2019")),
+    ('O', None),
+    ('P', Some("Issued to the first-time applicants between 1 August 1990 and 27 December 2000")),
+    ('Q', None),
+    ('R', Some("Issued to the first-time applicants between 28 December 2000 and 31 July 2011")),
+    ('S', Some("Births registered in Hong Kong between 1 April 2005 and 31 May 2019")),
+    ('T', Some("Issued between 1983 and 1997 when the computer system was down")),
+    ('U', None),
+    ('V', Some("Issued between 1983 and 2003 to the children under 11 holding a Document of Identity for Visa Purposes")),
+    ('W', Some("Issued to the foreign workers and domestic helpers since 10 November 1989")),
+    ('X', None),
+    ('Y', Some("Births registered in Hong Kong between 1 January 1989 and 31 March 2005")),
+    ('Z', Some("Births registered in Hong Kong between 1 January 1980 and 31 December 1988")),
+];
 
 /// Validates the number.
 pub fn validate(number: &str) -> bool {
-    if !PATTERN.is_match(number) {
-        return false;
-    }
-
-    let number = REMOVAL_PATTERN
-        .replace_all(number, "")
-        .trim()
-        .to_ascii_uppercase();
-
-    let mut sum: u32;
-    let mut card = &number[..];
-    if number.len() == 9 {
-        let first = match number.chars().nth(0) {
-            Some(ch) => ch as u32,
-            _ => return false,
-        };
-        let second = match number.chars().nth(1) {
-            Some(ch) => ch as u32,
-            _ => return false,
-        };
-        sum = (first - 55) * 9 + (second - 55) * 8;
-        card = &number[1..9];
+    match split(number) {
+        Some((body, check)) => check_digit(body) == Some(char::from(check)),
+        None => false,
+    }
+}
+
+/// Splits a well-formed number, i.e. 1 or 2 uppercase letters, 6 digits and
+/// the check character(`0` to `9`, or `A`) in the optional parentheses, into
+/// the part before the check character and the check character.
+fn split(number: &str) -> Option<(&str, u8)> {
+    let mut rest = number.as_bytes();
+    if let [head @ .., b')'] = rest {
+        rest = head;
+    }
+    let (&check, mut body) = rest.split_last()?;
+    if let [head @ .., b'('] = body {
+        body = head;
+    }
+    let prefix_len = body.len().checked_sub(6)?;
+    if (1..=2).contains(&prefix_len)
+        && body[..prefix_len].iter().all(u8::is_ascii_uppercase)
+        && body[prefix_len..].iter().all(u8::is_ascii_digit)
+        && (check.is_ascii_digit() || check == b'A')
+    {
+        Some((&number[..body.len()], check))
     } else {
-        let first = match number.chars().nth(0) {
-            Some(ch) => ch as u32,
-            _ => return false,
-        };
-        sum = 522 + (first - 55) * 8;
+        None
     }
+}
 
-    let mid = &card[1..7];
-    let end = &card[7..8];
+/// Calculates the check character(`0` to `9`, or `A`) of the prefix letters
+/// and the 6 digits, e.g. `G123456` gives `A`.
+pub fn check_digit(prefix_and_digits: &str) -> Option<char> {
+    let bytes = prefix_and_digits.as_bytes();
+    let prefix_len = bytes.len().checked_sub(6)?;
+    if !(1..=2).contains(&prefix_len)
+        || !bytes[..prefix_len].iter().all(u8::is_ascii_uppercase)
+        || !bytes[prefix_len..].iter().all(u8::is_ascii_digit)
+    {
+        return None;
+    }
 
-    let mut flag = 7;
-    for ch in mid.chars() {
-        let i = match ch.to_digit(10) {
-            Some(value) => value,
-            _ => return false,
-        };
-        sum = sum + i * flag;
-        flag -= 1;
+    // A single letter is preceded by a space, which counts as 36(`A` to `Z`
+    // are 10 to 35), and the 8 characters are weighted 9 to 2.
+    let mut values = Vec::with_capacity(8);
+    if prefix_len == 1 {
+        values.push(36);
     }
+    values.extend(
+        bytes[..prefix_len]
+            .iter()
+            .map(|&b| u32::from(b - b'A') + 10),
+    );
+    values.extend(bytes[prefix_len..].iter().map(|&b| u32::from(b - b'0')));
+    let sum: u32 = values.iter().zip((2..=9).rev()).map(|(v, w)| v * w).sum();
+    match (11 - sum % 11) % 11 {
+        10 => Some('A'),
+        n => std::char::from_digit(n, 10),
+    }
+}
 
-    if end == "A" {
-        sum = sum + 10;
+/// Normalizes a valid number into the canonical form, e.g. `g 123456 a` gives
+/// `G123456(A)`, returns `None` if the number is invalid.
+pub fn normalize(number: &str) -> Option<String> {
+    format(number, true)
+}
+
+/// Formats a valid number with or without the parentheses around the check
+/// character, i.e. `G123456(A)` or `G123456A`, returns `None` if the number is
+/// invalid.
+pub fn format(number: &str, with_parentheses: bool) -> Option<String> {
+    let id = Identity::try_new(number).ok()?;
+    if with_parentheses {
+        Some(id.number)
     } else {
-        let i = match end.chars().nth(0) {
-            Some(ch) => match ch.to_digit(10) {
-                Some(value) => value,
-                _ => return false,
-            },
-            _ => return false,
-        };
-        sum = sum + i;
-    }
-    sum % 11 == 0
+        Some(id.number.replace(['(', ')'], ""))
+    }
+}
+
+/// The information of a prefix letter, see `prefix_info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixInfo {
+    /// The letter.
+    pub letter: char,
+    /// The value of the letter in the check digit, `A` to `Z` are 10 to 35.
+    pub value: u32,
+    /// The known notes on the issuance, e.g. the period and the office.
+    pub note: Option<&'static str>,
+}
+
+/// Returns the information of the given prefix letter(case-insensitive).
+pub fn prefix_info(letter: char) -> Option<PrefixInfo> {
+    let letter = letter.to_ascii_uppercase();
+    let i = PREFIX_LETTERS
+        .binary_search_by_key(&letter, |&(c, _)| c)
+        .ok()?;
+    Some(PrefixInfo {
+        letter,
+        value: u32::from(letter) - u32::from('A') + 10,
+        note: PREFIX_LETTERS[i].1,
+    })
+}
+
+/// An object representation of the Hong Kong ID, e.g. `G123456(A)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity {
+    number: String,
+    prefix_len: usize,
+    valid: bool,
+}
+
+impl Identity {
+    /// Creates an identity object from the given number, the number is
+    /// normalized by `crate::normalize` first, and the parentheses around the
+    /// check character are optional.
+    pub fn new(number: &str) -> Self {
+        let number = crate::normalize(number);
+        let body: String = number
+            .chars()
+            .filter(|&ch| ch != '(' && ch != ')')
+            .collect();
+        let prefix_len = body.bytes().take_while(u8::is_ascii_uppercase).count();
+        if !(1..=2).contains(&prefix_len) || body.len() != prefix_len + 7 || !validate(&number) {
+            return Identity {
+                number,
+                prefix_len: 0,
+                valid: false,
+            };
+        }
+        let (body, check) = body.split_at(body.len() - 1);
+        Identity {
+            number: format!("{}({})", body, check),
+            prefix_len,
+            valid: true,
+        }
+    }
+
+    /// Creates an identity object from the given number, returns an error if
+    /// the number is invalid.
+    pub fn try_new(number: &str) -> Result<Self, Error> {
+        let id = Identity::new(number);
+        if id.is_valid() {
+            Ok(id)
+        } else {
+            Err(Error::InvalidNumber)
+        }
+    }
+
+    /// Returns the number, in the form of `G123456(A)` if it's valid.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// Returns the prefix letters, e.g. `G` or `AB`.
+    pub fn prefix(&self) -> Option<&str> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(&self.number[..self.prefix_len])
+    }
+
+    /// Returns the 6 digits after the prefix.
+    pub fn digits(&self) -> Option<&str> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(&self.number[self.prefix_len..self.prefix_len + 6])
+    }
+
+    /// Returns the check character(`0` to `9`, or `A`).
+    pub fn check_char(&self) -> Option<char> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.number[self.prefix_len + 7..].chars().next()
+    }
+
+    /// Checks if the number is valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.number())
+    }
+}
+
+impl FromStr for Identity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Identity::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for Identity {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Identity::try_new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Identity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.number())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Identity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let number = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        Identity::try_new(&number)
+            .map_err(|e| serde::de::Error::custom(format!("invalid HKID {:?}: {}", number, e)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_identity() {
+        let id = Identity::new(" g123456a ");
+        assert!(id.is_valid());
+        assert_eq!(id.number(), "G123456(A)");
+        assert_eq!(id.to_string(), "G123456(A)");
+        assert_eq!(id.prefix(), Some("G"));
+        assert_eq!(id.digits(), Some("123456"));
+        assert_eq!(id.check_char(), Some('A'));
+        assert_eq!(id, "G123456(A)".parse().unwrap());
+
+        let id = Identity::new("AB987654(3)");
+        assert_eq!(id.prefix(), Some("AB"));
+        assert_eq!(id.digits(), Some("987654"));
+        assert_eq!(id.check_char(), Some('3'));
+
+        let id = Identity::new("G123456(B)");
+        assert!(!id.is_valid());
+        assert_eq!(id.number(), "G123456(B)");
+        assert_eq!(id.prefix(), None);
+        assert!(Identity::try_new("G123456(B)").is_err());
+        assert!(Identity::try_new("").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let id = Identity::new("G123456A");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"G123456(A)\"");
+        let back: Identity = serde_json::from_str("\"g123456(a)\"").unwrap();
+        assert_eq!(back, id);
+        assert!(serde_json::from_str::<Identity>("\"G123456(B)\"").is_err());
+    }
+
     #[test]
     fn test_validate() {
         assert_eq!(validate("G123456(A)"), true);
@@ -96,4 +313,48 @@ mod tests {
         assert_eq!(validate("C123456(9)"), true);
         assert_eq!(validate("AY987654(A)"), false);
     }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("g123456a").as_deref(), Some("G123456(A)"));
+        assert_eq!(normalize(" AB 987654 (3) ").as_deref(), Some("AB987654(3)"));
+        assert_eq!(
+            normalize("Ｇ１２３４５６（Ａ）").as_deref(),
+            Some("G123456(A)")
+        );
+        assert_eq!(normalize("G123456(B)"), None);
+        assert_eq!(format("G123456(A)", false).as_deref(), Some("G123456A"));
+        assert_eq!(format("G123456A", true).as_deref(), Some("G123456(A)"));
+        assert_eq!(format("G12345", true), None);
+    }
+
+    #[test]
+    fn test_prefix_info() {
+        assert!(PREFIX_LETTERS.windows(2).all(|w| w[0].0 < w[1].0));
+        let info = prefix_info('g').unwrap();
+        assert_eq!(info.letter, 'G');
+        assert_eq!(info.value, 16);
+        assert!(info.note.is_some());
+        assert_eq!(prefix_info('A').unwrap().value, 10);
+        assert_eq!(prefix_info('Z').unwrap().value, 35);
+        assert_eq!(prefix_info('Q').unwrap().note, None);
+        assert_eq!(prefix_info('1'), None);
+        for &(letter, _) in PREFIX_LETTERS.iter() {
+            let body = format!("{}123456", letter);
+            let number = format!("{}({})", body, check_digit(&body).unwrap());
+            assert!(validate(&number));
+        }
+    }
+
+    #[test]
+    fn test_check_digit() {
+        assert_eq!(check_digit("G123456"), Some('A'));
+        assert_eq!(check_digit("L555555"), Some('0'));
+        assert_eq!(check_digit("AB987654"), Some('3'));
+        assert_eq!(check_digit("C123456"), Some('9'));
+        assert_eq!(check_digit("g123456"), None);
+        assert_eq!(check_digit("G12345"), None);
+        assert_eq!(check_digit("ABC123456"), None);
+        assert_eq!(check_digit("123456"), None);
+    }
 }
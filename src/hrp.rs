@@ -0,0 +1,64 @@
+//! Utilities for Mainland Travel Permit for Hong Kong and Macao Residents
+//! (港澳居民来往内地通行证, a.k.a. 回乡证)
+//!
+//! The number of the card issued since 2013 is a letter followed by 8 digits,
+//! e.g. `H12345678`, where the letter is `H` for Hong Kong residents and `M`
+//! for Macao residents. Some systems append the 2-digit renewal count to it,
+//! e.g. `H1234567801`.
+
+use crate::{normalize, Province};
+
+/// Validates the number, with or without the renewal count.
+pub fn validate(number: &str) -> bool {
+    let number = normalize(number);
+    let bytes = number.as_bytes();
+    matches!(bytes.len(), 9 | 11)
+        && matches!(bytes[0], b'H' | b'M')
+        && bytes[1..].iter().all(u8::is_ascii_digit)
+}
+
+/// Returns the region the holder comes from, `Province::HongKong` or
+/// `Province::Macao`.
+pub fn region(number: &str) -> Option<Province> {
+    if !validate(number) {
+        return None;
+    }
+    match normalize(number).as_bytes()[0] {
+        b'H' => Some(Province::HongKong),
+        _ => Some(Province::Macao),
+    }
+}
+
+/// Returns the renewal count, i.e. the trailing 2 digits, or `None` if the
+/// number is invalid or without it.
+pub fn renewal_count(number: &str) -> Option<u32> {
+    if !validate(number) {
+        return None;
+    }
+    normalize(number).get(9..)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("H12345678"));
+        assert!(validate("m1234567803"));
+        assert!(validate("H 1234 5678"));
+        assert!(!validate("H1234567"));
+        assert!(!validate("H123456789"));
+        assert!(!validate("C12345678"));
+        assert!(!validate("H1234567A"));
+    }
+
+    #[test]
+    fn test_info() {
+        assert_eq!(region("H12345678"), Some(Province::HongKong));
+        assert_eq!(region("M1234567803"), Some(Province::Macao));
+        assert_eq!(region("C12345678"), None);
+        assert_eq!(renewal_count("M1234567803"), Some(3));
+        assert_eq!(renewal_count("H12345678"), None);
+    }
+}
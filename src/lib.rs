@@ -7,7 +7,7 @@
 //! # Examples
 //!
 //! ```
-//! use idcard::{Identity, fake, Gender};
+//! use idcard::Identity;
 //!
 //! let id = Identity::new("632123820927051");
 //!
@@ -30,6 +30,10 @@
 //! assert_eq!(idcard::validate("230127197908177456"), true);
 //!
 //! // Generates a random fake ID number using the given options.
+//! # #[cfg(feature = "fake")]
+//! # {
+//! use idcard::fake;
+//!
 //! let opts = fake::FakeOptions::new()
 //!     .region("3301")
 //!     .min_year(1990)
@@ -39,86 +43,117 @@
 //!     Ok(num) => println!("{}", num),
 //!     Err(e) => println!("{}", e),
 //! }
+//! # }
 //! ```
 //! For more information ,please read the API documentation.
 //!
 
-#[macro_use]
-extern crate lazy_static;
+// The long-standing code is kept in its original style rather than rewritten
+// for the lints added to clippy since.
+#![allow(
+    clippy::assign_op_pattern,
+    clippy::bool_assert_comparison,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::question_mark,
+    clippy::unnecessary_unwrap
+)]
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
-use chrono::{Datelike, Local, NaiveDate};
-use std::collections::HashMap;
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, Months, NaiveDate};
+use date::Date;
+use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
 
+pub mod batch;
+pub mod birthcert;
+mod date;
+mod document;
+pub mod eep;
+mod era;
+#[cfg(feature = "fake")]
 pub mod fake;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod foreigner;
 pub mod hk;
+pub mod hrp;
+mod locale;
+pub mod lunar;
 pub mod mo;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+pub mod orgcode;
+pub mod passport;
+mod province;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod region;
+pub mod tbz;
+pub mod text;
 pub mod tw;
+pub mod uscc;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod zodiac;
+
+pub use document::{ChineseId, Document};
+pub use era::{ChineseEra, EarthlyBranch, HeavenlyStem};
+pub use locale::Locale;
+use lunar::LunarDate;
+pub use province::{Province, ProvinceKind};
+pub use zodiac::{Constellation, Zodiac};
 
 const ID_V1_LEN: usize = 15;
 const ID_V2_LEN: usize = 18;
+const ADULT_AGE: u32 = 18;
+
+static WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+static CHECK_CODES: [u8; 11] = *b"10X98765432";
+const MAX_AGE: u32 = 150;
 
-static CHINESE_ZODIAC: [&'static str; 12] = [
-    "猪", "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗",
+/// The region codes of the residence permits for Hong Kong, Macao and Taiwan
+/// residents, with the names of the regions the holders come from.
+static RESIDENCE_PERMIT_REGIONS: [(&str, &str); 3] = [
+    ("810000", "香港特别行政区"),
+    ("820000", "澳门特别行政区"),
+    ("830000", "台湾省"),
 ];
 
-static CELESTIAL_STEM: [&'static str; 10] =
-    ["癸", "甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "任"];
+/// Checks if the 18-digit number is a foreign permanent resident ID.
+fn is_foreign(number: &str) -> bool {
+    number.len() == ID_V2_LEN && number.starts_with('9')
+}
 
-static TERRESTRIAL_BRANCH: [&'static str; 12] = [
-    "亥", "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌",
-];
+fn residence_permit_region(code: &str) -> Option<&'static str> {
+    RESIDENCE_PERMIT_REGIONS
+        .iter()
+        .find(|&&(c, _)| c == code)
+        .map(|&(_, name)| name)
+}
 
-lazy_static! {
-    static ref PROVINCE_CODE_NAME: HashMap<&'static str, &'static str> = {
-        let mut map = HashMap::new();
-        map.insert("11", "北京");
-        map.insert("12", "天津");
-        map.insert("13", "河北");
-        map.insert("14", "山西");
-        map.insert("15", "内蒙古");
-        map.insert("21", "辽宁");
-        map.insert("22", "吉林");
-        map.insert("23", "黑龙江");
-        map.insert("31", "上海");
-        map.insert("32", "江苏");
-        map.insert("33", "浙江");
-        map.insert("34", "安徽");
-        map.insert("35", "福建");
-        map.insert("36", "江西");
-        map.insert("37", "山东");
-        map.insert("41", "河南");
-        map.insert("42", "湖北");
-        map.insert("43", "湖南");
-        map.insert("44", "广东");
-        map.insert("45", "广西");
-        map.insert("46", "海南");
-        map.insert("50", "重庆");
-        map.insert("51", "四川");
-        map.insert("52", "贵州");
-        map.insert("53", "云南");
-        map.insert("54", "西藏");
-        map.insert("61", "陕西");
-        map.insert("62", "甘肃");
-        map.insert("63", "青海");
-        map.insert("64", "宁夏");
-        map.insert("65", "新疆");
-        map.insert("71", "台湾");
-        map.insert("81", "香港");
-        map.insert("82", "澳门");
-        map.insert("83", "台湾");
-        map.insert("91", "国外");
-        map
-    };
+/// Returns the province name by the first 2 digits, `91` is for the foreigners.
+fn province_name(code: &str) -> Option<&'static str> {
+    match code {
+        "91" => Some("国外"),
+        _ => Province::from_code(code).map(Province::name),
+    }
 }
 
 /// Custom error type.
 #[derive(Debug)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Error), uniffi(flat_error))]
 pub enum Error {
     InvalidNumber,
     UpgradeError,
+    DowngradeError,
     GenerateFakeIDError(String),
+    ValidationFailed(ValidationError),
+    ComposeError(String),
 }
 
 impl std::error::Error for Error {}
@@ -128,51 +163,162 @@ impl fmt::Display for Error {
         match self {
             Error::InvalidNumber => write!(f, "Invalid Number"),
             Error::UpgradeError => write!(f, "Upgrade Failed"),
+            Error::DowngradeError => write!(f, "Downgrade Failed"),
             Error::GenerateFakeIDError(msg) => write!(f, "Generate Fake ID Error: {}", msg),
+            Error::ValidationFailed(reason) => write!(f, "Validation Failed: {}", reason),
+            Error::ComposeError(msg) => write!(f, "Compose Error: {}", msg),
+        }
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Error::ValidationFailed(err)
+    }
+}
+
+/// The reason why an ID number is rejected by the validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationError {
+    /// The length of the number is neither 15 nor 18.
+    WrongLength,
+    /// The number contains characters other than digits(and the trailing `X`).
+    NonDigitCharacter,
+    /// The date of birth does not exist in the calendar.
+    InvalidBirthDate,
+    /// The region code is unknown.
+    UnknownRegion,
+    /// The check digit does not match the first 17 digits.
+    ChecksumMismatch,
+    /// The date of birth is in the future or too long ago.
+    BirthDateOutOfRange,
+}
+
+impl std::error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::WrongLength => write!(f, "Wrong Length"),
+            ValidationError::NonDigitCharacter => write!(f, "Non-digit Character"),
+            ValidationError::InvalidBirthDate => write!(f, "Invalid Date of Birth"),
+            ValidationError::UnknownRegion => write!(f, "Unknown Region"),
+            ValidationError::ChecksumMismatch => write!(f, "Checksum Mismatch"),
+            ValidationError::BirthDateOutOfRange => write!(f, "Date of Birth Out of Range"),
         }
     }
 }
 
 /// The type of demographic genders
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum Gender {
     Male,
     Female,
 }
 
+/// The kinds of the supported documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentKind {
+    /// The first generation resident identity card of the mainland residents,
+    /// with a 15-digit number.
+    Mainland15,
+    /// The resident identity card of the mainland residents.
+    Mainland18,
+    /// The residence permit for Hong Kong, Macao and Taiwan residents(港澳台居民
+    /// 居住证), numbered from `810000`, `820000` or `830000`.
+    ResidencePermit,
+    /// The foreign permanent resident ID card(外国人永久居留身份证), either the
+    /// 18-digit one issued since 2023 or the legacy 15-character one, see
+    /// `foreigner`.
+    ForeignPermanentResident,
+    /// The Hong Kong identity card, see `hk`.
+    HongKong,
+    /// The Macau identity card, see `mo`.
+    Macau,
+    /// The Taiwan identity card, see `tw`.
+    Taiwan,
+    /// The mainland travel permit for Hong Kong and Macao residents(回乡证),
+    /// see `hrp`.
+    HomeReturnPermit,
+    /// The mainland travel permit for Taiwan residents(台胞证), see `tbz`.
+    TaiwanCompatriotPermit,
+    /// The exit-entry permit for travelling to and from Hong Kong and Macao
+    /// (往来港澳通行证), see `eep`.
+    ExitEntryPermit,
+}
+
 /// An object representation of the Chinese ID.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// The number is stored in a fixed-size buffer, so the object is cheap to copy.
+/// An input longer than 18 bytes is rejected, the object is invalid and its
+/// number is empty.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Identity {
-    number: String,
+    number: [u8; ID_V2_LEN],
+    len: u8,
     valid: bool,
+    birth: Option<Date>,
+}
+
+impl fmt::Debug for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Identity")
+            .field("number", &self.number())
+            .field("valid", &self.valid)
+            .finish()
+    }
 }
 
 impl Identity {
-    /// Creates an identity object from given number.
+    /// Creates an identity object from given number, the number is normalized
+    /// by `normalize` first.
     pub fn new(number: &str) -> Self {
-        let mut id = Identity {
-            number: number.trim().to_ascii_uppercase(),
-            valid: false,
-        };
-        if id.number.len() == ID_V1_LEN {
-            match upgrade(&id.number) {
-                Ok(value) => {
-                    id.number = value;
-                    id.valid = true;
-                }
-                _ => id.valid = false,
+        let number = normalize(number);
+        if number.len() == ID_V1_LEN {
+            match upgrade(&number) {
+                Ok(value) => Identity::store(&value, true),
+                _ => Identity::store(&number, false),
             }
-        } else if id.number.len() == ID_V2_LEN {
-            id.valid = if validate_v2(&id.number) { true } else { false }
+        } else if number.len() == ID_V2_LEN {
+            Identity::store(&number, validate_v2(&number).is_ok())
+        } else {
+            Identity::store(&number, false)
+        }
+    }
+
+    fn store(number: &str, valid: bool) -> Self {
+        let len = if number.len() > ID_V2_LEN {
+            0
+        } else {
+            number.len()
+        };
+        let mut buf = [0; ID_V2_LEN];
+        buf[..len].copy_from_slice(&number.as_bytes()[..len]);
+        Identity {
+            number: buf,
+            len: len as u8,
+            valid,
+            birth: if valid { birth_date_of(number) } else { None },
+        }
+    }
+
+    /// Creates an identity object from given number, returns an error if
+    /// the number is invalid.
+    pub fn try_new(number: &str) -> Result<Self, Error> {
+        validate_detailed(number)?;
+        let id = Identity::new(number);
+        if id.is_valid() {
+            Ok(id)
         } else {
-            id.valid = false;
+            Err(Error::InvalidNumber)
         }
-        id
     }
 
     /// Returns the ID number.
     pub fn number(&self) -> &str {
-        &self.number
+        std::str::from_utf8(&self.number[..self.len as usize]).unwrap_or_default()
     }
 
     /// Returns the formatted date of birth(yyyy-mm-dd).
@@ -180,23 +326,37 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        let birth = &self.number[6..14];
+        let birth = &self.number()[6..14];
         let year = &birth[0..4];
         let month = &birth[4..6];
         let date = &birth[6..8];
         Some(format!("{}-{}-{}", year, month, date))
     }
 
+    /// Returns the date of birth.
+    #[cfg(feature = "chrono")]
+    pub fn birth_naive_date(&self) -> Option<NaiveDate> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.birth.map(NaiveDate::from)
+    }
+
+    /// Returns the date of birth in the Chinese lunar calendar(农历), `None` if
+    /// it's out of the range of `lunar::LunarDate`.
+    pub fn lunar_birth_date(&self) -> Option<LunarDate> {
+        if !self.is_valid() {
+            return None;
+        }
+        LunarDate::from_date(self.birth?)
+    }
+
     /// Returns the year of birth.
     pub fn year(&self) -> Option<u32> {
         if !self.is_valid() {
             return None;
         }
-        if let Ok(year) = self.number[6..10].parse::<u32>() {
-            Some(year)
-        } else {
-            None
-        }
+        self.birth.map(|date| date.year() as u32)
     }
 
     /// Returns the month of birth.
@@ -204,11 +364,7 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        if let Ok(month) = self.number[10..12].parse::<u32>() {
-            Some(month)
-        } else {
-            None
-        }
+        self.birth.map(|date| date.month())
     }
 
     /// Returns the day in the month of the birth.
@@ -216,28 +372,77 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        if let Ok(day) = self.number[12..14].parse::<u32>() {
-            Some(day)
-        } else {
-            None
-        }
+        self.birth.map(|date| date.day())
     }
 
-    /// Calculates the current age based on the computer's local date,
-    /// if the birth year is less than the local's, it returns `None`.
+    /// Calculates the current age based on the computer's local date(the UTC
+    /// date without the `chrono` feature), if the date of birth is later than
+    /// the local date, it returns `None`.
     pub fn age(&self) -> Option<u32> {
+        self.age_as_of(Date::today())
+    }
+
+    /// Calculates the age as of the given date, taking the month and day of
+    /// birth into account. If the given date is earlier than the date of
+    /// birth, it returns `None`.
+    #[cfg(feature = "chrono")]
+    pub fn age_on(&self, date: NaiveDate) -> Option<u32> {
+        self.age_as_of(date.into())
+    }
+
+    fn age_as_of(&self, date: Date) -> Option<u32> {
         if !self.is_valid() {
             return None;
         }
-        if let Ok(year) = self.number[6..10].parse::<u32>() {
-            let current = Local::now().year() as u32;
-            if current < year {
-                return None;
-            }
-            Some(current - year)
-        } else {
-            None
+        let birth = self.birth?;
+        if date < birth {
+            return None;
+        }
+        let mut age = (date.year() - birth.year()) as u32;
+        if (date.month(), date.day()) < (birth.month(), birth.day()) {
+            age -= 1;
+        }
+        Some(age)
+    }
+
+    /// Checks if the person is an adult(at least 18 years old) based on the
+    /// computer's local date.
+    pub fn is_adult(&self) -> bool {
+        self.age().is_some_and(|age| age >= ADULT_AGE)
+    }
+
+    /// Checks if the person is at least the given years old as of the given
+    /// date, it returns `false` if the number is invalid.
+    #[cfg(feature = "chrono")]
+    pub fn is_at_least(&self, years: u32, as_of: NaiveDate) -> bool {
+        match self.age_on(as_of) {
+            Some(age) => age >= years,
+            None => false,
+        }
+    }
+
+    /// Calculates the precise age in years, months and days as of the given
+    /// date. If the given date is earlier than the date of birth, it returns
+    /// `None`.
+    #[cfg(feature = "chrono")]
+    pub fn age_precise(&self, as_of: NaiveDate) -> Option<AgeParts> {
+        let birth = self.birth_naive_date()?;
+        if as_of < birth {
+            return None;
+        }
+        let mut months = (as_of.year() - birth.year()) as u32 * 12 + as_of.month() - birth.month();
+        if as_of.day() < birth.day() {
+            months -= 1;
         }
+        // Adding months to a day that does not exist in the target month
+        // (e.g. Jan 31 + 1 month) yields the last day of that month.
+        let anchor = birth.checked_add_months(Months::new(months))?;
+        let days = (as_of - anchor).num_days() as u32;
+        Some(AgeParts {
+            years: months / 12,
+            months: months % 12,
+            days,
+        })
     }
 
     /// Calculates the age based on the given year, if the given year is less
@@ -246,7 +451,7 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        if let Ok(value) = self.number[6..10].parse::<u32>() {
+        if let Ok(value) = self.number()[6..10].parse::<u32>() {
             if year < value {
                 return None;
             }
@@ -261,7 +466,7 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        if let Ok(code) = self.number[16..17].parse::<u32>() {
+        if let Ok(code) = self.number()[16..17].parse::<u32>() {
             if code % 2 != 0 {
                 Some(Gender::Male)
             } else {
@@ -277,19 +482,68 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        let code = &self.number[0..2];
-        match PROVINCE_CODE_NAME.get(code) {
-            Some(name) => Some(*name),
-            None => None,
+        if is_foreign(self.number()) {
+            return Some("国外");
+        }
+        let code = &self.number()[0..2];
+        province_name(code)
+    }
+
+    /// Returns the province based on the first 2 digits of the number, `None`
+    /// for the foreigners.
+    pub fn province_enum(&self) -> Option<Province> {
+        if !self.is_valid() {
+            return None;
+        }
+        Province::from_code(&self.number()[0..2])
+    }
+
+    /// Returns the kind of the document. A 15-digit number is upgraded when
+    /// the object is created, so it's reported as `Mainland18`.
+    pub fn document_kind(&self) -> Option<DocumentKind> {
+        if !self.is_valid() {
+            return None;
+        }
+        if residence_permit_region(&self.number()[0..6]).is_some() {
+            Some(DocumentKind::ResidencePermit)
+        } else if is_foreign(self.number()) {
+            Some(DocumentKind::ForeignPermanentResident)
+        } else {
+            Some(DocumentKind::Mainland18)
+        }
+    }
+
+    /// Returns the 3-digit nationality code(GB/T 2659, the same as the numeric
+    /// code of ISO 3166-1, e.g. `840` for the United States) of a foreign
+    /// permanent resident ID.
+    pub fn nationality_code(&self) -> Option<&str> {
+        if !self.is_valid() || !is_foreign(self.number()) {
+            return None;
+        }
+        Some(&self.number()[3..6])
+    }
+
+    /// Returns the province where a foreign permanent resident ID was issued,
+    /// by the 2nd and 3rd digits.
+    pub fn issuing_province(&self) -> Option<Province> {
+        if !self.is_valid() || !is_foreign(self.number()) {
+            return None;
         }
+        Province::from_code(&self.number()[1..3])
     }
 
-    /// Returns the region name based on the first 6 digits of the number
+    /// Returns the region name based on the first 6 digits of the number, the
+    /// abolished codes are resolved by the birth year. For a residence permit,
+    /// it's the region the holder comes from, e.g. `香港特别行政区`.
     pub fn region(&self) -> Option<&str> {
         if !self.is_valid() {
             return None;
         }
-        region::query(&self.number[0..6])
+        let code = &self.number()[0..6];
+        if let Some(name) = residence_permit_region(code) {
+            return Some(name);
+        }
+        region::provider().name_at(code, self.year()?)
     }
 
     /// Returns the region code(the first 6 digits)
@@ -297,7 +551,34 @@ impl Identity {
         if !self.is_valid() {
             return None;
         }
-        Some(&self.number[0..6])
+        Some(&self.number()[0..6])
+    }
+
+    /// Returns the sequence code(the 15th to 17th digits).
+    pub fn sequence_code(&self) -> Option<&str> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(&self.number()[14..17])
+    }
+
+    /// Returns the check digit(the last character).
+    pub fn check_digit(&self) -> Option<char> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.number()[17..].chars().next()
+    }
+
+    /// Returns the structured decomposition of the number.
+    #[cfg(feature = "chrono")]
+    pub fn parts(&self) -> Option<IdParts<'_>> {
+        Some(IdParts {
+            region_code: self.region_code()?,
+            birth_date: self.birth_naive_date()?,
+            sequence: self.sequence_code()?,
+            check_digit: self.check_digit()?,
+        })
     }
 
     /// Returns the constellation by the date of birth.
@@ -316,8 +597,16 @@ impl Identity {
         constellation(month, day)
     }
 
+    /// Returns the constellation by the date of birth, as an enum.
+    pub fn constellation_enum(&self) -> Option<Constellation> {
+        if !self.is_valid() {
+            return None;
+        }
+        Constellation::from_date(self.month()?, self.day()?)
+    }
+
     /// Returns the Chinese Era by the year of birth.
-    pub fn chinese_era(&self) -> Option<String> {
+    pub fn chinese_era(&self) -> Option<ChineseEra> {
         if !self.is_valid() {
             return None;
         }
@@ -340,6 +629,61 @@ impl Identity {
         chinese_zodiac(year)
     }
 
+    /// Returns the Chinese Zodiac animal by the date of birth, the zodiac year
+    /// begins on the lunar new year, so e.g. the people born in January 1985
+    /// are `鼠` rather than `牛`. `None` if the date of birth is out of the
+    /// range of the lunar calendar(1900-01-31 to 2101-01-28).
+    pub fn chinese_zodiac_accurate(&self) -> Option<&str> {
+        self.chinese_zodiac_with(YearStart::LunarNewYear)
+    }
+
+    /// Returns the Chinese Zodiac animal by the date of birth, the zodiac year
+    /// begins on the given day.
+    pub fn chinese_zodiac_with(&self, start: YearStart) -> Option<&str> {
+        if !self.is_valid() {
+            return None;
+        }
+        chinese_zodiac_on(self.year()?, self.month()?, self.day()?, start)
+    }
+
+    /// Returns the Chinese Zodiac animal by the year of birth, as an enum.
+    pub fn chinese_zodiac_enum(&self) -> Option<Zodiac> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(Zodiac::from_year(self.year()? as i32))
+    }
+
+    /// Returns the province name in the given locale.
+    pub fn province_in(&self, locale: Locale) -> Option<String> {
+        match self.province_enum() {
+            Some(p) => Some(locale.render(p.name(), p.name_en())),
+            None => locale.translate(self.province()?),
+        }
+    }
+
+    /// Returns the region name in the given locale, the English name is taken
+    /// from the builtin region table, e.g. `Sichuan, Dazhou, Tongchuan District`.
+    pub fn region_in(&self, locale: Locale) -> Option<String> {
+        let name = self.region()?;
+        match locale {
+            Locale::En => region::query_en(&self.number()[0..6]),
+            _ => locale.translate(name),
+        }
+    }
+
+    /// Returns the constellation in the given locale.
+    pub fn constellation_in(&self, locale: Locale) -> Option<String> {
+        let c = self.constellation_enum()?;
+        Some(locale.render(c.name_zh(), c.name_en()))
+    }
+
+    /// Returns the Chinese Zodiac animal in the given locale.
+    pub fn chinese_zodiac_in(&self, locale: Locale) -> Option<String> {
+        let z = self.chinese_zodiac_enum()?;
+        Some(locale.render(z.name_zh(), z.name_en()))
+    }
+
     /// Checks if the number is valid.
     pub fn is_valid(&self) -> bool {
         self.valid
@@ -347,12 +691,307 @@ impl Identity {
 
     /// Checks if the number is empty.
     pub fn is_empty(&self) -> bool {
-        self.number.is_empty()
+        self.len == 0
     }
 
     /// Returns the length of the number.
     pub fn len(&self) -> usize {
-        self.number.len()
+        self.len as usize
+    }
+
+    /// Returns all the detailed information of the number, if the number is
+    /// invalid, it returns `None`.
+    pub fn info(&self) -> Option<IdentityInfo> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(IdentityInfo {
+            number: self.number().to_owned(),
+            gender: self.gender()?,
+            age: self.age(),
+            birth_date: self.birth_date()?,
+            year: self.year()?,
+            month: self.month()?,
+            day: self.day()?,
+            province: self.province().map(|s| s.to_owned()),
+            region: self.region().map(|s| s.to_owned()),
+            region_code: self.region_code()?.to_owned(),
+            constellation: self.constellation().map(|s| s.to_owned()),
+            chinese_era: self.chinese_era(),
+            chinese_zodiac: self.chinese_zodiac().map(|s| s.to_owned()),
+        })
+    }
+
+    /// Returns the number formatted in the given style, e.g.
+    /// `110101 19900101 123X`. The formatted number can be parsed back by
+    /// `Identity::new` or `str::parse`, since the separators are removed by
+    /// `normalize`.
+    pub fn format(&self, style: FormatStyle) -> String {
+        let separator = match style {
+            FormatStyle::Plain => return self.number().to_owned(),
+            FormatStyle::Spaced => ' ',
+            FormatStyle::Hyphenated => '-',
+        };
+        if self.len as usize != ID_V2_LEN || !self.number().is_ascii() {
+            return self.number().to_owned();
+        }
+        format!(
+            "{}{}{}{}{}",
+            &self.number()[0..6],
+            separator,
+            &self.number()[6..14],
+            separator,
+            &self.number()[14..]
+        )
+    }
+
+    /// Returns the masked number for desensitization, e.g. `110101********1234`.
+    pub fn mask(&self, style: MaskStyle) -> String {
+        mask_number(self.number(), style)
+    }
+
+    /// Returns the detailed information as a JSON string, if the number is
+    /// invalid, it returns `None`.
+    pub fn to_json_string(&self) -> Option<String> {
+        self.info().map(|info| info.to_json_string())
+    }
+
+    /// Returns the detailed information as a JSON value, if the number is
+    /// invalid, it returns `None`.
+    #[cfg(feature = "serde")]
+    pub fn to_json_value(&self) -> Option<serde_json::Value> {
+        self.info().and_then(|info| serde_json::to_value(info).ok())
+    }
+}
+
+/// The style used to format an ID number, see [`Identity::format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormatStyle {
+    /// No grouping, e.g. `11010119900101123X`.
+    #[default]
+    Plain,
+    /// Groups the region, the date of birth and the rest by spaces, e.g.
+    /// `110101 19900101 123X`.
+    Spaced,
+    /// Groups the region, the date of birth and the rest by hyphens, e.g.
+    /// `110101-19900101-123X`.
+    Hyphenated,
+}
+
+/// The style used to mask an ID number, see [`Identity::mask`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaskStyle {
+    /// Keeps the first 6 and the last 4 characters, e.g. `110101********1234`.
+    #[default]
+    KeepFirst6Last4,
+    /// Keeps only the year of birth, e.g. `******1990********`.
+    KeepBirthYearOnly,
+    /// Masks the characters in the range `start..end`.
+    Range { start: usize, end: usize },
+}
+
+const MASK_CHAR: char = '*';
+
+fn mask_number(number: &str, style: MaskStyle) -> String {
+    let len = number.chars().count();
+    let masked = |i: usize| match style {
+        MaskStyle::KeepFirst6Last4 => i >= 6 && i + 4 < len,
+        MaskStyle::KeepBirthYearOnly if len == ID_V1_LEN => !(6..8).contains(&i),
+        MaskStyle::KeepBirthYearOnly => !(6..10).contains(&i),
+        MaskStyle::Range { start, end } => (start..end).contains(&i),
+    };
+    number
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| if masked(i) { MASK_CHAR } else { ch })
+        .collect()
+}
+
+/// The day the zodiac year begins on, see [`chinese_zodiac_on`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YearStart {
+    /// The lunar new year(春节), as the zodiac is commonly reckoned.
+    #[default]
+    LunarNewYear,
+    /// The Beginning of Spring(立春), as in the Four Pillars(八字).
+    SpringBegins,
+}
+
+/// The components of an ID number, see [`Identity::parts`].
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdParts<'a> {
+    /// The region code(the first 6 digits).
+    pub region_code: &'a str,
+    /// The date of birth.
+    pub birth_date: NaiveDate,
+    /// The sequence code(the 15th to 17th digits).
+    pub sequence: &'a str,
+    /// The check digit(the last character).
+    pub check_digit: char,
+}
+
+/// A precise age, see [`Identity::age_precise`].
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AgeParts {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+}
+
+/// The detailed information extracted from a valid ID number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentityInfo {
+    pub number: String,
+    pub gender: Gender,
+    pub age: Option<u32>,
+    pub birth_date: String,
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+    pub province: Option<String>,
+    pub region: Option<String>,
+    pub region_code: String,
+    pub constellation: Option<String>,
+    pub chinese_era: Option<ChineseEra>,
+    pub chinese_zodiac: Option<String>,
+}
+
+impl IdentityInfo {
+    /// Returns the information as a JSON string.
+    pub fn to_json_string(&self) -> String {
+        let gender = match self.gender {
+            Gender::Male => "Male",
+            Gender::Female => "Female",
+        };
+        let fields = [
+            ("number", json_string(Some(&self.number))),
+            ("gender", json_string(Some(gender))),
+            ("age", json_number(self.age)),
+            ("birth_date", json_string(Some(&self.birth_date))),
+            ("year", json_number(Some(self.year))),
+            ("month", json_number(Some(self.month))),
+            ("day", json_number(Some(self.day))),
+            ("province", json_string(self.province.as_deref())),
+            ("region", json_string(self.region.as_deref())),
+            ("region_code", json_string(Some(&self.region_code))),
+            ("constellation", json_string(self.constellation.as_deref())),
+            (
+                "chinese_era",
+                json_string(self.chinese_era.map(|era| era.to_string()).as_deref()),
+            ),
+            (
+                "chinese_zodiac",
+                json_string(self.chinese_zodiac.as_deref()),
+            ),
+        ];
+        let body = fields
+            .iter()
+            .map(|(key, value)| format!("\"{}\":{}", key, value))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{{{}}}", body)
+    }
+}
+
+fn json_string(value: Option<&str>) -> String {
+    match value {
+        Some(s) => {
+            let mut out = String::with_capacity(s.len() + 2);
+            out.push('"');
+            for ch in s.chars() {
+                match ch {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+            out
+        }
+        None => "null".to_string(),
+    }
+}
+
+fn json_number(value: Option<u32>) -> String {
+    match value {
+        Some(n) => n.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+impl FromStr for Identity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Identity::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for Identity {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Identity::try_new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Identity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.number())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Identity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let number = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        Identity::try_new(&number)
+            .map_err(|e| serde::de::Error::custom(format!("invalid ID number {:?}: {}", number, e)))
+    }
+}
+
+/// Generates a valid identity mostly, and a near-valid one(with a wrong check
+/// digit) once in a while.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Identity {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let codes: Vec<&str> = region::provider().codes_with_prefix("").collect();
+        let region = match u.choose(&codes) {
+            Ok(code) => code.to_string(),
+            Err(_) => format!("{}0000", u.choose(&Province::ALL)?.code()),
+        };
+        let birth = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap()
+            + chrono::Duration::days(u.int_in_range(0..=365 * 120)?);
+        let seq = u.int_in_range(0..=999)?;
+        let mut number =
+            compose(&region, birth, seq).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        if u.ratio(1, 8)? {
+            let check = number.pop().unwrap_or_default();
+            number.push(if check == '0' { '1' } else { '0' });
+        }
+        Ok(Identity::new(&number))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Gender {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(if u.arbitrary()? {
+            Gender::Male
+        } else {
+            Gender::Female
+        })
     }
 }
 
@@ -362,70 +1001,194 @@ pub fn chinese_zodiac(year: u32) -> Option<&'static str> {
     if year < 1000 {
         return None;
     }
-    let end = 3;
-    let idx = (year - end) % 12;
-    let zod = CHINESE_ZODIAC[idx as usize];
-    Some(zod)
+    Some(Zodiac::from_year(year as i32).name_zh())
+}
+
+/// Returns the Chinese Zodiac animal by the given date, the zodiac year begins
+/// on the given day rather than January 1st. Returns `None` if the date is
+/// invalid or out of the range of the lunar calendar(1900-01-31 to 2101-01-28,
+/// or 1900 to 2100 for the Beginning of Spring).
+///
+/// # Examples
+///
+/// ```
+/// use idcard::YearStart;
+///
+/// assert_eq!(idcard::chinese_zodiac(2021), Some("牛"));
+/// assert_eq!(idcard::chinese_zodiac_on(2021, 2, 11, YearStart::LunarNewYear), Some("鼠"));
+/// assert_eq!(idcard::chinese_zodiac_on(2021, 2, 11, YearStart::SpringBegins), Some("牛"));
+/// ```
+pub fn chinese_zodiac_on(
+    year: u32,
+    month: u32,
+    day: u32,
+    start: YearStart,
+) -> Option<&'static str> {
+    let date = Date::new(year as i32, month, day)?;
+    let year = match start {
+        YearStart::LunarNewYear => LunarDate::from_date(date)?.year(),
+        YearStart::SpringBegins if (month, day) < (2, lunar::spring_begins(date.year())?) => {
+            date.year() - 1
+        }
+        YearStart::SpringBegins => date.year(),
+    };
+    chinese_zodiac(year as u32)
 }
 
 /// Returns the Chinese Era by the given year, the given year
 /// should not be less than 1000.
-pub fn chinese_era(year: u32) -> Option<String> {
+pub fn chinese_era(year: u32) -> Option<ChineseEra> {
     if year < 1000 {
         return None;
     }
-    let i = (year - 3) % 10;
-    let j = (year - 3) % 12;
-    let era = format!(
-        "{}{}",
-        CELESTIAL_STEM[i as usize], TERRESTRIAL_BRANCH[j as usize]
-    );
-    Some(era)
+    Some(ChineseEra::from_year(year as i32))
 }
 
 /// Returns the constellation by the given month and day.
 pub fn constellation(month: u32, day: u32) -> Option<&'static str> {
-    let result = if (month == 1 && day >= 20) || (month == 2 && day <= 18) {
-        "水瓶座"
-    } else if (month == 2 && day >= 19) || (month == 3 && day <= 20) {
-        "双鱼座"
-    } else if (month == 3 && day > 20) || (month == 4 && day <= 19) {
-        "白羊座"
-    } else if (month == 4 && day >= 20) || (month == 5 && day <= 20) {
-        "金牛座"
-    } else if (month == 5 && day >= 21) || (month == 6 && day <= 21) {
-        "双子座"
-    } else if (month == 6 && day > 21) || (month == 7 && day <= 22) {
-        "巨蟹座"
-    } else if (month == 7 && day > 22) || (month == 8 && day <= 22) {
-        "狮子座"
-    } else if (month == 8 && day >= 23) || (month == 9 && day <= 22) {
-        "处女座"
-    } else if (month == 9 && day >= 23) || (month == 10 && day <= 23) {
-        "天秤座"
-    } else if (month == 10 && day > 23) || (month == 11 && day <= 22) {
-        "天蝎座"
-    } else if (month == 11 && day > 22) || (month == 12 && day <= 21) {
-        "射手座"
-    } else if (month == 12 && day > 21) || (month == 1 && day <= 19) {
-        "魔羯座"
+    Constellation::from_date(month, day).map(Constellation::name_zh)
+}
+
+/// Detects the kind of the document by the format and the check digit of the
+/// number, returns `None` if it matches none of the supported documents, or
+/// if it's ambiguous between two of them:
+///
+/// - A bare 8-digit number starting with `1`, `5` or `7`(e.g. `12345678`) is
+///   either a Macau ID or a Taiwan compatriot permit.
+/// - `C` followed by a letter and 7 digits(e.g. `CA1234578`) is either a Hong
+///   Kong ID or an exit-entry permit, when the check digit of the former
+///   happens to match.
+///
+/// Writing the check character of a Hong Kong or Macau ID in the parentheses
+/// (e.g. `1234567(8)`) tells it apart.
+pub fn detect(number: &str) -> Option<DocumentKind> {
+    let number = normalize(number);
+    if number.len() == ID_V1_LEN && validate_v1(&number).is_ok() {
+        Some(DocumentKind::Mainland15)
+    } else if number.len() == ID_V2_LEN {
+        Identity::new(&number).document_kind()
+    } else if foreigner::validate_legacy(&number) {
+        Some(DocumentKind::ForeignPermanentResident)
+    } else if hk::validate(&number) && !eep::validate(&number) {
+        Some(DocumentKind::HongKong)
+    } else if mo::validate(&number) && !tbz::validate(&number) {
+        Some(DocumentKind::Macau)
+    } else if tw::validate(&number) {
+        Some(DocumentKind::Taiwan)
+    } else if hrp::validate(&number) {
+        Some(DocumentKind::HomeReturnPermit)
+    } else if tbz::validate(&number) && !mo::validate(&number) {
+        Some(DocumentKind::TaiwanCompatriotPermit)
+    } else if eep::validate(&number) && !hk::validate(&number) {
+        Some(DocumentKind::ExitEntryPermit)
     } else {
-        return None;
+        None
+    }
+}
+
+/// Normalizes an ID number, full-width characters are converted to their
+/// ASCII counterparts, whitespaces and hyphens are removed, and the letters
+/// are uppercased.
+pub fn normalize(number: &str) -> String {
+    normalized_chars(number)
+        .map(|ch| ch.to_ascii_uppercase())
+        .collect()
+}
+
+/// Converts full-width characters and removes whitespaces and hyphens, the
+/// letter case is kept.
+fn normalized_chars(number: &str) -> impl Iterator<Item = char> + '_ {
+    number
+        .chars()
+        .filter_map(|ch| match ch {
+            '\u{FF01}'..='\u{FF5E}' => std::char::from_u32(ch as u32 - 0xFEE0),
+            _ => Some(ch),
+        })
+        .filter(|ch| !ch.is_whitespace() && !is_separator(*ch))
+}
+
+fn is_separator(ch: char) -> bool {
+    matches!(ch, '-' | '\u{2010}'..='\u{2015}' | '\u{2212}')
+}
+
+/// A character replaced by the OCR correction, see [`from_ocr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution {
+    /// The index of the character in the corrected number.
+    pub position: usize,
+    /// The character recognized by the OCR.
+    pub from: char,
+    /// The character it was replaced with.
+    pub to: char,
+}
+
+/// The result of the OCR correction, see [`from_ocr`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrCorrection {
+    /// The corrected and valid ID number.
+    pub number: String,
+    /// The substitutions applied to the recognized text.
+    pub substitutions: Vec<Substitution>,
+}
+
+/// Corrects an ID number recognized by OCR, the characters commonly confused
+/// with digits(e.g. `O` to `0`, `I`/`l` to `1`, `B` to `8`) are replaced,
+/// then the result is validated.
+pub fn from_ocr(text: &str) -> Result<OcrCorrection, Error> {
+    let (number, substitutions) = correct_ocr(text);
+    validate_detailed(&number)?;
+    Ok(OcrCorrection {
+        number,
+        substitutions,
+    })
+}
+
+fn correct_ocr(text: &str) -> (String, Vec<Substitution>) {
+    let chars: Vec<char> = normalized_chars(text).collect();
+    let mut number = String::with_capacity(chars.len());
+    let mut substitutions = Vec::new();
+    for (i, &ch) in chars.iter().enumerate() {
+        let is_check_digit = chars.len() == ID_V2_LEN && i == ID_V2_LEN - 1;
+        let to = if is_check_digit && (ch == 'x' || ch == 'X') {
+            'X'
+        } else {
+            ocr_confusable(ch).unwrap_or(ch)
+        };
+        if to != ch {
+            substitutions.push(Substitution {
+                position: i,
+                from: ch,
+                to,
+            });
+        }
+        number.push(to.to_ascii_uppercase());
+    }
+    (number, substitutions)
+}
+
+fn ocr_confusable(ch: char) -> Option<char> {
+    let digit = match ch {
+        'O' | 'o' | 'D' | 'Q' => '0',
+        'I' | 'l' | 'i' | '|' | '!' => '1',
+        'Z' | 'z' => '2',
+        'S' | 's' => '5',
+        'G' | 'b' => '6',
+        'B' => '8',
+        'g' | 'q' => '9',
+        _ => return None,
     };
-    Some(result)
+    Some(digit)
 }
 
 /// Upgrades a Chinese ID number from 15-digit to 18-digit.
 pub fn upgrade(number: &str) -> Result<String, Error> {
-    let number = number.trim().to_ascii_uppercase();
+    let number = normalize(number);
     if number.len() == ID_V1_LEN && is_digital(&number) {
         let mut idv2 = String::new();
         let birthday = "19".to_owned() + &number[6..12];
-        let birth_date = NaiveDate::parse_from_str(&birthday, "%Y%m%d");
-
-        let cal = match birth_date {
-            Ok(value) => value,
-            _ => return Err(Error::UpgradeError),
+        let cal = match Date::parse_ymd(&birthday) {
+            Some(value) => value,
+            None => return Err(Error::UpgradeError),
         };
 
         idv2.push_str(&number[0..6]);
@@ -449,66 +1212,417 @@ pub fn upgrade(number: &str) -> Result<String, Error> {
     }
 }
 
-/// Validates a Chinese ID number(only supports 15/18-digit).
+/// Downgrades a Chinese ID number from 18-digit to 15-digit, only numbers
+/// with the year of birth between 1900 and 1999 can be downgraded.
+pub fn downgrade(number: &str) -> Result<String, Error> {
+    let number = normalize(number);
+    if number.len() != ID_V2_LEN {
+        return Err(Error::InvalidNumber);
+    }
+    validate_v2(&number)?;
+    if &number[6..8] != "19" {
+        return Err(Error::DowngradeError);
+    }
+    let mut idv1 = String::with_capacity(ID_V1_LEN);
+    idv1.push_str(&number[0..6]);
+    idv1.push_str(&number[8..17]);
+    Ok(idv1)
+}
+
+/// Composes an 18-digit ID number from the given region code, date of birth
+/// and sequence code, the check digit is computed and appended.
+#[cfg(feature = "chrono")]
+pub fn compose(region: &str, birth: NaiveDate, seq: u16) -> Result<String, Error> {
+    if region.len() != 6 || !is_digital(region) {
+        return Err(Error::ComposeError(
+            "The region code must be 6 digits".to_string(),
+        ));
+    }
+    if birth.year() < 1000 || birth.year() > 9999 {
+        return Err(Error::ComposeError(
+            "The year of birth must be 4 digits".to_string(),
+        ));
+    }
+    if seq > 999 {
+        return Err(Error::ComposeError(
+            "The sequence code must be less than 1000".to_string(),
+        ));
+    }
+
+    let seg17 = format!("{}{}{:0>3}", region, birth.format("%Y%m%d"), seq);
+    let iarr = string_to_integer_array(&seg17)?;
+    let weight = get_weights_sum(&iarr);
+    match get_check_code(weight) {
+        Some(code) => Ok(seg17 + code),
+        None => Err(Error::ComposeError("Invalid check code".to_string())),
+    }
+}
+
+/// Recomputes the check digit of an 18-digit ID number and returns the
+/// corrected number, the first 17 digits must be well-formed.
+pub fn repair_check_digit(number: &str) -> Result<String, Error> {
+    let number = normalize(number);
+    if number.len() != ID_V2_LEN || !number.is_char_boundary(17) {
+        return Err(ValidationError::WrongLength.into());
+    }
+    let code17 = &number[0..17];
+    if !is_digital(code17) {
+        return Err(ValidationError::NonDigitCharacter.into());
+    }
+    if Date::parse_ymd(&number[6..14]).is_none() {
+        return Err(ValidationError::InvalidBirthDate.into());
+    }
+    let iarr = string_to_integer_array(code17)?;
+    match get_check_code(get_weights_sum(&iarr)) {
+        Some(code) => Ok(code17.to_owned() + code),
+        None => Err(Error::InvalidNumber),
+    }
+}
+
+/// Suggests at most `max` corrections for a mistyped ID number, each of them
+/// differs from the given number by a single character and passes the
+/// validation.
+pub fn suggest_corrections(number: &str, max: usize) -> Vec<String> {
+    let number = normalize(number);
+    let mut found = Vec::new();
+    if !number.is_ascii() || (number.len() != ID_V1_LEN && number.len() != ID_V2_LEN) {
+        return found;
+    }
+
+    let mut candidate = number.clone().into_bytes();
+    for i in 0..candidate.len() {
+        let original = candidate[i];
+        let replacements: &[u8] = if i == ID_V2_LEN - 1 {
+            b"0123456789X"
+        } else {
+            b"0123456789"
+        };
+        for &ch in replacements {
+            if found.len() >= max {
+                return found;
+            }
+            if ch == original {
+                continue;
+            }
+            candidate[i] = ch;
+            if let Ok(value) = std::str::from_utf8(&candidate) {
+                if validate(value) {
+                    found.push(value.to_owned());
+                }
+            }
+        }
+        candidate[i] = original;
+    }
+    found
+}
+
+/// Validates a Chinese ID number(only supports 15/18-digit), the number is
+/// normalized by `normalize` first.
 pub fn validate(number: &str) -> bool {
-    let number = number.trim().to_ascii_uppercase();
+    validate_detailed(number).is_ok()
+}
+
+/// Validates a Chinese ID number(only supports 15/18-digit), returns the
+/// reason of the failure if the number is invalid.
+pub fn validate_detailed(number: &str) -> Result<(), ValidationError> {
+    // Fast path: a plain number needs no normalization, so the validation
+    // works on the bytes of the input without any heap allocation.
+    let trimmed = number.trim();
+    if trimmed
+        .bytes()
+        .all(|b| b.is_ascii_digit() || b == b'X' || b == b'x')
+    {
+        validate_normalized(trimmed)
+    } else {
+        validate_normalized(&normalize(trimmed))
+    }
+}
+
+fn validate_normalized(number: &str) -> Result<(), ValidationError> {
     if number.len() == ID_V1_LEN {
-        validate_v1(&number)
+        validate_v1(number)
     } else if number.len() == ID_V2_LEN {
-        validate_v2(&number)
+        validate_v2(number)
     } else {
-        false
+        Err(ValidationError::WrongLength)
+    }
+}
+
+/// Validates a Chinese ID number(only supports 15/18-digit) strictly, besides
+/// the checks of `validate`, the region code must exist in the region database
+/// and the date of birth must be neither in the future nor older than 150
+/// years.
+pub fn validate_strict(number: &str) -> bool {
+    validate_strict_detailed(number).is_ok()
+}
+
+/// Validates a Chinese ID number strictly like `validate_strict`, returns the
+/// reason of the failure if the number is invalid.
+pub fn validate_strict_detailed(number: &str) -> Result<(), ValidationError> {
+    validate_with(number, &ValidationPolicy::strict())
+}
+
+/// Options which can be used to configure how an ID number is validated.
+///
+/// The default policy mirrors `validate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationPolicy {
+    check_region: bool,
+    reject_future: bool,
+    accept_v1: bool,
+    min_year: Option<i32>,
+    max_year: Option<i32>,
+    max_age: Option<u32>,
+    normalize: bool,
+    ocr_tolerant: bool,
+    provider: Option<Provider>,
+}
+
+/// A region provider on a policy, compared by address.
+#[derive(Clone, Copy)]
+struct Provider(&'static dyn region::RegionProvider);
+
+impl fmt::Debug for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("RegionProvider")
+    }
+}
+
+impl PartialEq for Provider {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::addr_eq(self.0, other.0)
     }
 }
 
-fn validate_v1(number: &str) -> bool {
-    if number.len() == ID_V1_LEN && is_digital(number) {
-        let code = &number[0..2];
-        if !PROVINCE_CODE_NAME.contains_key(code) {
-            return false;
+impl Eq for Provider {}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        ValidationPolicy {
+            check_region: false,
+            reject_future: false,
+            accept_v1: true,
+            min_year: None,
+            max_year: None,
+            max_age: None,
+            normalize: true,
+            ocr_tolerant: false,
+            provider: None,
         }
+    }
+}
 
-        let birthday = "19".to_owned() + &number[6..12];
-        let birth_date = NaiveDate::parse_from_str(&birthday, "%Y%m%d");
-        birth_date.is_ok()
-    } else {
-        false
+impl ValidationPolicy {
+    /// Creates a policy that behaves the same as `validate`.
+    pub fn new() -> Self {
+        ValidationPolicy::default()
+    }
+
+    /// Creates a policy that behaves the same as `validate_strict`.
+    pub fn strict() -> Self {
+        ValidationPolicy::default()
+            .check_region(true)
+            .reject_future(true)
+            .max_age(MAX_AGE)
+    }
+
+    /// Sets whether the region code must exist in the region database.
+    pub fn check_region(mut self, yes: bool) -> Self {
+        self.check_region = yes;
+        self
+    }
+
+    /// Sets the region provider consulted by `check_region` in place of the
+    /// global one.
+    pub fn region_provider(mut self, provider: &'static dyn region::RegionProvider) -> Self {
+        self.provider = Some(Provider(provider));
+        self
+    }
+
+    /// Sets whether the date of birth in the future is rejected.
+    pub fn reject_future(mut self, yes: bool) -> Self {
+        self.reject_future = yes;
+        self
+    }
+
+    /// Sets whether the legacy 15-digit numbers are accepted.
+    pub fn accept_v1(mut self, yes: bool) -> Self {
+        self.accept_v1 = yes;
+        self
+    }
+
+    /// Sets the accepted range of the year of birth(inclusive), e.g.
+    /// `year_range(1900, 2099)` only accepts the 20th and 21st centuries.
+    pub fn year_range(mut self, min: i32, max: i32) -> Self {
+        self.min_year = Some(min);
+        self.max_year = Some(max);
+        self
+    }
+
+    /// Sets whether the number is normalized by `normalize` before the
+    /// validation, otherwise it's only trimmed and uppercased.
+    pub fn normalize(mut self, yes: bool) -> Self {
+        self.normalize = yes;
+        self
+    }
+
+    /// Sets whether the characters commonly confused by OCR are corrected
+    /// before the validation, see [`from_ocr`].
+    pub fn ocr_tolerant(mut self, yes: bool) -> Self {
+        self.ocr_tolerant = yes;
+        self
+    }
+
+    /// Sets the maximum age(based on the computer's local date).
+    pub fn max_age(mut self, age: u32) -> Self {
+        self.max_age = Some(age);
+        self
     }
 }
 
-fn validate_v2(number: &str) -> bool {
-    if number.len() != ID_V2_LEN {
-        return false;
+/// Validates a Chinese ID number(only supports 15/18-digit) using the given
+/// policy, returns the reason of the failure if the number is invalid.
+pub fn validate_with(number: &str, policy: &ValidationPolicy) -> Result<(), ValidationError> {
+    let number = if policy.ocr_tolerant {
+        correct_ocr(number).0
+    } else if policy.normalize {
+        normalize(number)
+    } else {
+        number.trim().to_ascii_uppercase()
+    };
+    if number.len() == ID_V1_LEN && policy.accept_v1 {
+        validate_v1(&number)?;
+    } else if number.len() == ID_V2_LEN {
+        validate_v2(&number)?;
+    } else {
+        return Err(ValidationError::WrongLength);
     }
 
-    let birth_date = NaiveDate::parse_from_str(&number[6..14], "%Y%m%d");
-    if !birth_date.is_ok() {
-        return false;
+    let birth = birth_date_of(&number);
+    if policy.check_region && is_foreign(&number) {
+        if Province::from_code(&number[1..3]).is_none() {
+            return Err(ValidationError::UnknownRegion);
+        }
+    } else if policy.check_region && residence_permit_region(&number[0..6]).is_none() {
+        let year = birth.map_or(0, |birth| birth.year() as u32);
+        let provider = policy.provider.map_or_else(region::provider, |p| p.0);
+        if !provider.contains_at(&number[0..6], year) {
+            return Err(ValidationError::UnknownRegion);
+        }
     }
 
-    let code17 = &number[0..17];
-    let code18 = &number[17..18];
-    if is_digital(code17) {
-        let iarr = match string_to_integer_array(code17) {
-            Ok(value) => value,
-            _ => return false,
-        };
+    let birth = birth.ok_or(ValidationError::InvalidBirthDate)?;
+    check_birth_date(birth, Date::today(), policy)
+}
 
-        let sum17 = get_weights_sum(&iarr);
-        if let Some(code) = get_check_code(sum17) {
-            if code == code18.to_uppercase() {
-                return true;
-            }
+fn check_birth_date(
+    birth: Date,
+    today: Date,
+    policy: &ValidationPolicy,
+) -> Result<(), ValidationError> {
+    if policy.reject_future && birth > today {
+        return Err(ValidationError::BirthDateOutOfRange);
+    }
+    if let Some(age) = policy.max_age {
+        if today.year() - birth.year() > age as i32 {
+            return Err(ValidationError::BirthDateOutOfRange);
+        }
+    }
+    if let Some(min) = policy.min_year {
+        if birth.year() < min {
+            return Err(ValidationError::BirthDateOutOfRange);
+        }
+    }
+    if let Some(max) = policy.max_year {
+        if birth.year() > max {
+            return Err(ValidationError::BirthDateOutOfRange);
         }
     }
-    false
+    Ok(())
+}
+
+/// Returns the date of birth of a 15/18-digit number.
+fn birth_date_of(number: &str) -> Option<Date> {
+    if number.len() == ID_V1_LEN {
+        Date::parse_ymd(&format!("19{}", number.get(6..12)?))
+    } else if number.len() == ID_V2_LEN {
+        Date::parse_ymd(number.get(6..14)?)
+    } else {
+        None
+    }
+}
+
+fn validate_v1(number: &str) -> Result<(), ValidationError> {
+    let bytes = number.as_bytes();
+    if bytes.len() != ID_V1_LEN {
+        return Err(ValidationError::WrongLength);
+    }
+    if !bytes.iter().all(u8::is_ascii_digit) {
+        return Err(ValidationError::NonDigitCharacter);
+    }
+
+    let code = &number[0..2];
+    if province_name(code).is_none() {
+        return Err(ValidationError::UnknownRegion);
+    }
+
+    let year = 1900 + parse_digits(&bytes[6..8]);
+    let month = parse_digits(&bytes[8..10]);
+    let day = parse_digits(&bytes[10..12]);
+    if Date::new(year as i32, month, day).is_none() {
+        return Err(ValidationError::InvalidBirthDate);
+    }
+    Ok(())
+}
+
+/// Validates an 18-digit number, the check digit can be either `X` or `x`.
+fn validate_v2(number: &str) -> Result<(), ValidationError> {
+    let bytes = number.as_bytes();
+    if bytes.len() != ID_V2_LEN {
+        return Err(ValidationError::WrongLength);
+    }
+
+    let code17 = &bytes[0..17];
+    let code18 = bytes[17].to_ascii_uppercase();
+    if !code17.iter().all(u8::is_ascii_digit) || !(code18.is_ascii_digit() || code18 == b'X') {
+        return Err(ValidationError::NonDigitCharacter);
+    }
+
+    let year = parse_digits(&bytes[6..10]);
+    let month = parse_digits(&bytes[10..12]);
+    let day = parse_digits(&bytes[12..14]);
+    if Date::new(year as i32, month, day).is_none() {
+        return Err(ValidationError::InvalidBirthDate);
+    }
+
+    if check_code_of(code17) != code18 {
+        return Err(ValidationError::ChecksumMismatch);
+    }
+    Ok(())
+}
+
+/// Parses ASCII digits into an integer, the bytes must be digits.
+fn parse_digits(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'))
+}
+
+/// Computes the check digit of the first 17 digits, the bytes must be digits.
+fn check_code_of(code17: &[u8]) -> u8 {
+    let sum: u32 = code17
+        .iter()
+        .zip(WEIGHTS.iter())
+        .map(|(b, w)| u32::from(b - b'0') * w)
+        .sum();
+    CHECK_CODES[(sum % 11) as usize]
 }
 
 fn is_digital(s: &str) -> bool {
     if s.is_empty() {
         false
     } else {
-        s.chars().all(char::is_numeric)
+        s.chars().all(|ch| ch.is_ascii_digit())
     }
 }
 
@@ -542,13 +1656,12 @@ fn string_to_integer_array(s: &str) -> Result<Vec<u32>, Error> {
 }
 
 fn get_weights_sum(arr: &[u32]) -> u32 {
-    let weights = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
     let mut sum = 0;
-    if weights.len() == arr.len() {
+    if WEIGHTS.len() == arr.len() {
         for i in 0..arr.len() {
-            for j in 0..weights.len() {
+            for j in 0..WEIGHTS.len() {
                 if i == j {
-                    sum = sum + arr[i] * weights[j];
+                    sum = sum + arr[i] * WEIGHTS[j];
                 }
             }
         }
@@ -569,12 +1682,495 @@ mod tests {
         assert_eq!(&id, "310112198504095227");
     }
 
+    #[test]
+    fn test_downgrade() {
+        assert_eq!(downgrade("632123198209270518").unwrap(), "632123820927051");
+        assert_eq!(
+            downgrade(" 21021119810503545x ").unwrap(),
+            "210211810503545"
+        );
+        assert_eq!(
+            upgrade(&downgrade("310112198504095227").unwrap()).unwrap(),
+            "310112198504095227"
+        );
+        assert!(matches!(
+            downgrade("11010120000229123X"),
+            Err(Error::DowngradeError)
+        ));
+        assert!(matches!(
+            downgrade("632123198209270519"),
+            Err(Error::ValidationFailed(ValidationError::ChecksumMismatch))
+        ));
+        assert!(matches!(
+            downgrade("632123820927051"),
+            Err(Error::InvalidNumber)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_validate_strict() {
+        assert!(validate_strict("830000199001011234"));
+        assert_eq!(
+            validate_strict_detailed("810101199001011232"),
+            Err(ValidationError::UnknownRegion)
+        );
+        assert!(validate_strict("230127197908177456"));
+        assert!(validate_strict("310112850409522"));
+        assert!(validate("632123820927051"));
+        assert_eq!(validate_strict_detailed("632123820927051"), Ok(()));
+        assert!(validate_strict("632123198209270518"));
+        assert!(validate_strict("341421199001010010"));
+        assert_eq!(
+            validate_strict_detailed("632123201401010016"),
+            Err(ValidationError::UnknownRegion)
+        );
+        assert_eq!(
+            validate_strict_detailed("230127197908177457"),
+            Err(ValidationError::ChecksumMismatch)
+        );
+        assert!(validate("110199199001011232"));
+        assert_eq!(
+            validate_strict_detailed("110199199001011232"),
+            Err(ValidationError::UnknownRegion)
+        );
+        assert!(validate_strict("11010319850101001X"));
+        assert_eq!(
+            validate_strict_detailed("110103201501010014"),
+            Err(ValidationError::UnknownRegion)
+        );
+        assert!(validate("11010118500101123X"));
+        assert_eq!(
+            validate_strict_detailed("11010118500101123X"),
+            Err(ValidationError::BirthDateOutOfRange)
+        );
+
+        let today = Date::new(2021, 6, 1).unwrap();
+        let date = |y, m, d| Date::new(y, m, d).unwrap();
+        let strict = ValidationPolicy::strict();
+        assert_eq!(check_birth_date(date(2021, 6, 1), today, &strict), Ok(()));
+        assert_eq!(
+            check_birth_date(date(2021, 6, 2), today, &strict),
+            Err(ValidationError::BirthDateOutOfRange)
+        );
+        assert_eq!(check_birth_date(date(1871, 1, 1), today, &strict), Ok(()));
+        assert_eq!(
+            check_birth_date(date(1870, 12, 31), today, &strict),
+            Err(ValidationError::BirthDateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_validate_with() {
+        let policy = ValidationPolicy::default();
+        assert_eq!(validate_with("632123820927051", &policy), Ok(()));
+        assert_eq!(validate_with("11010118500101123X", &policy), Ok(()));
+        assert_eq!(validate_with("110101299001011235", &policy), Ok(()));
+
+        let policy = ValidationPolicy::new().accept_v1(false);
+        assert_eq!(
+            validate_with("632123820927051", &policy),
+            Err(ValidationError::WrongLength)
+        );
+        assert_eq!(validate_with("632123198209270518", &policy), Ok(()));
+
+        let policy = ValidationPolicy::new().check_region(true);
+        assert_eq!(
+            validate_with("110199199001011232", &policy),
+            Err(ValidationError::UnknownRegion)
+        );
+        #[cfg(feature = "region-minimal")]
+        assert_eq!(validate_with("632123198209270518", &policy), Ok(()));
+
+        struct Only;
+        impl region::RegionProvider for Only {
+            fn name(&self, code: &str) -> Option<&str> {
+                Some("测试区").filter(|_| code == "710101")
+            }
+
+            fn codes_with_prefix<'a>(&'a self, _: &str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+                Box::new(std::iter::empty())
+            }
+        }
+        static ONLY: Only = Only;
+        let policy = ValidationPolicy::new()
+            .check_region(true)
+            .region_provider(&ONLY);
+        assert_eq!(validate_with("710101199001010017", &policy), Ok(()));
+        assert_eq!(
+            validate_with("511702197409284963", &policy),
+            Err(ValidationError::UnknownRegion)
+        );
+
+        let policy = ValidationPolicy::new().reject_future(true);
+        assert_eq!(
+            validate_with("110101299001011235", &policy),
+            Err(ValidationError::BirthDateOutOfRange)
+        );
+
+        let policy = ValidationPolicy::new().year_range(1900, 1999);
+        assert_eq!(validate_with("632123820927051", &policy), Ok(()));
+        assert_eq!(
+            validate_with("11010118500101123X", &policy),
+            Err(ValidationError::BirthDateOutOfRange)
+        );
+        assert_eq!(
+            validate_with("11010120000229123X", &policy),
+            Err(ValidationError::BirthDateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_repair_check_digit() {
+        assert_eq!(
+            repair_check_digit("230127197908177457").unwrap(),
+            "230127197908177456"
+        );
+        assert_eq!(
+            repair_check_digit("210211198105035451").unwrap(),
+            "21021119810503545X"
+        );
+        assert_eq!(
+            repair_check_digit("21021119810503545x").unwrap(),
+            "21021119810503545X"
+        );
+        assert_eq!(
+            repair_check_digit("21021119810503545?").unwrap(),
+            "21021119810503545X"
+        );
+        assert!(repair_check_digit("2102111981050354").is_err());
+        assert!(repair_check_digit("2102111981A503545X").is_err());
+        assert!(repair_check_digit("210211198102305451").is_err());
+    }
+
+    #[test]
+    fn test_suggest_corrections() {
+        let found = suggest_corrections("230127197908177457", 100);
+        assert!(found.contains(&"230127197908177456".to_string()));
+        assert!(found.iter().all(|s| validate(s)));
+        assert!(found.len() > 1);
+        assert_eq!(suggest_corrections("230127197908177457", 1).len(), 1);
+        assert!(suggest_corrections("230127197908177457", 0).is_empty());
+
+        let found = suggest_corrections("210211198105035451", 100);
+        assert!(found.contains(&"21021119810503545X".to_string()));
+
+        let found = suggest_corrections("011702800222130", 100);
+        assert!(found.contains(&"511702800222130".to_string()));
+        assert!(suggest_corrections("2301271979", 100).is_empty());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(" 21021119810503545x "), "21021119810503545X");
+        assert_eq!(
+            normalize("２１０２１１１９８１０５０３５４５ｘ"),
+            "21021119810503545X"
+        );
+        assert_eq!(normalize("210211 19810503 545X"), "21021119810503545X");
+        assert_eq!(normalize("210211-19810503-545X"), "21021119810503545X");
+        assert_eq!(
+            normalize("210211\u{3000}19810503－545Ｘ"),
+            "21021119810503545X"
+        );
+
+        assert!(validate("２１０２１１１９８１０５０３５４５Ｘ"));
+        assert!(validate("210211 19810503 545X"));
+        assert!(Identity::new("632123-820927-051").is_valid());
+        assert_eq!(
+            upgrade("６３２１２３８２０９２７０５１").unwrap(),
+            "632123198209270518"
+        );
+        assert_eq!(
+            validate_with(
+                "210211 19810503 545X",
+                &ValidationPolicy::new().normalize(false)
+            ),
+            Err(ValidationError::WrongLength)
+        );
+        assert_eq!(
+            validate_detailed("1234567890123456é"),
+            Err(ValidationError::NonDigitCharacter)
+        );
+    }
+
+    #[test]
+    fn test_from_ocr() {
+        let fixed = from_ocr("2lO2ll198lO5O3545x").unwrap();
+        assert_eq!(fixed.number, "21021119810503545X");
+        assert_eq!(fixed.substitutions.len(), 8);
+        assert_eq!(
+            fixed.substitutions[0],
+            Substitution {
+                position: 1,
+                from: 'l',
+                to: '1'
+            }
+        );
+        assert_eq!(
+            fixed.substitutions[7],
+            Substitution {
+                position: 17,
+                from: 'x',
+                to: 'X'
+            }
+        );
+
+        let fixed = from_ocr("63Z123 B2O927 O5I").unwrap();
+        assert_eq!(fixed.number, "632123820927051");
+        assert_eq!(fixed.substitutions.len(), 5);
+
+        let fixed = from_ocr("230127197908177456").unwrap();
+        assert!(fixed.substitutions.is_empty());
+        assert!(from_ocr("23O127197908177457").is_err());
+
+        let policy = ValidationPolicy::new().ocr_tolerant(true);
+        assert_eq!(validate_with("2lO2ll198lO5O3545x", &policy), Ok(()));
+        assert!(validate_with("2lO2ll198lO5O3545x", &ValidationPolicy::new()).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_compose() {
+        let birth = NaiveDate::from_ymd_opt(1981, 5, 3).unwrap();
+        assert_eq!(compose("210211", birth, 545).unwrap(), "21021119810503545X");
+        let birth = NaiveDate::from_ymd_opt(1982, 9, 27).unwrap();
+        assert_eq!(compose("632123", birth, 51).unwrap(), "632123198209270518");
+        assert!(compose("6321", birth, 51).is_err());
+        assert!(compose("63212a", birth, 51).is_err());
+        assert!(compose("632123", birth, 1000).is_err());
+        let birth = NaiveDate::from_ymd_opt(999, 9, 27).unwrap();
+        assert!(compose("632123", birth, 51).is_err());
+    }
+
     #[test]
     fn test_validate() {
         assert_eq!(validate("511702800222130"), true);
         assert_eq!(validate("230127197908177456"), true);
     }
 
+    #[test]
+    fn test_validate_fast_path() {
+        assert_eq!(check_code_of(b"21021119810503545"), b'X');
+        assert_eq!(check_code_of(b"23012719790817745"), b'6');
+        assert_eq!(parse_digits(b"1981"), 1981);
+        assert!(validate("21021119810503545x"));
+        assert!(validate(" 21021119810503545X\n"));
+        assert!(validate("632123820927051"));
+        assert!(!validate("632123821327051"));
+        assert!(!validate("X32123198209270518"));
+        assert!(!validate("63212319820927051X"));
+    }
+
+    #[test]
+    fn test_validate_detailed() {
+        assert_eq!(validate_detailed("230127197908177456"), Ok(()));
+        assert_eq!(validate_detailed("511702800222130"), Ok(()));
+        assert_eq!(
+            validate_detailed("23012719790817745"),
+            Err(ValidationError::WrongLength)
+        );
+        assert_eq!(
+            validate_detailed("2301271979O8177456"),
+            Err(ValidationError::NonDigitCharacter)
+        );
+        assert_eq!(
+            validate_detailed("230127197902307456"),
+            Err(ValidationError::InvalidBirthDate)
+        );
+        assert_eq!(
+            validate_detailed("001702800222130"),
+            Err(ValidationError::UnknownRegion)
+        );
+        assert_eq!(
+            validate_detailed("230127197908177457"),
+            Err(ValidationError::ChecksumMismatch)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_birth_naive_date() {
+        let id = Identity::new("511702800222130");
+        assert_eq!(
+            id.birth_naive_date(),
+            Some(NaiveDate::from_ymd_opt(1980, 2, 22).unwrap())
+        );
+        assert_eq!(Identity::new("511702198002221309").birth_naive_date(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_age_on() {
+        let id = Identity::new("511702800222130");
+        let on = |y, m, d| id.age_on(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(on(2020, 2, 21), Some(39));
+        assert_eq!(on(2020, 2, 22), Some(40));
+        assert_eq!(on(1980, 2, 22), Some(0));
+        assert_eq!(on(1980, 2, 21), None);
+
+        let id = Identity::new("11010120000229123X");
+        let on = |y, m, d| id.age_on(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(on(2001, 2, 28), Some(0));
+        assert_eq!(on(2001, 3, 1), Some(1));
+        assert!(id.age().is_some());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_age_precise() {
+        let parts = |years, months, days| AgeParts {
+            years,
+            months,
+            days,
+        };
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let id = Identity::new("511702800222130");
+        assert_eq!(id.age_precise(date(1980, 2, 22)), Some(parts(0, 0, 0)));
+        assert_eq!(id.age_precise(date(2020, 2, 21)), Some(parts(39, 11, 30)));
+        assert_eq!(id.age_precise(date(2020, 5, 1)), Some(parts(40, 2, 9)));
+        assert_eq!(id.age_precise(date(1980, 2, 21)), None);
+
+        let id = Identity::new("11010120000229123X");
+        assert_eq!(id.age_precise(date(2001, 2, 28)), Some(parts(0, 11, 30)));
+        assert_eq!(id.age_precise(date(2001, 3, 1)), Some(parts(1, 0, 1)));
+        assert_eq!(id.age_precise(date(2004, 2, 29)), Some(parts(4, 0, 0)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_is_at_least() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let id = Identity::new("11010120000229123X");
+        assert!(!id.is_at_least(18, date(2018, 2, 28)));
+        assert!(id.is_at_least(18, date(2018, 3, 1)));
+        assert!(id.is_at_least(0, date(2000, 2, 29)));
+        assert!(!id.is_at_least(0, date(2000, 2, 28)));
+        assert!(id.is_adult());
+        assert!(!Identity::new("11010120000229123").is_adult());
+    }
+
+    #[test]
+    fn test_sequence_code() {
+        let id = Identity::new("21021119810503545x");
+        assert_eq!(id.sequence_code(), Some("545"));
+        assert_eq!(id.check_digit(), Some('X'));
+        let id = Identity::new("632123820927051");
+        assert_eq!(id.sequence_code(), Some("051"));
+        assert_eq!(id.check_digit(), Some('8'));
+        let id = Identity::new("21021119810503545");
+        assert_eq!(id.sequence_code(), None);
+        assert_eq!(id.check_digit(), None);
+    }
+
+    #[test]
+    fn test_chinese_zodiac_accurate() {
+        let id = Identity::new("11010319850101001X");
+        assert_eq!(id.chinese_zodiac(), Some("牛"));
+        assert_eq!(id.chinese_zodiac_accurate(), Some("鼠"));
+        assert_eq!(id.chinese_zodiac_with(YearStart::SpringBegins), Some("鼠"));
+        let id = Identity::new("632123820927051");
+        assert_eq!(id.chinese_zodiac_accurate(), Some("狗"));
+        assert_eq!(
+            Identity::new("110101190001010014").chinese_zodiac_accurate(),
+            None
+        );
+
+        let lny = YearStart::LunarNewYear;
+        let spring = YearStart::SpringBegins;
+        assert_eq!(chinese_zodiac_on(1985, 2, 4, lny), Some("鼠"));
+        assert_eq!(chinese_zodiac_on(1985, 2, 4, spring), Some("牛"));
+        assert_eq!(chinese_zodiac_on(1985, 2, 20, lny), Some("牛"));
+        assert_eq!(chinese_zodiac_on(2021, 2, 2, spring), Some("鼠"));
+        assert_eq!(chinese_zodiac_on(2021, 2, 3, spring), Some("牛"));
+        assert_eq!(chinese_zodiac_on(1900, 1, 1, spring), Some("猪"));
+        assert_eq!(chinese_zodiac_on(2021, 2, 30, lny), None);
+        assert_eq!(chinese_zodiac_on(1800, 6, 1, lny), None);
+    }
+
+    #[test]
+    fn test_enum_accessors() {
+        let id = Identity::new("511702197409284963");
+        assert_eq!(id.constellation_enum(), Some(Constellation::Libra));
+        assert_eq!(id.chinese_zodiac_enum(), Some(Zodiac::Tiger));
+        assert_eq!(id.chinese_zodiac_enum().unwrap().name_en(), "Tiger");
+        let id = Identity::new("511702198002221309");
+        assert_eq!(id.constellation_enum(), None);
+        assert_eq!(id.chinese_zodiac_enum(), None);
+    }
+
+    #[test]
+    fn test_lunar_birth_date() {
+        let id = Identity::new("632123820927051");
+        let date = id.lunar_birth_date().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1982, 8, 11));
+        assert_eq!(date.to_string(), "一九八二年八月十一");
+        assert_eq!(Identity::new("110101190001010014").lunar_birth_date(), None);
+        assert_eq!(Identity::new("632123198209270519").lunar_birth_date(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parts() {
+        let id = Identity::new("21021119810503545x");
+        let IdParts {
+            region_code,
+            birth_date,
+            sequence,
+            check_digit,
+        } = id.parts().unwrap();
+        assert_eq!(region_code, "210211");
+        assert_eq!(birth_date, NaiveDate::from_ymd_opt(1981, 5, 3).unwrap());
+        assert_eq!(sequence, "545");
+        assert_eq!(check_digit, 'X');
+        assert!(Identity::new("21021119810503545").parts().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_region() {
+        let id = Identity::new("511702197409284963");
+        assert_eq!(id.region(), Some("四川省达州市通川区"));
+        let id = Identity::new("11010319850101001X");
+        assert_eq!(id.region(), Some("北京市崇文区"));
+        let id = Identity::new("110103201501010014");
+        assert!(id.is_valid());
+        assert_eq!(id.region(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_locale() {
+        let id = Identity::new("511702197409284963");
+        assert_eq!(id.province_in(Locale::ZhHans).as_deref(), Some("四川"));
+        assert_eq!(id.province_in(Locale::En).as_deref(), Some("Sichuan"));
+        assert_eq!(
+            id.region_in(Locale::ZhHant).as_deref(),
+            Some("四川省達州市通川區")
+        );
+        assert_eq!(
+            id.region_in(Locale::En).as_deref(),
+            Some("Sichuan, Dazhou, Tongchuan District")
+        );
+        assert_eq!(id.constellation_in(Locale::En).as_deref(), Some("Libra"));
+        assert_eq!(id.chinese_zodiac_in(Locale::En).as_deref(), Some("Tiger"));
+        let id = Identity::new("11010319850101001X");
+        assert_eq!(id.chinese_zodiac_in(Locale::ZhHant).as_deref(), Some("牛"));
+        assert_eq!(id.region_in(Locale::En), None);
+        assert_eq!(Identity::new("").province_in(Locale::En), None);
+        let id = Identity::new("91184019900101123X");
+        assert_eq!(id.province_in(Locale::En).as_deref(), Some("Foreign"));
+        assert_eq!(id.province_in(Locale::ZhHant).as_deref(), Some("國外"));
+        let id = Identity::new("19850101");
+        assert_eq!(id.constellation_in(Locale::En), None);
+        let id = Identity::new("110103198512310018");
+        assert_eq!(
+            id.constellation_in(Locale::ZhHans).as_deref(),
+            Some("摩羯座")
+        );
+    }
+
     #[test]
     fn test_compute_age() {
         let id = Identity::new("511702800222130");
@@ -583,19 +2179,292 @@ mod tests {
         assert_eq!(id.age_in_year(1900), None);
     }
 
+    #[test]
+    fn test_province_name() {
+        assert_eq!(province_name("11"), Some("北京"));
+        assert_eq!(province_name("83"), Some("台湾"));
+        assert_eq!(province_name("91"), Some("国外"));
+        assert_eq!(province_name("10"), None);
+    }
+
+    #[test]
+    fn test_residence_permit() {
+        let id = Identity::new("810000199001011230");
+        assert_eq!(id.document_kind(), Some(DocumentKind::ResidencePermit));
+        assert_eq!(id.region(), Some("香港特别行政区"));
+        assert_eq!(id.province(), Some("香港"));
+        assert_eq!(id.gender(), Some(Gender::Male));
+        let id = Identity::new("820000199001011232");
+        assert_eq!(id.region(), Some("澳门特别行政区"));
+        let id = Identity::new("830000199001011234");
+        assert_eq!(id.document_kind(), Some(DocumentKind::ResidencePermit));
+        assert_eq!(id.region(), Some("台湾省"));
+        assert_eq!(id.province_enum(), Some(Province::Taiwan));
+        let id = Identity::new("830000199001011233");
+        assert_eq!(id.document_kind(), None);
+        let id = Identity::new("230127197908177456");
+        assert_eq!(id.document_kind(), Some(DocumentKind::Mainland18));
+    }
+
+    #[test]
+    fn test_foreign_permanent_resident() {
+        let id = Identity::new("91184019900101123X");
+        assert!(id.is_valid());
+        assert_eq!(
+            id.document_kind(),
+            Some(DocumentKind::ForeignPermanentResident)
+        );
+        assert_eq!(id.nationality_code(), Some("840"));
+        assert_eq!(id.issuing_province(), Some(Province::Beijing));
+        assert_eq!(id.birth_date().as_deref(), Some("1990-01-01"));
+        assert_eq!(id.gender(), Some(Gender::Male));
+        assert_eq!(id.province(), Some("国外"));
+        assert_eq!(id.province_enum(), None);
+        assert_eq!(id.region(), None);
+        assert!(validate_strict("91184019900101123X"));
+        assert_eq!(
+            validate_strict_detailed("999840199001011223"),
+            Err(ValidationError::UnknownRegion)
+        );
+        let id = Identity::new("230127197908177456");
+        assert_eq!(id.nationality_code(), None);
+        assert_eq!(id.issuing_province(), None);
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect("632123820927051"), Some(DocumentKind::Mainland15));
+        assert_eq!(detect("230127197908177456"), Some(DocumentKind::Mainland18));
+        assert_eq!(
+            detect("830000199001011234"),
+            Some(DocumentKind::ResidencePermit)
+        );
+        assert_eq!(
+            detect("91184019900101123X"),
+            Some(DocumentKind::ForeignPermanentResident)
+        );
+        assert_eq!(
+            detect("USA119001011221"),
+            Some(DocumentKind::ForeignPermanentResident)
+        );
+        assert_eq!(detect("G123456(A)"), Some(DocumentKind::HongKong));
+        assert_eq!(detect("1123456(A)"), Some(DocumentKind::Macau));
+        assert_eq!(detect("A123456789"), Some(DocumentKind::Taiwan));
+        assert_eq!(detect("H12345678"), Some(DocumentKind::HomeReturnPermit));
+        assert_eq!(
+            detect("02345678(01)"),
+            Some(DocumentKind::TaiwanCompatriotPermit)
+        );
+        assert_eq!(detect("1234567(8)"), Some(DocumentKind::Macau));
+        assert_eq!(detect("5215299A"), Some(DocumentKind::Macau));
+        assert_eq!(detect("12345678"), None);
+        assert_eq!(detect("72345678"), None);
+        assert_eq!(
+            detect("22345678"),
+            Some(DocumentKind::TaiwanCompatriotPermit)
+        );
+        assert_eq!(
+            detect("12345678(01)"),
+            Some(DocumentKind::TaiwanCompatriotPermit)
+        );
+        assert_eq!(detect("C12345678"), Some(DocumentKind::ExitEntryPermit));
+        assert_eq!(detect("CA1234579"), Some(DocumentKind::ExitEntryPermit));
+        assert_eq!(detect("CA1234578"), None);
+        assert_eq!(detect("CA123457(8)"), Some(DocumentKind::HongKong));
+        assert_eq!(detect("W12345678"), Some(DocumentKind::ExitEntryPermit));
+        assert_eq!(detect("230127197908177457"), None);
+        assert_eq!(detect("hello"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+        let mut u = Unstructured::new(&data);
+        let mut valid = 0;
+        for _ in 0..100 {
+            let id = Identity::arbitrary(&mut u).unwrap();
+            assert_eq!(id.len(), 18);
+            if id.is_valid() {
+                valid += 1;
+            } else {
+                assert_eq!(
+                    validate_detailed(id.number()),
+                    Err(ValidationError::ChecksumMismatch)
+                );
+            }
+        }
+        assert!(valid > 50);
+        assert!(Gender::arbitrary(&mut u).is_ok());
+    }
+
+    #[test]
+    fn test_province_enum() {
+        let id = Identity::new("511702197409284963");
+        assert_eq!(id.province_enum(), Some(Province::Sichuan));
+        assert_eq!(id.province(), Some("四川"));
+        assert_eq!(Identity::new("").province_enum(), None);
+    }
+
     #[test]
     fn test_utilities() {
         assert_eq!(chinese_zodiac(1000), Some("鼠"));
         assert_eq!(chinese_zodiac(1900), Some("鼠"));
         assert_eq!(chinese_zodiac(2021), Some("牛"));
-        assert_eq!(chinese_era(1000), Some("庚子".to_string()));
-        assert_eq!(chinese_era(1900), Some("庚子".to_string()));
-        assert_eq!(chinese_era(2021), Some("辛丑".to_string()));
+        assert_eq!(chinese_era(1000).unwrap().to_string(), "庚子");
+        assert_eq!(chinese_era(1900).unwrap().to_string(), "庚子");
+        assert_eq!(chinese_era(2021).unwrap().to_string(), "辛丑");
+        assert_eq!(chinese_era(1992).unwrap().to_string(), "壬申");
+        assert_eq!(chinese_era(999), None);
         assert_eq!(constellation(10, 25), Some("天蝎座"));
         assert_eq!(constellation(2, 29), Some("双鱼座"));
         assert_eq!(constellation(0, 32), None);
     }
 
+    #[test]
+    fn test_try_new() {
+        let id = Identity::try_new("632123820927051").unwrap();
+        assert_eq!(id.number(), "632123198209270518");
+        assert!(Identity::try_new("21021119810503545x").is_ok());
+        match Identity::try_new("230127197908177457") {
+            Err(Error::ValidationFailed(reason)) => {
+                assert_eq!(reason, ValidationError::ChecksumMismatch)
+            }
+            _ => panic!("expected a validation error"),
+        }
+        assert!(Identity::try_new("").is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let id: Identity = "632123820927051".parse().unwrap();
+        assert_eq!(id.number(), "632123198209270518");
+        assert!("230127197908177457".parse::<Identity>().is_err());
+        let id = Identity::try_from("230127197908177456").unwrap();
+        assert!(id.is_valid());
+        assert!(Identity::try_from("abc").is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        let id = Identity::new("21021119810503545x");
+        assert_eq!(id.format(FormatStyle::Plain), "21021119810503545X");
+        assert_eq!(id.format(FormatStyle::Spaced), "210211 19810503 545X");
+        assert_eq!(id.format(FormatStyle::Hyphenated), "210211-19810503-545X");
+        let parsed: Identity = id.format(FormatStyle::Spaced).parse().unwrap();
+        assert_eq!(parsed, id);
+        let parsed: Identity = id.format(FormatStyle::Hyphenated).parse().unwrap();
+        assert_eq!(parsed, id);
+        assert_eq!(Identity::new("123").format(FormatStyle::Spaced), "123");
+    }
+
+    #[test]
+    fn test_mask() {
+        let id = Identity::new("21021119810503545x");
+        assert_eq!(id.mask(MaskStyle::KeepFirst6Last4), "210211********545X");
+        assert_eq!(id.mask(MaskStyle::default()), "210211********545X");
+        assert_eq!(id.mask(MaskStyle::KeepBirthYearOnly), "******1981********");
+        assert_eq!(
+            id.mask(MaskStyle::Range { start: 10, end: 14 }),
+            "2102111981****545X"
+        );
+        assert_eq!(
+            id.mask(MaskStyle::Range { start: 16, end: 30 }),
+            "2102111981050354**"
+        );
+        assert_eq!(Identity::new("123").mask(MaskStyle::KeepFirst6Last4), "123");
+    }
+
+    #[test]
+    fn test_info() {
+        let id = Identity::new("511702800222130");
+        let info = id.info().unwrap();
+        assert_eq!(info.number, "511702198002221308");
+        assert_eq!(info.gender, Gender::Female);
+        assert_eq!(info.birth_date, "1980-02-22");
+        assert_eq!((info.year, info.month, info.day), (1980, 2, 22));
+        assert_eq!(info.province.as_deref(), Some("四川"));
+        assert_eq!(info.region_code, "511702");
+        assert_eq!(info.constellation.as_deref(), Some("双鱼座"));
+        assert_eq!(info.chinese_era, Some(ChineseEra::from_year(1980)));
+        assert_eq!(info.chinese_zodiac.as_deref(), Some("猴"));
+        assert!(Identity::new("511702198002221309").info().is_none());
+
+        let json = id.to_json_string().unwrap();
+        assert!(json.starts_with("{\"number\":\"511702198002221308\",\"gender\":\"Female\","));
+        assert!(json.contains("\"birth_date\":\"1980-02-22\""));
+        assert!(json.contains("\"year\":1980,"));
+        assert!(json.contains("\"chinese_era\":\"庚申\","));
+        assert!(json.ends_with("\"chinese_zodiac\":\"猴\"}"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let id = Identity::new("632123820927051");
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"632123198209270518\"");
+        let de: Identity = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, id);
+        let de: Identity = serde_json::from_str("\"632123820927051\"").unwrap();
+        assert_eq!(de, id);
+
+        let err = serde_json::from_str::<Identity>("\"230127197908177457\"").unwrap_err();
+        assert!(err.to_string().contains("Checksum Mismatch"));
+
+        let json = serde_json::to_string(&Gender::Female).unwrap();
+        assert_eq!(json, "\"Female\"");
+        let gender: Gender = serde_json::from_str(&json).unwrap();
+        assert_eq!(gender, Gender::Female);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json_value() {
+        let id = Identity::new("511702800222130");
+        let value = id.to_json_value().unwrap();
+        assert_eq!(value["number"], "511702198002221308");
+        assert_eq!(value["gender"], "Female");
+        assert_eq!(value["year"], 1980);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&id.to_json_string().unwrap()).unwrap();
+        assert_eq!(parsed, value);
+        assert!(Identity::new("511702198002221309")
+            .to_json_value()
+            .is_none());
+    }
+
+    #[test]
+    fn test_identity_storage() {
+        let id = Identity::new("632123820927051");
+        let copied = id;
+        assert_eq!(copied, id);
+        assert_eq!(copied.number(), "632123198209270518");
+        assert_eq!(copied.len(), 18);
+        assert_eq!(
+            format!("{:?}", id),
+            "Identity { number: \"632123198209270518\", valid: true }"
+        );
+
+        let id = Identity::new("12345");
+        assert_eq!(id.number(), "12345");
+        assert!(!id.is_valid());
+        assert!(Identity::new("").is_empty());
+        let id = Identity::new("1234567890123456789012");
+        assert!(!id.is_valid());
+        assert!(id.is_empty());
+        assert!(matches!(
+            Identity::try_new("1234567890123456789012"),
+            Err(Error::ValidationFailed(ValidationError::WrongLength))
+        ));
+        let id = Identity::new("12345678901234567中文");
+        assert!(!id.is_valid());
+        assert_eq!(id.number(), "");
+        assert!(std::mem::size_of::<Identity>() <= 32);
+    }
+
     #[test]
     fn test_identity() {
         let a = Identity::new("632123820927051");
@@ -0,0 +1,490 @@
+//! Localization of the names, in simplified Chinese, traditional Chinese or
+//! English.
+
+use crate::{Constellation, Province, Zodiac};
+
+/// The locales which the names can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// Simplified Chinese(zh-Hans), the names in the crate are written in.
+    #[default]
+    ZhHans,
+    /// Traditional Chinese(zh-Hant).
+    ZhHant,
+    /// English.
+    En,
+}
+
+impl Locale {
+    /// Renders the simplified Chinese name in the locale, returns `None` if
+    /// there's no English translation of it. The English names are known for
+    /// the provinces, the constellations and the zodiac animals.
+    pub fn translate(self, name: &str) -> Option<String> {
+        match self {
+            Locale::ZhHans => Some(name.to_owned()),
+            Locale::ZhHant => Some(name.chars().map(traditional).collect()),
+            Locale::En => english(name).map(str::to_owned),
+        }
+    }
+
+    /// Renders a name of which both the simplified Chinese and the English
+    /// forms are known.
+    pub(crate) fn render(self, zh: &str, en: &str) -> String {
+        match self {
+            Locale::ZhHans => zh.to_owned(),
+            Locale::ZhHant => zh.chars().map(traditional).collect(),
+            Locale::En => en.to_owned(),
+        }
+    }
+}
+
+/// Returns the English name of a province, a constellation or a zodiac animal.
+fn english(name: &str) -> Option<&'static str> {
+    if name == "国外" {
+        return Some("Foreign");
+    }
+    if let Some(p) = Province::ALL.iter().find(|p| p.name() == name) {
+        return Some(p.name_en());
+    }
+    if let Some(c) = Constellation::from_name(name) {
+        return Some(c.name_en());
+    }
+    Zodiac::ALL
+        .iter()
+        .find(|z| z.name_zh() == name)
+        .map(|z| z.name_en())
+}
+
+/// Converts a simplified character to the traditional one.
+fn traditional(c: char) -> char {
+    TRADITIONAL
+        .binary_search_by_key(&c, |&(s, _)| s)
+        .map_or(c, |i| TRADITIONAL[i].1)
+}
+
+/// The simplified characters(of the names in the crate) that differ in
+/// traditional Chinese, sorted. It's generated by ICU(`Hans-Hant`), leaving out
+/// the characters whose traditional forms depend on the context in the names,
+/// e.g. `干`(`余干县`) and `范`(`范县`).
+static TRADITIONAL: [(char, char); 389] = [
+    ('万', '萬'),
+    ('业', '業'),
+    ('丛', '叢'),
+    ('东', '東'),
+    ('两', '兩'),
+    ('个', '個'),
+    ('丰', '豐'),
+    ('临', '臨'),
+    ('为', '為'),
+    ('丽', '麗'),
+    ('义', '義'),
+    ('乌', '烏'),
+    ('乐', '樂'),
+    ('习', '習'),
+    ('乡', '鄉'),
+    ('云', '雲'),
+    ('亚', '亞'),
+    ('仆', '僕'),
+    ('从', '從'),
+    ('仑', '侖'),
+    ('仓', '倉'),
+    ('仪', '儀'),
+    ('们', '們'),
+    ('会', '會'),
+    ('伟', '偉'),
+    ('伦', '倫'),
+    ('儿', '兒'),
+    ('兖', '兗'),
+    ('党', '黨'),
+    ('兰', '蘭'),
+    ('关', '關'),
+    ('兴', '興'),
+    ('内', '內'),
+    ('冈', '岡'),
+    ('册', '冊'),
+    ('军', '軍'),
+    ('农', '農'),
+    ('冲', '衝'),
+    ('凉', '涼'),
+    ('凤', '鳳'),
+    ('凭', '憑'),
+    ('凯', '凱'),
+    ('则', '則'),
+    ('刚', '剛'),
+    ('别', '別'),
+    ('剑', '劍'),
+    ('劝', '勸'),
+    ('务', '務'),
+    ('匀', '勻'),
+    ('区', '區'),
+    ('华', '華'),
+    ('单', '單'),
+    ('卢', '盧'),
+    ('卧', '臥'),
+    ('卫', '衛'),
+    ('厂', '廠'),
+    ('历', '歷'),
+    ('厢', '廂'),
+    ('厦', '廈'),
+    ('县', '縣'),
+    ('双', '雙'),
+    ('叙', '敘'),
+    ('叠', '疊'),
+    ('叶', '葉'),
+    ('后', '後'),
+    ('吕', '呂'),
+    ('启', '啓'),
+    ('吴', '吳'),
+    ('响', '響'),
+    ('团', '團'),
+    ('园', '園'),
+    ('围', '圍'),
+    ('国', '國'),
+    ('图', '圖'),
+    ('圣', '聖'),
+    ('场', '場'),
+    ('坛', '壇'),
+    ('坝', '壩'),
+    ('垒', '壘'),
+    ('垦', '墾'),
+    ('垫', '墊'),
+    ('壮', '壯'),
+    ('壶', '壺'),
+    ('处', '處'),
+    ('复', '復'),
+    ('头', '頭'),
+    ('夹', '夾'),
+    ('娄', '婁'),
+    ('孙', '孫'),
+    ('宁', '寧'),
+    ('宝', '寶'),
+    ('审', '審'),
+    ('宫', '宮'),
+    ('宽', '寬'),
+    ('宾', '賓'),
+    ('寻', '尋'),
+    ('寿', '壽'),
+    ('将', '將'),
+    ('尔', '爾'),
+    ('尧', '堯'),
+    ('屿', '嶼'),
+    ('岗', '崗'),
+    ('岚', '嵐'),
+    ('岛', '島'),
+    ('岭', '嶺'),
+    ('峄', '嶧'),
+    ('峡', '峽'),
+    ('崂', '嶗'),
+    ('崃', '崍'),
+    ('巩', '鞏'),
+    ('师', '師'),
+    ('广', '廣'),
+    ('庄', '莊'),
+    ('庆', '慶'),
+    ('庐', '廬'),
+    ('库', '庫'),
+    ('应', '應'),
+    ('开', '開'),
+    ('张', '張'),
+    ('弥', '彌'),
+    ('强', '強'),
+    ('归', '歸'),
+    ('当', '當'),
+    ('彦', '彥'),
+    ('怀', '懷'),
+    ('恒', '恆'),
+    ('户', '戶'),
+    ('扬', '揚'),
+    ('抚', '撫'),
+    ('无', '無'),
+    ('旧', '舊'),
+    ('晋', '晉'),
+    ('晖', '暉'),
+    ('杂', '雜'),
+    ('权', '權'),
+    ('来', '來'),
+    ('杨', '楊'),
+    ('极', '極'),
+    ('枞', '樅'),
+    ('枣', '棗'),
+    ('树', '樹'),
+    ('栖', '棲'),
+    ('栾', '欒'),
+    ('桥', '橋'),
+    ('桦', '樺'),
+    ('梦', '夢'),
+    ('棱', '稜'),
+    ('楼', '樓'),
+    ('横', '橫'),
+    ('毕', '畢'),
+    ('汇', '匯'),
+    ('汉', '漢'),
+    ('汤', '湯'),
+    ('沟', '溝'),
+    ('沧', '滄'),
+    ('泸', '瀘'),
+    ('泽', '澤'),
+    ('泾', '涇'),
+    ('洼', '窪'),
+    ('浈', '湞'),
+    ('济', '濟'),
+    ('浏', '瀏'),
+    ('浑', '渾'),
+    ('浔', '潯'),
+    ('涂', '塗'),
+    ('涞', '淶'),
+    ('涟', '漣'),
+    ('涡', '渦'),
+    ('润', '潤'),
+    ('涧', '澗'),
+    ('渌', '淥'),
+    ('渑', '澠'),
+    ('温', '溫'),
+    ('湾', '灣'),
+    ('溆', '漵'),
+    ('满', '滿'),
+    ('滦', '灤'),
+    ('滨', '濱'),
+    ('滩', '灘'),
+    ('潍', '濰'),
+    ('潜', '潛'),
+    ('澜', '瀾'),
+    ('灯', '燈'),
+    ('灵', '靈'),
+    ('炉', '爐'),
+    ('点', '點'),
+    ('烟', '煙'),
+    ('烦', '煩'),
+    ('爱', '愛'),
+    ('犹', '猶'),
+    ('独', '獨'),
+    ('狮', '獅'),
+    ('猪', '豬'),
+    ('献', '獻'),
+    ('玛', '瑪'),
+    ('环', '環'),
+    ('珲', '琿'),
+    ('琼', '瓊'),
+    ('瑶', '瑤'),
+    ('瓮', '甕'),
+    ('瓯', '甌'),
+    ('电', '電'),
+    ('畴', '疇'),
+    ('盐', '鹽'),
+    ('监', '監'),
+    ('盖', '蓋'),
+    ('盘', '盤'),
+    ('矿', '礦'),
+    ('砀', '碭'),
+    ('砚', '硯'),
+    ('硕', '碩'),
+    ('确', '確'),
+    ('碱', '鹼'),
+    ('礼', '禮'),
+    ('禄', '祿'),
+    ('禅', '禪'),
+    ('离', '離'),
+    ('积', '積'),
+    ('称', '稱'),
+    ('竞', '競'),
+    ('简', '簡'),
+    ('类', '類'),
+    ('红', '紅'),
+    ('纳', '納'),
+    ('细', '細'),
+    ('织', '織'),
+    ('绍', '紹'),
+    ('经', '經'),
+    ('结', '結'),
+    ('绛', '絳'),
+    ('绥', '綏'),
+    ('绩', '績'),
+    ('维', '維'),
+    ('绵', '綿'),
+    ('绿', '綠'),
+    ('缙', '縉'),
+    ('罗', '羅'),
+    ('聂', '聶'),
+    ('联', '聯'),
+    ('肃', '肅'),
+    ('胜', '勝'),
+    ('胶', '膠'),
+    ('脱', '脫'),
+    ('腊', '臘'),
+    ('腾', '騰'),
+    ('舆', '輿'),
+    ('节', '節'),
+    ('芗', '薌'),
+    ('芜', '蕪'),
+    ('芦', '蘆'),
+    ('苍', '蒼'),
+    ('苏', '蘇'),
+    ('荆', '荊'),
+    ('荣', '榮'),
+    ('荥', '滎'),
+    ('荫', '蔭'),
+    ('莱', '萊'),
+    ('莲', '蓮'),
+    ('获', '獲'),
+    ('萝', '蘿'),
+    ('营', '營'),
+    ('萧', '蕭'),
+    ('萨', '薩'),
+    ('蓝', '藍'),
+    ('蓟', '薊'),
+    ('蓥', '鎣'),
+    ('蔺', '藺'),
+    ('蕲', '蘄'),
+    ('蕴', '蘊'),
+    ('蝎', '蠍'),
+    ('观', '觀'),
+    ('觉', '覺'),
+    ('让', '讓'),
+    ('讷', '訥'),
+    ('许', '許'),
+    ('诏', '詔'),
+    ('诸', '諸'),
+    ('诺', '諾'),
+    ('调', '調'),
+    ('谊', '誼'),
+    ('谋', '謀'),
+    ('谟', '謨'),
+    ('谢', '謝'),
+    ('谦', '謙'),
+    ('谯', '譙'),
+    ('谱', '譜'),
+    ('贝', '貝'),
+    ('贞', '貞'),
+    ('贡', '貢'),
+    ('贤', '賢'),
+    ('贵', '貴'),
+    ('费', '費'),
+    ('贺', '賀'),
+    ('贾', '賈'),
+    ('资', '資'),
+    ('赉', '賚'),
+    ('赛', '賽'),
+    ('赞', '贊'),
+    ('赣', '贛'),
+    ('赵', '趙'),
+    ('车', '車'),
+    ('轮', '輪'),
+    ('载', '載'),
+    ('辉', '輝'),
+    ('边', '邊'),
+    ('辽', '遼'),
+    ('达', '達'),
+    ('迁', '遷'),
+    ('迈', '邁'),
+    ('运', '運'),
+    ('进', '進'),
+    ('远', '遠'),
+    ('连', '連'),
+    ('逊', '遜'),
+    ('遥', '遙'),
+    ('邓', '鄧'),
+    ('邮', '郵'),
+    ('邹', '鄒'),
+    ('邺', '鄴'),
+    ('邻', '鄰'),
+    ('郏', '郟'),
+    ('郑', '鄭'),
+    ('郓', '鄆'),
+    ('郧', '鄖'),
+    ('郸', '鄲'),
+    ('钟', '鐘'),
+    ('钢', '鋼'),
+    ('钦', '欽'),
+    ('钱', '錢'),
+    ('铁', '鐵'),
+    ('铅', '鉛'),
+    ('铜', '銅'),
+    ('银', '銀'),
+    ('锋', '鋒'),
+    ('错', '錯'),
+    ('锡', '錫'),
+    ('锦', '錦'),
+    ('镇', '鎮'),
+    ('镜', '鏡'),
+    ('镶', '鑲'),
+    ('长', '長'),
+    ('门', '門'),
+    ('间', '間'),
+    ('闵', '閔'),
+    ('闸', '閘'),
+    ('闻', '聞'),
+    ('闽', '閩'),
+    ('阁', '閣'),
+    ('阆', '閬'),
+    ('阊', '閶'),
+    ('阎', '閻'),
+    ('阳', '陽'),
+    ('阴', '陰'),
+    ('陆', '陸'),
+    ('陇', '隴'),
+    ('陈', '陳'),
+    ('陉', '陘'),
+    ('陕', '陝'),
+    ('随', '隨'),
+    ('静', '靜'),
+    ('韩', '韓'),
+    ('顶', '頂'),
+    ('项', '項'),
+    ('顺', '順'),
+    ('颇', '頗'),
+    ('颍', '潁'),
+    ('额', '額'),
+    ('风', '風'),
+    ('饶', '饒'),
+    ('馆', '館'),
+    ('马', '馬'),
+    ('驻', '駐'),
+    ('驿', '驛'),
+    ('骅', '驊'),
+    ('鱼', '魚'),
+    ('鲁', '魯'),
+    ('鲅', '鮁'),
+    ('鲜', '鮮'),
+    ('鲤', '鯉'),
+    ('鸠', '鳩'),
+    ('鸡', '雞'),
+    ('鸣', '鳴'),
+    ('鸭', '鴨'),
+    ('鹤', '鶴'),
+    ('鹰', '鷹'),
+    ('麦', '麥'),
+    ('黄', '黃'),
+    ('齐', '齊'),
+    ('龙', '龍'),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        assert!(TRADITIONAL.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(Locale::default(), Locale::ZhHans);
+        assert_eq!(Locale::ZhHans.translate("广东").as_deref(), Some("广东"));
+        assert_eq!(Locale::ZhHant.translate("广东").as_deref(), Some("廣東"));
+        assert_eq!(
+            Locale::ZhHant.translate("双鱼座").as_deref(),
+            Some("雙魚座")
+        );
+        assert_eq!(Locale::En.translate("双鱼座").as_deref(), Some("Pisces"));
+        assert_eq!(
+            Locale::En.translate("内蒙古").as_deref(),
+            Some("Inner Mongolia")
+        );
+        assert_eq!(Locale::En.translate("国外").as_deref(), Some("Foreign"));
+        assert_eq!(Locale::En.translate("摩羯座").as_deref(), Some("Capricorn"));
+        assert_eq!(Locale::En.translate("魔羯座").as_deref(), Some("Capricorn"));
+        assert_eq!(Locale::En.translate("鼠").as_deref(), Some("Rat"));
+        assert_eq!(Locale::En.translate("火星"), None);
+    }
+}
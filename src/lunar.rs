@@ -0,0 +1,370 @@
+//! The Chinese lunisolar calendar(农历) between 1900 and 2100
+//!
+//! The conversion is based on a table of the month lengths and the leap months
+//! of the lunar years, starting from the lunar new year of 1900(1900-01-31),
+//! the Beginning of Spring(立春) of each year is tabulated as well.
+
+use crate::date::Date;
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use std::fmt;
+
+/// The first lunar year in the table.
+pub const MIN_YEAR: i32 = 1900;
+/// The last lunar year in the table.
+pub const MAX_YEAR: i32 = 2100;
+
+/// The lunar years from 1900 to 2100, the lowest 4 bits are the leap month(0
+/// for none), the bits 15 to 4 are the lengths of the month 1 to 12 and the bit
+/// 16 is the length of the leap month, 1 for 30 days and 0 for 29 days.
+static LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2,
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0,
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    0x0d520,
+];
+
+/// The days in February of the Beginning of Spring(立春) from 1900 to 2100, in
+/// Beijing time.
+static SPRING_BEGINS: &[u8; 201] = b"\
+    44555455545554455445544554455445544554455445544454\
+    44544454445444544454445444544454444444444444444444\
+    44444444444444444344434443444344434443444344434443\
+    34433443344334433443344334433443334333433343334333\
+    4";
+
+static MONTH_NAMES: [&str; 12] = [
+    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "冬", "腊",
+];
+
+static DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// The days since 1970-01-01 of the lunar new year of 1900.
+fn epoch() -> i64 {
+    Date::new(MIN_YEAR, 1, 31).map_or(0, Date::to_days)
+}
+
+fn info(year: i32) -> Option<u32> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&year) {
+        Some(LUNAR_INFO[(year - MIN_YEAR) as usize])
+    } else {
+        None
+    }
+}
+
+/// Returns the leap month of the lunar year, `None` if there's no leap month
+/// or the year is out of the range.
+pub fn leap_month(year: i32) -> Option<u32> {
+    match info(year)? & 0xf {
+        0 => None,
+        month => Some(month),
+    }
+}
+
+/// Returns the day in February of the Beginning of Spring(立春), the first of
+/// the 24 solar terms, `None` if the year is out of the range.
+pub fn spring_begins(year: i32) -> Option<u32> {
+    info(year)?;
+    Some(u32::from(SPRING_BEGINS[(year - MIN_YEAR) as usize] - b'0'))
+}
+
+/// Returns the days in the month of the lunar year, `None` if the month does
+/// not exist.
+pub fn days_in_month(year: i32, month: u32, leap: bool) -> Option<u32> {
+    let info = info(year)?;
+    if !(1..=12).contains(&month) || (leap && info & 0xf != month) {
+        return None;
+    }
+    Some(month_len(info, month, leap))
+}
+
+fn month_len(info: u32, month: u32, leap: bool) -> u32 {
+    let mask = if leap { 0x10000 } else { 0x10000 >> month };
+    if info & mask != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+/// Returns the days in the lunar year.
+pub fn days_in_year(year: i32) -> Option<u32> {
+    Some(months(year)?.map(|(_, _, days)| days).sum())
+}
+
+/// Returns the months of the lunar year in order, as `(month, leap, days)`.
+fn months(year: i32) -> Option<impl Iterator<Item = (u32, bool, u32)>> {
+    let info = info(year)?;
+    Some((1..=12).flat_map(move |month| {
+        let leap = if info & 0xf == month { 2 } else { 1 };
+        [false, true]
+            .iter()
+            .take(leap)
+            .map(move |&leap| (month, leap, month_len(info, month, leap)))
+    }))
+}
+
+/// A date in the Chinese lunisolar calendar, ordered chronologically.
+///
+/// # Examples
+///
+/// ```
+/// use idcard::lunar::LunarDate;
+///
+/// let date = LunarDate::from_solar(1982, 9, 27).unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (1982, 8, 11));
+/// assert_eq!(date.to_string(), "一九八二年八月十一");
+/// assert_eq!(date.to_solar(), (1982, 9, 27));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LunarDate {
+    year: i32,
+    month: u32,
+    leap: bool,
+    day: u32,
+}
+
+impl LunarDate {
+    /// Creates a lunar date, returns `None` if it doesn't exist in the
+    /// calendar or is out of the range of the table.
+    pub fn new(year: i32, month: u32, day: u32, leap: bool) -> Option<LunarDate> {
+        let days = days_in_month(year, month, leap)?;
+        if (1..=days).contains(&day) {
+            Some(LunarDate {
+                year,
+                month,
+                leap,
+                day,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Converts the Gregorian date to the lunar date, returns `None` if the
+    /// date is invalid or out of the range(1900-01-31 to 2101-01-28).
+    pub fn from_solar(year: i32, month: u32, day: u32) -> Option<LunarDate> {
+        LunarDate::from_date(Date::new(year, month, day)?)
+    }
+
+    /// Converts the Gregorian date to the lunar date.
+    #[cfg(feature = "chrono")]
+    pub fn from_naive_date(date: NaiveDate) -> Option<LunarDate> {
+        LunarDate::from_date(date.into())
+    }
+
+    pub(crate) fn from_date(date: Date) -> Option<LunarDate> {
+        let mut offset = date.to_days() - epoch();
+        if offset < 0 {
+            return None;
+        }
+        for year in MIN_YEAR..=MAX_YEAR {
+            for (month, leap, days) in months(year)? {
+                if offset < i64::from(days) {
+                    return Some(LunarDate {
+                        year,
+                        month,
+                        leap,
+                        day: offset as u32 + 1,
+                    });
+                }
+                offset -= i64::from(days);
+            }
+        }
+        None
+    }
+
+    /// Converts the lunar date to the Gregorian date, as `(year, month, day)`.
+    pub fn to_solar(self) -> (i32, u32, u32) {
+        let date = self.to_date();
+        (date.year(), date.month(), date.day())
+    }
+
+    /// Converts the lunar date to the Gregorian date.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(self) -> NaiveDate {
+        self.to_date().into()
+    }
+
+    pub(crate) fn to_date(self) -> Date {
+        let mut offset = epoch() + i64::from(self.day) - 1;
+        offset += (MIN_YEAR..self.year)
+            .filter_map(days_in_year)
+            .map(i64::from)
+            .sum::<i64>();
+        for (month, leap, days) in months(self.year).into_iter().flatten() {
+            if (month, leap) == (self.month, self.leap) {
+                break;
+            }
+            offset += i64::from(days);
+        }
+        Date::from_days(offset)
+    }
+
+    /// Returns the lunar year, note that it starts from the lunar new year
+    /// rather than January 1st.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day in the month, from 1 to 30.
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Checks if the month is a leap month(闰月).
+    pub fn is_leap_month(self) -> bool {
+        self.leap
+    }
+
+    /// Returns the name of the month, e.g. `正月` or `闰四月`.
+    pub fn month_name(self) -> String {
+        let name = MONTH_NAMES[self.month as usize - 1];
+        if self.leap {
+            format!("闰{}月", name)
+        } else {
+            format!("{}月", name)
+        }
+    }
+
+    /// Returns the name of the day, e.g. `初一`, `十五` or `廿三`.
+    pub fn day_name(self) -> String {
+        let digit = DIGITS[(self.day % 10) as usize];
+        match self.day {
+            10 => "初十".to_owned(),
+            20 => "二十".to_owned(),
+            30 => "三十".to_owned(),
+            1..=9 => format!("初{}", digit),
+            11..=19 => format!("十{}", digit),
+            _ => format!("廿{}", digit),
+        }
+    }
+}
+
+/// Formats the date in Chinese, e.g. `一九八二年八月十一`.
+impl fmt::Display for LunarDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let year: String = self
+            .year
+            .to_string()
+            .bytes()
+            .map(|b| DIGITS[(b - b'0') as usize])
+            .collect();
+        write!(f, "{}年{}{}", year, self.month_name(), self.day_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        assert_eq!(leap_month(2020), Some(4));
+        assert_eq!(leap_month(2021), None);
+        assert_eq!(leap_month(2033), Some(11));
+        assert_eq!(days_in_month(2020, 4, true), Some(29));
+        assert_eq!(days_in_month(2021, 4, true), None);
+        assert_eq!(days_in_month(2021, 13, false), None);
+        assert_eq!(days_in_year(2020), Some(384));
+        assert_eq!(days_in_year(2021), Some(354));
+        assert_eq!(days_in_year(2101), None);
+        assert_eq!(spring_begins(2021), Some(3));
+        assert_eq!(spring_begins(2024), Some(4));
+        assert_eq!(spring_begins(1980), Some(5));
+        assert_eq!(spring_begins(1899), None);
+    }
+
+    #[test]
+    fn test_from_solar() {
+        let date = |y, m, d, leap| LunarDate::new(y, m, d, leap).unwrap();
+        assert_eq!(LunarDate::from_solar(1900, 1, 30), None);
+        assert_eq!(
+            LunarDate::from_solar(1900, 1, 31),
+            Some(date(1900, 1, 1, false))
+        );
+        assert_eq!(
+            LunarDate::from_solar(1982, 1, 24),
+            Some(date(1981, 12, 30, false))
+        );
+        assert_eq!(
+            LunarDate::from_solar(1982, 1, 25),
+            Some(date(1982, 1, 1, false))
+        );
+        assert_eq!(
+            LunarDate::from_solar(2000, 2, 5),
+            Some(date(2000, 1, 1, false))
+        );
+        assert_eq!(
+            LunarDate::from_solar(2020, 5, 23),
+            Some(date(2020, 4, 1, true))
+        );
+        assert_eq!(
+            LunarDate::from_solar(2023, 9, 29),
+            Some(date(2023, 8, 15, false))
+        );
+        assert_eq!(
+            LunarDate::from_solar(2101, 1, 28),
+            Some(date(2100, 12, 29, false))
+        );
+        assert_eq!(LunarDate::from_solar(2101, 1, 29), None);
+        assert_eq!(LunarDate::from_solar(2021, 2, 29), None);
+        assert_eq!(LunarDate::new(2020, 4, 30, true), None);
+    }
+
+    #[test]
+    fn test_to_solar() {
+        let first = Date::new(1900, 1, 31).unwrap().to_days();
+        let last = Date::new(2101, 1, 28).unwrap().to_days();
+        let mut prev = None;
+        for days in first..=last {
+            let date = Date::from_days(days);
+            let lunar = LunarDate::from_date(date).unwrap();
+            assert_eq!(lunar.to_date(), date);
+            assert!(prev < Some(lunar));
+            prev = Some(lunar);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let date = LunarDate::new(2020, 4, 1, true).unwrap();
+        assert_eq!(date.to_string(), "二〇二〇年闰四月初一");
+        let date = LunarDate::new(2023, 12, 23, false).unwrap();
+        assert_eq!(date.to_string(), "二〇二三年腊月廿三");
+        assert_eq!(
+            LunarDate::new(2023, 11, 10, false).unwrap().day_name(),
+            "初十"
+        );
+        assert_eq!(
+            LunarDate::new(2023, 11, 20, false).unwrap().day_name(),
+            "二十"
+        );
+        assert_eq!(
+            LunarDate::new(2023, 11, 29, false).unwrap().month_name(),
+            "冬月"
+        );
+    }
+}
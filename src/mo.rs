@@ -1,22 +1,209 @@
 //! Utilities for Macau Identity Card
 
-use regex::Regex;
+use crate::Error;
+#[cfg(feature = "fake")]
+use rand::seq::SliceRandom;
+#[cfg(feature = "fake")]
+use rand::{thread_rng, Rng};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
-lazy_static! {
-    static ref PATTERN: Regex = Regex::new(r"^[1|5|7][0-9]{6}\(?[0-9A-Z]\)?$").unwrap();
-    static ref REMOVAL_PATTERN: Regex = Regex::new(r"[\(|\)]").unwrap();
+/// Validates the format of the number. The check character isn't verified,
+/// as its algorithm isn't published by the Identification Services
+/// Bureau(DSI), so there is no strict validation for Macau.
+pub fn validate(number: &str) -> bool {
+    let number: String = number.chars().filter(|c| !matches!(c, '(' | ')')).collect();
+    let bytes = number.trim().as_bytes();
+    bytes.len() == 8
+        && matches!(bytes[0], b'1' | b'5' | b'7')
+        && bytes[1..7].iter().all(u8::is_ascii_digit)
+        && (bytes[7].is_ascii_digit() || bytes[7].is_ascii_alphabetic())
 }
 
-/// Validates the number.
-pub fn validate(number: &str) -> bool {
-    let number = REMOVAL_PATTERN
-        .replace_all(number, "")
-        .trim()
-        .to_ascii_uppercase();
-    if number.len() == 8 && PATTERN.is_match(&number) {
-        true
-    } else {
-        false
+/// Generates a random well-formed number, e.g. `1123456(3)`. The algorithm of
+/// the check character is not published, so it's a random digit as well.
+#[cfg(feature = "fake")]
+pub fn fake() -> String {
+    let holder_type = *[
+        HolderType::PermanentLocal,
+        HolderType::Permanent,
+        HolderType::NonPermanent,
+    ]
+    .choose(&mut thread_rng())
+    .unwrap();
+    fake_with(holder_type)
+}
+
+/// Generates a random well-formed number of the given category of the holder,
+/// see `fake`.
+#[cfg(feature = "fake")]
+pub fn fake_with(holder_type: HolderType) -> String {
+    let mut rng = thread_rng();
+    let mut body = String::with_capacity(7);
+    body.push(holder_type.digit());
+    for _ in 0..6 {
+        body.push(char::from(b'0' + rng.gen_range(0..10)));
+    }
+    let check = char::from(b'0' + rng.gen_range(0..10));
+    format!("{}({})", body, check)
+}
+
+/// The category of the holder, indicated by the first digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolderType {
+    /// `1`, a permanent resident who was born in Macau or held the identity
+    /// card issued under the Portuguese administration.
+    PermanentLocal,
+    /// `5`, a permanent resident, generally born outside Macau.
+    Permanent,
+    /// `7`, a non-permanent resident.
+    NonPermanent,
+}
+
+impl HolderType {
+    /// Returns the first digit of the numbers of the holders.
+    pub fn digit(self) -> char {
+        match self {
+            HolderType::PermanentLocal => '1',
+            HolderType::Permanent => '5',
+            HolderType::NonPermanent => '7',
+        }
+    }
+
+    /// Checks if the holder is a permanent resident.
+    pub fn is_permanent(self) -> bool {
+        self != HolderType::NonPermanent
+    }
+
+    fn from_digit(digit: char) -> Option<HolderType> {
+        match digit {
+            '1' => Some(HolderType::PermanentLocal),
+            '5' => Some(HolderType::Permanent),
+            '7' => Some(HolderType::NonPermanent),
+            _ => None,
+        }
+    }
+}
+
+/// An object representation of the Macau ID, e.g. `1123456(0)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity {
+    number: String,
+    valid: bool,
+}
+
+impl Identity {
+    /// Creates an identity object from the given number, the number is
+    /// normalized by `crate::normalize` first, and the parentheses around the
+    /// check character are optional.
+    pub fn new(number: &str) -> Self {
+        let number = crate::normalize(number);
+        if !validate(&number) {
+            return Identity {
+                number,
+                valid: false,
+            };
+        }
+        let body: String = number
+            .chars()
+            .filter(|&ch| ch != '(' && ch != ')')
+            .collect();
+        let (body, check) = body.split_at(7);
+        Identity {
+            number: format!("{}({})", body, check),
+            valid: true,
+        }
+    }
+
+    /// Creates an identity object from the given number, returns an error if
+    /// the number is invalid.
+    pub fn try_new(number: &str) -> Result<Self, Error> {
+        let id = Identity::new(number);
+        if id.is_valid() {
+            Ok(id)
+        } else {
+            Err(Error::InvalidNumber)
+        }
+    }
+
+    /// Returns the number, in the form of `1123456(0)` if it's valid.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// Returns the category of the holder.
+    pub fn holder_type(&self) -> Option<HolderType> {
+        if !self.is_valid() {
+            return None;
+        }
+        HolderType::from_digit(self.number.chars().next()?)
+    }
+
+    /// Returns the 7 digits before the check character.
+    pub fn body(&self) -> Option<&str> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(&self.number[0..7])
+    }
+
+    /// Returns the check character.
+    pub fn check_char(&self) -> Option<char> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.number[8..].chars().next()
+    }
+
+    /// Checks if the number is valid, the check character is not verified since
+    /// its algorithm is not published.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.number())
+    }
+}
+
+impl FromStr for Identity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Identity::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for Identity {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Identity::try_new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Identity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.number())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Identity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let number = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        Identity::try_new(&number)
+            .map_err(|e| serde::de::Error::custom(format!("invalid Macau ID {:?}: {}", number, e)))
     }
 }
 
@@ -24,6 +211,54 @@ pub fn validate(number: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_identity() {
+        let id = Identity::new("5215299a");
+        assert!(id.is_valid());
+        assert_eq!(id.number(), "5215299(A)");
+        assert_eq!(id.to_string(), "5215299(A)");
+        assert_eq!(id.holder_type(), Some(HolderType::Permanent));
+        assert_eq!(id.body(), Some("5215299"));
+        assert_eq!(id.check_char(), Some('A'));
+        assert_eq!(id, "5215299(A)".parse().unwrap());
+
+        let id = Identity::new("7431243(3)");
+        assert_eq!(id.holder_type(), Some(HolderType::NonPermanent));
+        assert!(!id.holder_type().unwrap().is_permanent());
+        assert_eq!(
+            Identity::new("1123456(0)").holder_type(),
+            Some(HolderType::PermanentLocal)
+        );
+
+        let id = Identity::new("2000148(3)");
+        assert!(!id.is_valid());
+        assert_eq!(id.holder_type(), None);
+        assert!(Identity::try_new("2000148(3)").is_err());
+    }
+
+    #[cfg(feature = "fake")]
+    #[test]
+    fn test_fake() {
+        for _ in 0..100 {
+            let number = fake();
+            assert!(validate(&number));
+            assert_eq!(Identity::new(&number).number(), number);
+        }
+        let id = Identity::new(&fake_with(HolderType::NonPermanent));
+        assert!(id.is_valid());
+        assert_eq!(id.holder_type(), Some(HolderType::NonPermanent));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let id = Identity::new("1123456(0)");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"1123456(0)\"");
+        let back: Identity = serde_json::from_str("\"11234560\"").unwrap();
+        assert_eq!(back, id);
+        assert!(serde_json::from_str::<Identity>("\"2000148(3)\"").is_err());
+    }
+
     #[test]
     fn test_validate() {
         assert_eq!(validate("1123456(A)"), true);
@@ -31,5 +266,8 @@ mod tests {
         assert_eq!(validate("5631279(0)"), true);
         assert_eq!(validate("2000148(3)"), false);
         assert_eq!(validate("5215299A"), true);
+        assert!(!validate("1|123456(3)"));
+        assert!(!validate("1123456|3"));
+        assert!(!Identity::new("1|123456(3)").is_valid());
     }
 }
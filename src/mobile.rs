@@ -0,0 +1,185 @@
+//! Kotlin and Swift bindings via UniFFI
+//!
+//! Build the `cdylib` with `--features uniffi`, then generate the sources from
+//! it, e.g.
+//!
+//! ```sh
+//! cargo rustc --release --features uniffi --crate-type cdylib
+//! cargo run --features uniffi-bindgen --bin uniffi-bindgen generate \
+//!     --library target/release/libidcard.so --language kotlin --out-dir out
+//! ```
+//!
+//! The `Identity` object, `validate`, `upgrade` and `fake` are exported, and
+//! the errors are thrown as `Error` with the message of `crate::Error`.
+
+use crate::{fake as fakes, Error, Gender};
+use std::sync::Arc;
+
+/// An object representation of the Chinese ID, see `crate::Identity`.
+#[derive(Debug, uniffi::Object)]
+pub struct Identity(crate::Identity);
+
+#[uniffi::export]
+impl Identity {
+    /// Parses the 15/18-digit number, the 15-digit one is upgraded.
+    #[uniffi::constructor]
+    pub fn new(number: String) -> Arc<Self> {
+        Arc::new(Identity(crate::Identity::new(&number)))
+    }
+
+    pub fn number(&self) -> String {
+        self.0.number().to_owned()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    pub fn gender(&self) -> Option<Gender> {
+        self.0.gender()
+    }
+
+    pub fn age(&self) -> Option<u32> {
+        self.0.age()
+    }
+
+    /// Returns the date of birth, e.g. `1982-09-27`.
+    pub fn birth_date(&self) -> Option<String> {
+        self.0.birth_date()
+    }
+
+    pub fn province(&self) -> Option<String> {
+        self.0.province().map(str::to_owned)
+    }
+
+    pub fn region(&self) -> Option<String> {
+        self.0.region().map(str::to_owned)
+    }
+
+    pub fn region_code(&self) -> Option<String> {
+        self.0.region_code().map(str::to_owned)
+    }
+
+    /// Returns the masked number for desensitization.
+    pub fn mask(&self, style: MaskStyle) -> String {
+        self.0.mask(style.into())
+    }
+
+    /// Returns the detailed information as a JSON string, `None` if the number
+    /// is invalid.
+    pub fn to_json_string(&self) -> Option<String> {
+        self.0.to_json_string()
+    }
+}
+
+/// The style used to mask an ID number, see `crate::MaskStyle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MaskStyle {
+    KeepFirst6Last4,
+    KeepBirthYearOnly,
+    Range { start: u32, end: u32 },
+}
+
+impl From<MaskStyle> for crate::MaskStyle {
+    fn from(style: MaskStyle) -> Self {
+        match style {
+            MaskStyle::KeepFirst6Last4 => crate::MaskStyle::KeepFirst6Last4,
+            MaskStyle::KeepBirthYearOnly => crate::MaskStyle::KeepBirthYearOnly,
+            MaskStyle::Range { start, end } => crate::MaskStyle::Range {
+                start: start as usize,
+                end: end as usize,
+            },
+        }
+    }
+}
+
+/// The options of `fake`, see `crate::fake::FakeOptions`.
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct FakeOptions {
+    #[uniffi(default = None)]
+    pub region: Option<String>,
+    #[uniffi(default = None)]
+    pub gender: Option<Gender>,
+    #[uniffi(default = None)]
+    pub min_year: Option<u32>,
+    #[uniffi(default = None)]
+    pub max_year: Option<u32>,
+}
+
+impl From<FakeOptions> for fakes::FakeOptions {
+    fn from(options: FakeOptions) -> Self {
+        let mut result = fakes::FakeOptions::new();
+        if let Some(region) = &options.region {
+            result = result.region(region);
+        }
+        result = match options.gender {
+            Some(Gender::Male) => result.male(),
+            Some(Gender::Female) => result.female(),
+            None => result,
+        };
+        if let Some(year) = options.min_year {
+            result = result.min_year(year);
+        }
+        if let Some(year) = options.max_year {
+            result = result.max_year(year);
+        }
+        result
+    }
+}
+
+/// Checks if the 15/18-digit number is valid.
+#[uniffi::export]
+pub fn validate(number: String) -> bool {
+    crate::validate(&number)
+}
+
+/// Upgrades the number from 15-digit to 18-digit.
+#[uniffi::export]
+pub fn upgrade(number: String) -> Result<String, Error> {
+    crate::upgrade(&number)
+}
+
+/// Generates a fake number using the given options.
+#[uniffi::export]
+pub fn fake(options: FakeOptions) -> Result<String, Error> {
+    fakes::rand_with(&options.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mobile() {
+        let id = Identity::new("632123820927051".to_owned());
+        assert!(id.is_valid());
+        assert_eq!(id.number(), "632123198209270518");
+        assert_eq!(id.gender(), Some(Gender::Male));
+        assert_eq!(id.birth_date().as_deref(), Some("1982-09-27"));
+        assert_eq!(id.mask(MaskStyle::KeepFirst6Last4), "632123********0518");
+        assert_eq!(
+            id.mask(MaskStyle::Range { start: 0, end: 6 }),
+            "******198209270518"
+        );
+
+        assert!(validate("632123198209270518".to_owned()));
+        assert_eq!(
+            upgrade("310112850409522".to_owned()).unwrap(),
+            "310112198504095227"
+        );
+        let number = fake(FakeOptions {
+            region: Some("3301".to_owned()),
+            gender: Some(Gender::Female),
+            ..Default::default()
+        })
+        .unwrap();
+        let id = Identity::new(number);
+        assert!(id.number().starts_with("3301"));
+        assert_eq!(id.gender(), Some(Gender::Female));
+        assert!(fake(FakeOptions {
+            region: Some("00".to_owned()),
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
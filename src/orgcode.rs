@@ -0,0 +1,76 @@
+//! Utilities for Organization Code(组织机构代码, GB 11714)
+//!
+//! The code consists of 8 digits or uppercase letters and a check character,
+//! often written with a hyphen before the check character, e.g. `71526726-X`.
+//! It's embedded in the Unified Social Credit Code, see `uscc`.
+
+use crate::normalize;
+
+/// The weights of the first 8 characters in the check character.
+static WEIGHTS: [u32; 8] = [3, 7, 9, 10, 5, 8, 4, 2];
+
+/// Validates the code, with or without the hyphen.
+pub fn validate(code: &str) -> bool {
+    let code = normalize(code);
+    code.len() == 9 && code.is_char_boundary(8) && check_char(&code[0..8]) == code.chars().nth(8)
+}
+
+/// Computes the check character of the first 8 characters, e.g. `71526726`
+/// gives `X`.
+pub fn check_char(first_eight: &str) -> Option<char> {
+    if first_eight.len() != 8 {
+        return None;
+    }
+    let mut sum = 0;
+    for (b, w) in first_eight.bytes().zip(WEIGHTS.iter()) {
+        let value = match b.to_ascii_uppercase() {
+            b @ b'0'..=b'9' => u32::from(b - b'0'),
+            b @ b'A'..=b'Z' => u32::from(b - b'A') + 10,
+            _ => return None,
+        };
+        sum += value * w;
+    }
+    match 11 - sum % 11 {
+        10 => Some('X'),
+        11 => Some('0'),
+        n => std::char::from_digit(n, 10),
+    }
+}
+
+/// Formats a valid code with the hyphen, e.g. `71526726-X`.
+pub fn format(code: &str) -> Option<String> {
+    if !validate(code) {
+        return None;
+    }
+    let code = normalize(code);
+    Some(format!("{}-{}", &code[0..8], &code[8..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("71526726-X"));
+        assert!(validate("71526726x"));
+        assert!(validate("M000100Y4"));
+        assert!(validate("80210043-3"));
+        assert!(!validate("71526726-9"));
+        assert!(!validate("7152672-X"));
+        assert!(!validate("7152672@-X"));
+    }
+
+    #[test]
+    fn test_check_char() {
+        assert_eq!(check_char("71526726"), Some('X'));
+        assert_eq!(check_char("D2143612"), Some('3'));
+        assert_eq!(check_char("7152672"), None);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format("71526726X").as_deref(), Some("71526726-X"));
+        assert_eq!(format("715267269"), None);
+    }
+}
@@ -0,0 +1,77 @@
+//! Utilities for the passport of the People's Republic of China
+//!
+//! The number is 9 characters:
+//!
+//! - the ordinary passport: `E` followed by 8 digits, e.g. `E12345678`, or by
+//!   a letter from `A` to `J`(except `I`) and 7 digits since the former ran
+//!   out, e.g. `EA1234567`, and the legacy one is `G` followed by 8 digits
+//! - the public affairs ordinary passport: `PE` followed by 7 digits
+//! - the service passport: `SE` followed by 7 digits
+//! - the diplomatic passport: `DE` followed by 7 digits
+
+use crate::normalize;
+
+/// The kinds of the passports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// 普通护照
+    Ordinary,
+    /// 公务普通护照
+    PublicAffairs,
+    /// 公务护照
+    Service,
+    /// 外交护照
+    Diplomatic,
+}
+
+/// Validates the number of any kind.
+pub fn validate(number: &str) -> bool {
+    kind(number).is_some()
+}
+
+/// Returns the kind of a valid number.
+pub fn kind(number: &str) -> Option<Kind> {
+    let number = normalize(number);
+    let bytes = number.as_bytes();
+    if bytes.len() != 9 || !bytes[2..].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    match (bytes[0], bytes[1]) {
+        (b'E', b'0'..=b'9') | (b'G', b'0'..=b'9') => Some(Kind::Ordinary),
+        (b'E', b'A'..=b'J') if bytes[1] != b'I' => Some(Kind::Ordinary),
+        (b'P', b'E') => Some(Kind::PublicAffairs),
+        (b'S', b'E') => Some(Kind::Service),
+        (b'D', b'E') => Some(Kind::Diplomatic),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("E12345678"));
+        assert!(validate("ej1234567"));
+        assert!(validate("G12345678"));
+        assert!(validate("DE1234567"));
+        assert!(!validate("EI1234567"));
+        assert!(!validate("EK1234567"));
+        assert!(!validate("EO1234567"));
+        assert!(!validate("E1234567"));
+        assert!(!validate("E123456789"));
+        assert!(!validate("DA1234567"));
+        assert!(!validate("GA1234567"));
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(kind("E12345678"), Some(Kind::Ordinary));
+        assert_eq!(kind("EA1234567"), Some(Kind::Ordinary));
+        assert_eq!(kind("PE1234567"), Some(Kind::PublicAffairs));
+        assert_eq!(kind("SE1234567"), Some(Kind::Service));
+        assert_eq!(kind("DE1234567"), Some(Kind::Diplomatic));
+        assert_eq!(kind("XE1234567"), None);
+    }
+}
@@ -0,0 +1,246 @@
+//! The provincial-level divisions.
+
+use std::fmt;
+
+/// The kinds of the provincial-level divisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProvinceKind {
+    /// 省
+    Province,
+    /// 直辖市
+    Municipality,
+    /// 自治区
+    AutonomousRegion,
+    /// 特别行政区
+    SpecialAdministrativeRegion,
+}
+
+use ProvinceKind::*;
+
+/// The provincial-level divisions, in the order of their codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Province {
+    /// 北京
+    Beijing,
+    /// 天津
+    Tianjin,
+    /// 河北
+    Hebei,
+    /// 山西
+    Shanxi,
+    /// 内蒙古
+    InnerMongolia,
+    /// 辽宁
+    Liaoning,
+    /// 吉林
+    Jilin,
+    /// 黑龙江
+    Heilongjiang,
+    /// 上海
+    Shanghai,
+    /// 江苏
+    Jiangsu,
+    /// 浙江
+    Zhejiang,
+    /// 安徽
+    Anhui,
+    /// 福建
+    Fujian,
+    /// 江西
+    Jiangxi,
+    /// 山东
+    Shandong,
+    /// 河南
+    Henan,
+    /// 湖北
+    Hubei,
+    /// 湖南
+    Hunan,
+    /// 广东
+    Guangdong,
+    /// 广西
+    Guangxi,
+    /// 海南
+    Hainan,
+    /// 重庆
+    Chongqing,
+    /// 四川
+    Sichuan,
+    /// 贵州
+    Guizhou,
+    /// 云南
+    Yunnan,
+    /// 西藏
+    Tibet,
+    /// 陕西
+    Shaanxi,
+    /// 甘肃
+    Gansu,
+    /// 青海
+    Qinghai,
+    /// 宁夏
+    Ningxia,
+    /// 新疆
+    Xinjiang,
+    /// 台湾
+    Taiwan,
+    /// 香港
+    HongKong,
+    /// 澳门
+    Macao,
+}
+
+/// The code, name, English name, abbreviation and kind of each province, in
+/// the order of the variants.
+static PROVINCES: [(&str, &str, &str, char, ProvinceKind); 34] = [
+    ("11", "北京", "Beijing", '京', Municipality),
+    ("12", "天津", "Tianjin", '津', Municipality),
+    ("13", "河北", "Hebei", '冀', Province),
+    ("14", "山西", "Shanxi", '晋', Province),
+    ("15", "内蒙古", "Inner Mongolia", '蒙', AutonomousRegion),
+    ("21", "辽宁", "Liaoning", '辽', Province),
+    ("22", "吉林", "Jilin", '吉', Province),
+    ("23", "黑龙江", "Heilongjiang", '黑', Province),
+    ("31", "上海", "Shanghai", '沪', Municipality),
+    ("32", "江苏", "Jiangsu", '苏', Province),
+    ("33", "浙江", "Zhejiang", '浙', Province),
+    ("34", "安徽", "Anhui", '皖', Province),
+    ("35", "福建", "Fujian", '闽', Province),
+    ("36", "江西", "Jiangxi", '赣', Province),
+    ("37", "山东", "Shandong", '鲁', Province),
+    ("41", "河南", "Henan", '豫', Province),
+    ("42", "湖北", "Hubei", '鄂', Province),
+    ("43", "湖南", "Hunan", '湘', Province),
+    ("44", "广东", "Guangdong", '粤', Province),
+    ("45", "广西", "Guangxi", '桂', AutonomousRegion),
+    ("46", "海南", "Hainan", '琼', Province),
+    ("50", "重庆", "Chongqing", '渝', Municipality),
+    ("51", "四川", "Sichuan", '川', Province),
+    ("52", "贵州", "Guizhou", '黔', Province),
+    ("53", "云南", "Yunnan", '滇', Province),
+    ("54", "西藏", "Tibet", '藏', AutonomousRegion),
+    ("61", "陕西", "Shaanxi", '陕', Province),
+    ("62", "甘肃", "Gansu", '甘', Province),
+    ("63", "青海", "Qinghai", '青', Province),
+    ("64", "宁夏", "Ningxia", '宁', AutonomousRegion),
+    ("65", "新疆", "Xinjiang", '新', AutonomousRegion),
+    ("71", "台湾", "Taiwan", '台', Province),
+    ("81", "香港", "Hong Kong", '港', SpecialAdministrativeRegion),
+    ("82", "澳门", "Macao", '澳', SpecialAdministrativeRegion),
+];
+
+impl Province {
+    /// All the provinces, in the order of their codes.
+    pub const ALL: [Province; 34] = [
+        Province::Beijing,
+        Province::Tianjin,
+        Province::Hebei,
+        Province::Shanxi,
+        Province::InnerMongolia,
+        Province::Liaoning,
+        Province::Jilin,
+        Province::Heilongjiang,
+        Province::Shanghai,
+        Province::Jiangsu,
+        Province::Zhejiang,
+        Province::Anhui,
+        Province::Fujian,
+        Province::Jiangxi,
+        Province::Shandong,
+        Province::Henan,
+        Province::Hubei,
+        Province::Hunan,
+        Province::Guangdong,
+        Province::Guangxi,
+        Province::Hainan,
+        Province::Chongqing,
+        Province::Sichuan,
+        Province::Guizhou,
+        Province::Yunnan,
+        Province::Tibet,
+        Province::Shaanxi,
+        Province::Gansu,
+        Province::Qinghai,
+        Province::Ningxia,
+        Province::Xinjiang,
+        Province::Taiwan,
+        Province::HongKong,
+        Province::Macao,
+    ];
+
+    /// Returns the province by the first 2 digits of a region code, `83`(used
+    /// by the residence permits of the Taiwan residents) gives `Taiwan`.
+    pub fn from_code(code: &str) -> Option<Province> {
+        let code = if code == "83" { "71" } else { code };
+        PROVINCES
+            .binary_search_by_key(&code, |p| p.0)
+            .ok()
+            .map(|i| Province::ALL[i])
+    }
+
+    /// Returns the 2-digit code, e.g. `11`.
+    pub fn code(self) -> &'static str {
+        PROVINCES[self as usize].0
+    }
+
+    /// Returns the short name, e.g. `北京`.
+    pub fn name(self) -> &'static str {
+        PROVINCES[self as usize].1
+    }
+
+    /// Returns the English name, e.g. `Beijing`.
+    pub fn name_en(self) -> &'static str {
+        PROVINCES[self as usize].2
+    }
+
+    /// Returns the one-character abbreviation, e.g. `京`.
+    pub fn abbreviation(self) -> char {
+        PROVINCES[self as usize].3
+    }
+
+    /// Returns the kind of the division.
+    pub fn kind(self) -> ProvinceKind {
+        PROVINCES[self as usize].4
+    }
+}
+
+impl fmt::Display for Province {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        assert!(PROVINCES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(Province::ALL
+            .iter()
+            .enumerate()
+            .all(|(i, &p)| p as usize == i));
+    }
+
+    #[test]
+    fn test_province() {
+        let p = Province::from_code("31").unwrap();
+        assert_eq!(p, Province::Shanghai);
+        assert_eq!(p.code(), "31");
+        assert_eq!(p.name(), "上海");
+        assert_eq!(p.name_en(), "Shanghai");
+        assert_eq!(p.abbreviation(), '沪');
+        assert_eq!(p.kind(), ProvinceKind::Municipality);
+        assert_eq!(p.to_string(), "上海");
+        assert_eq!(Province::from_code("44").unwrap().abbreviation(), '粤');
+        assert_eq!(Province::Xinjiang.kind(), ProvinceKind::AutonomousRegion);
+        assert_eq!(
+            Province::Macao.kind(),
+            ProvinceKind::SpecialAdministrativeRegion
+        );
+        assert_eq!(Province::from_code("83"), Some(Province::Taiwan));
+        assert_eq!(Province::from_code("91"), None);
+        assert_eq!(Province::from_code("10"), None);
+    }
+}
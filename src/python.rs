@@ -0,0 +1,256 @@
+//! Python bindings via PyO3
+//!
+//! Build the extension module with maturin(see `pyproject.toml`), e.g.
+//! `maturin develop --release`, then:
+//!
+//! ```python
+//! import idcard
+//!
+//! idcard.validate("632123198209270518")  # True
+//! idcard.upgrade("310112850409522")  # "310112198504095227"
+//! idcard.fake(region="3301", gender="female", min_year=1990, max_year=2000)
+//! idcard.fake_many(100, region="3301")
+//!
+//! id = idcard.Identity("632123820927051")
+//! id.is_valid(), id.gender, id.birth_date, id.region_code
+//! id.mask("keep-birth-year-only")  # "******1982********"
+//! ```
+//!
+//! The errors are raised as `ValueError`.
+
+use crate::fake::{self as fakes, FakeOptions};
+use crate::{Error, Gender, MaskStyle};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn value_error(err: Error) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn gender_name(gender: Gender) -> &'static str {
+    match gender {
+        Gender::Male => "Male",
+        Gender::Female => "Female",
+    }
+}
+
+/// An object representation of the Chinese ID, see `crate::Identity`.
+#[pyclass(name = "Identity", module = "idcard", frozen)]
+pub struct PyIdentity(crate::Identity);
+
+#[pymethods]
+impl PyIdentity {
+    #[new]
+    fn new(number: &str) -> Self {
+        PyIdentity(crate::Identity::new(number))
+    }
+
+    #[getter]
+    fn number(&self) -> &str {
+        self.0.number()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// `"Male"` or `"Female"`, `None` if the number is invalid.
+    #[getter]
+    fn gender(&self) -> Option<&'static str> {
+        self.0.gender().map(gender_name)
+    }
+
+    #[getter]
+    fn age(&self) -> Option<u32> {
+        self.0.age()
+    }
+
+    /// The date of birth, e.g. `"1982-09-27"`.
+    #[getter]
+    fn birth_date(&self) -> Option<String> {
+        self.0.birth_date()
+    }
+
+    #[getter]
+    fn province(&self) -> Option<String> {
+        self.0.province().map(str::to_owned)
+    }
+
+    #[getter]
+    fn region(&self) -> Option<String> {
+        self.0.region().map(str::to_owned)
+    }
+
+    #[getter]
+    fn region_code(&self) -> Option<&str> {
+        self.0.region_code()
+    }
+
+    /// Returns the masked number, the style is `"keep-first6-last4"`(the
+    /// default) or `"keep-birth-year-only"`.
+    #[pyo3(signature = (style = "keep-first6-last4"))]
+    fn mask(&self, style: &str) -> PyResult<String> {
+        let style = match style {
+            "keep-first6-last4" => MaskStyle::KeepFirst6Last4,
+            "keep-birth-year-only" => MaskStyle::KeepBirthYearOnly,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown mask style {:?}",
+                    style
+                )))
+            }
+        };
+        Ok(self.0.mask(style))
+    }
+
+    /// Returns the detailed information as a JSON string, `None` if the number
+    /// is invalid.
+    fn to_json(&self) -> Option<String> {
+        self.0.to_json_string()
+    }
+
+    fn __str__(&self) -> &str {
+        self.0.number()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Identity({:?})", self.0.number())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Checks if the 15/18-digit number is valid.
+#[pyfunction]
+fn validate(number: &str) -> bool {
+    crate::validate(number)
+}
+
+/// Upgrades the number from 15-digit to 18-digit.
+#[pyfunction]
+fn upgrade(number: &str) -> PyResult<String> {
+    crate::upgrade(number).map_err(value_error)
+}
+
+fn fake_options(
+    region: Option<&str>,
+    gender: Option<&str>,
+    min_year: Option<u32>,
+    max_year: Option<u32>,
+) -> PyResult<FakeOptions> {
+    let mut options = FakeOptions::new();
+    if let Some(region) = region {
+        options = options.region(region);
+    }
+    options = match gender {
+        None => options,
+        Some("male") | Some("m") => options.male(),
+        Some("female") | Some("f") => options.female(),
+        Some(gender) => {
+            return Err(PyValueError::new_err(format!(
+                "unknown gender {:?}",
+                gender
+            )))
+        }
+    };
+    if let Some(year) = min_year {
+        options = options.min_year(year);
+    }
+    if let Some(year) = max_year {
+        options = options.max_year(year);
+    }
+    Ok(options)
+}
+
+/// Generates a fake number, the gender is `"male"` or `"female"`.
+#[pyfunction]
+#[pyo3(signature = (region = None, gender = None, min_year = None, max_year = None))]
+fn fake(
+    region: Option<&str>,
+    gender: Option<&str>,
+    min_year: Option<u32>,
+    max_year: Option<u32>,
+) -> PyResult<String> {
+    let options = fake_options(region, gender, min_year, max_year)?;
+    fakes::rand_with(&options).map_err(value_error)
+}
+
+/// Generates `n` unique fake numbers, see `fake`.
+#[pyfunction]
+#[pyo3(signature = (n, region = None, gender = None, min_year = None, max_year = None))]
+fn fake_many(
+    n: usize,
+    region: Option<&str>,
+    gender: Option<&str>,
+    min_year: Option<u32>,
+    max_year: Option<u32>,
+) -> PyResult<Vec<String>> {
+    let options = fake_options(region, gender, min_year, max_year)?;
+    fakes::rand_many(n, &options).map_err(value_error)
+}
+
+/// The `idcard` Python module.
+#[pymodule]
+fn idcard(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyIdentity>()?;
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
+    m.add_function(wrap_pyfunction!(upgrade, m)?)?;
+    m.add_function(wrap_pyfunction!(fake, m)?)?;
+    m.add_function(wrap_pyfunction!(fake_many, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "idcard").unwrap();
+            idcard(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("idcard", module).unwrap();
+            let code = CString::new(
+                r#"
+assert idcard.validate("632123198209270518")
+assert not idcard.validate("632123198209270519")
+assert idcard.upgrade("310112850409522") == "310112198504095227"
+try:
+    idcard.upgrade("123")
+    assert False
+except ValueError:
+    pass
+
+id = idcard.Identity("632123820927051")
+assert id.is_valid()
+assert id.number == "632123198209270518"
+assert (id.gender, id.birth_date, id.region_code) == ("Male", "1982-09-27", "632123")
+assert id.mask() == "632123********0518"
+assert id.mask("keep-birth-year-only") == "******1982********"
+assert repr(id) == 'Identity("632123198209270518")'
+assert id == idcard.Identity("632123198209270518")
+assert idcard.Identity("123").gender is None
+
+number = idcard.fake(region="3301", gender="female", min_year=1990, max_year=2000)
+assert number.startswith("3301") and idcard.Identity(number).gender == "Female"
+assert len(set(idcard.fake_many(100, region="3301"))) == 100
+"#,
+            )
+            .unwrap();
+            py.run(&code, Some(&globals), None).unwrap();
+        });
+    }
+}
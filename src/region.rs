@@ -1,3257 +1,594 @@
 //! Region query utilities(only includes mainland data)
 
+#[cfg(feature = "fake")]
 use rand::{thread_rng, Rng};
-use std::collections::HashMap;
-
-lazy_static! {
-    static ref REGIONS: HashMap<&'static str, &'static str> = {
-        let mut map = HashMap::new();
-        map.insert("110000", "北京市");
-        map.insert("110101", "北京市东城区");
-        map.insert("110102", "北京市西城区");
-        map.insert("110105", "北京市朝阳区");
-        map.insert("110106", "北京市丰台区");
-        map.insert("110107", "北京市石景山区");
-        map.insert("110108", "北京市海淀区");
-        map.insert("110109", "北京市门头沟区");
-        map.insert("110111", "北京市房山区");
-        map.insert("110112", "北京市通州区");
-        map.insert("110113", "北京市顺义区");
-        map.insert("110114", "北京市昌平区");
-        map.insert("110115", "北京市大兴区");
-        map.insert("110116", "北京市怀柔区");
-        map.insert("110117", "北京市平谷区");
-        map.insert("110118", "北京市密云区");
-        map.insert("110119", "北京市延庆区");
-        map.insert("120000", "天津市");
-        map.insert("120101", "天津市和平区");
-        map.insert("120102", "天津市河东区");
-        map.insert("120103", "天津市河西区");
-        map.insert("120104", "天津市南开区");
-        map.insert("120105", "天津市河北区");
-        map.insert("120106", "天津市红桥区");
-        map.insert("120110", "天津市东丽区");
-        map.insert("120111", "天津市西青区");
-        map.insert("120112", "天津市津南区");
-        map.insert("120113", "天津市北辰区");
-        map.insert("120114", "天津市武清区");
-        map.insert("120115", "天津市宝坻区");
-        map.insert("120116", "天津市滨海新区");
-        map.insert("120117", "天津市宁河区");
-        map.insert("120118", "天津市静海区");
-        map.insert("120119", "天津市蓟州区");
-        map.insert("130000", "河北省");
-        map.insert("130100", "河北省石家庄市");
-        map.insert("130102", "河北省石家庄市长安区");
-        map.insert("130104", "河北省石家庄市桥西区");
-        map.insert("130105", "河北省石家庄市新华区");
-        map.insert("130107", "河北省石家庄市井陉矿区");
-        map.insert("130108", "河北省石家庄市裕华区");
-        map.insert("130109", "河北省石家庄市藁城区");
-        map.insert("130110", "河北省石家庄市鹿泉区");
-        map.insert("130111", "河北省石家庄市栾城区");
-        map.insert("130121", "河北省石家庄市井陉县");
-        map.insert("130123", "河北省石家庄市正定县");
-        map.insert("130125", "河北省石家庄市行唐县");
-        map.insert("130126", "河北省石家庄市灵寿县");
-        map.insert("130127", "河北省石家庄市高邑县");
-        map.insert("130128", "河北省石家庄市深泽县");
-        map.insert("130129", "河北省石家庄市赞皇县");
-        map.insert("130130", "河北省石家庄市无极县");
-        map.insert("130131", "河北省石家庄市平山县");
-        map.insert("130132", "河北省石家庄市元氏县");
-        map.insert("130133", "河北省石家庄市赵县");
-        map.insert("130181", "河北省石家庄市辛集市");
-        map.insert("130183", "河北省石家庄市晋州市");
-        map.insert("130184", "河北省石家庄市新乐市");
-        map.insert("130200", "河北省唐山市");
-        map.insert("130202", "河北省唐山市路南区");
-        map.insert("130203", "河北省唐山市路北区");
-        map.insert("130204", "河北省唐山市古冶区");
-        map.insert("130205", "河北省唐山市开平区");
-        map.insert("130207", "河北省唐山市丰南区");
-        map.insert("130208", "河北省唐山市丰润区");
-        map.insert("130209", "河北省唐山市曹妃甸区");
-        map.insert("130224", "河北省唐山市滦南县");
-        map.insert("130225", "河北省唐山市乐亭县");
-        map.insert("130227", "河北省唐山市迁西县");
-        map.insert("130229", "河北省唐山市玉田县");
-        map.insert("130281", "河北省唐山市遵化市");
-        map.insert("130283", "河北省唐山市迁安市");
-        map.insert("130284", "河北省唐山市滦州市");
-        map.insert("130300", "河北省秦皇岛市");
-        map.insert("130302", "河北省秦皇岛市海港区");
-        map.insert("130303", "河北省秦皇岛市山海关区");
-        map.insert("130304", "河北省秦皇岛市北戴河区");
-        map.insert("130306", "河北省秦皇岛市抚宁区");
-        map.insert("130321", "河北省秦皇岛市青龙满族自治县");
-        map.insert("130322", "河北省秦皇岛市昌黎县");
-        map.insert("130324", "河北省秦皇岛市卢龙县");
-        map.insert("130400", "河北省邯郸市");
-        map.insert("130402", "河北省邯郸市邯山区");
-        map.insert("130403", "河北省邯郸市丛台区");
-        map.insert("130404", "河北省邯郸市复兴区");
-        map.insert("130406", "河北省邯郸市峰峰矿区");
-        map.insert("130407", "河北省邯郸市肥乡区");
-        map.insert("130408", "河北省邯郸市永年区");
-        map.insert("130423", "河北省邯郸市临漳县");
-        map.insert("130424", "河北省邯郸市成安县");
-        map.insert("130425", "河北省邯郸市大名县");
-        map.insert("130426", "河北省邯郸市涉县");
-        map.insert("130427", "河北省邯郸市磁县");
-        map.insert("130430", "河北省邯郸市邱县");
-        map.insert("130431", "河北省邯郸市鸡泽县");
-        map.insert("130432", "河北省邯郸市广平县");
-        map.insert("130433", "河北省邯郸市馆陶县");
-        map.insert("130434", "河北省邯郸市魏县");
-        map.insert("130435", "河北省邯郸市曲周县");
-        map.insert("130481", "河北省邯郸市武安市");
-        map.insert("130500", "河北省邢台市");
-        map.insert("130502", "河北省邢台市襄都区");
-        map.insert("130503", "河北省邢台市信都区");
-        map.insert("130505", "河北省邢台市任泽区");
-        map.insert("130506", "河北省邢台市南和区");
-        map.insert("130522", "河北省邢台市临城县");
-        map.insert("130523", "河北省邢台市内丘县");
-        map.insert("130524", "河北省邢台市柏乡县");
-        map.insert("130525", "河北省邢台市隆尧县");
-        map.insert("130528", "河北省邢台市宁晋县");
-        map.insert("130529", "河北省邢台市巨鹿县");
-        map.insert("130530", "河北省邢台市新河县");
-        map.insert("130531", "河北省邢台市广宗县");
-        map.insert("130532", "河北省邢台市平乡县");
-        map.insert("130533", "河北省邢台市威县");
-        map.insert("130534", "河北省邢台市清河县");
-        map.insert("130535", "河北省邢台市临西县");
-        map.insert("130581", "河北省邢台市南宫市");
-        map.insert("130582", "河北省邢台市沙河市");
-        map.insert("130600", "河北省保定市");
-        map.insert("130602", "河北省保定市竞秀区");
-        map.insert("130606", "河北省保定市莲池区");
-        map.insert("130607", "河北省保定市满城区");
-        map.insert("130608", "河北省保定市清苑区");
-        map.insert("130609", "河北省保定市徐水区");
-        map.insert("130623", "河北省保定市涞水县");
-        map.insert("130624", "河北省保定市阜平县");
-        map.insert("130626", "河北省保定市定兴县");
-        map.insert("130627", "河北省保定市唐县");
-        map.insert("130628", "河北省保定市高阳县");
-        map.insert("130629", "河北省保定市容城县");
-        map.insert("130630", "河北省保定市涞源县");
-        map.insert("130631", "河北省保定市望都县");
-        map.insert("130632", "河北省保定市安新县");
-        map.insert("130633", "河北省保定市易县");
-        map.insert("130634", "河北省保定市曲阳县");
-        map.insert("130635", "河北省保定市蠡县");
-        map.insert("130636", "河北省保定市顺平县");
-        map.insert("130637", "河北省保定市博野县");
-        map.insert("130638", "河北省保定市雄县");
-        map.insert("130681", "河北省保定市涿州市");
-        map.insert("130682", "河北省保定市定州市");
-        map.insert("130683", "河北省保定市安国市");
-        map.insert("130684", "河北省保定市高碑店市");
-        map.insert("130700", "河北省张家口市");
-        map.insert("130702", "河北省张家口市桥东区");
-        map.insert("130703", "河北省张家口市桥西区");
-        map.insert("130705", "河北省张家口市宣化区");
-        map.insert("130706", "河北省张家口市下花园区");
-        map.insert("130708", "河北省张家口市万全区");
-        map.insert("130709", "河北省张家口市崇礼区");
-        map.insert("130722", "河北省张家口市张北县");
-        map.insert("130723", "河北省张家口市康保县");
-        map.insert("130724", "河北省张家口市沽源县");
-        map.insert("130725", "河北省张家口市尚义县");
-        map.insert("130726", "河北省张家口市蔚县");
-        map.insert("130727", "河北省张家口市阳原县");
-        map.insert("130728", "河北省张家口市怀安县");
-        map.insert("130730", "河北省张家口市怀来县");
-        map.insert("130731", "河北省张家口市涿鹿县");
-        map.insert("130732", "河北省张家口市赤城县");
-        map.insert("130800", "河北省承德市");
-        map.insert("130802", "河北省承德市双桥区");
-        map.insert("130803", "河北省承德市双滦区");
-        map.insert("130804", "河北省承德市鹰手营子矿区");
-        map.insert("130821", "河北省承德市承德县");
-        map.insert("130822", "河北省承德市兴隆县");
-        map.insert("130824", "河北省承德市滦平县");
-        map.insert("130825", "河北省承德市隆化县");
-        map.insert("130826", "河北省承德市丰宁满族自治县");
-        map.insert("130827", "河北省承德市宽城满族自治县");
-        map.insert("130828", "河北省承德市围场满族蒙古族自治县");
-        map.insert("130881", "河北省承德市平泉市");
-        map.insert("130900", "河北省沧州市");
-        map.insert("130902", "河北省沧州市新华区");
-        map.insert("130903", "河北省沧州市运河区");
-        map.insert("130921", "河北省沧州市沧县");
-        map.insert("130922", "河北省沧州市青县");
-        map.insert("130923", "河北省沧州市东光县");
-        map.insert("130924", "河北省沧州市海兴县");
-        map.insert("130925", "河北省沧州市盐山县");
-        map.insert("130926", "河北省沧州市肃宁县");
-        map.insert("130927", "河北省沧州市南皮县");
-        map.insert("130928", "河北省沧州市吴桥县");
-        map.insert("130929", "河北省沧州市献县");
-        map.insert("130930", "河北省沧州市孟村回族自治县");
-        map.insert("130981", "河北省沧州市泊头市");
-        map.insert("130982", "河北省沧州市任丘市");
-        map.insert("130983", "河北省沧州市黄骅市");
-        map.insert("130984", "河北省沧州市河间市");
-        map.insert("131000", "河北省廊坊市");
-        map.insert("131002", "河北省廊坊市安次区");
-        map.insert("131003", "河北省廊坊市广阳区");
-        map.insert("131022", "河北省廊坊市固安县");
-        map.insert("131023", "河北省廊坊市永清县");
-        map.insert("131024", "河北省廊坊市香河县");
-        map.insert("131025", "河北省廊坊市大城县");
-        map.insert("131026", "河北省廊坊市文安县");
-        map.insert("131028", "河北省廊坊市大厂回族自治县");
-        map.insert("131081", "河北省廊坊市霸州市");
-        map.insert("131082", "河北省廊坊市三河市");
-        map.insert("131100", "河北省衡水市");
-        map.insert("131102", "河北省衡水市桃城区");
-        map.insert("131103", "河北省衡水市冀州区");
-        map.insert("131121", "河北省衡水市枣强县");
-        map.insert("131122", "河北省衡水市武邑县");
-        map.insert("131123", "河北省衡水市武强县");
-        map.insert("131124", "河北省衡水市饶阳县");
-        map.insert("131125", "河北省衡水市安平县");
-        map.insert("131126", "河北省衡水市故城县");
-        map.insert("131127", "河北省衡水市景县");
-        map.insert("131128", "河北省衡水市阜城县");
-        map.insert("131182", "河北省衡水市深州市");
-        map.insert("140000", "山西省");
-        map.insert("140100", "山西省太原市");
-        map.insert("140105", "山西省太原市小店区");
-        map.insert("140106", "山西省太原市迎泽区");
-        map.insert("140107", "山西省太原市杏花岭区");
-        map.insert("140108", "山西省太原市尖草坪区");
-        map.insert("140109", "山西省太原市万柏林区");
-        map.insert("140110", "山西省太原市晋源区");
-        map.insert("140121", "山西省太原市清徐县");
-        map.insert("140122", "山西省太原市阳曲县");
-        map.insert("140123", "山西省太原市娄烦县");
-        map.insert("140181", "山西省太原市古交市");
-        map.insert("140200", "山西省大同市");
-        map.insert("140212", "山西省大同市新荣区");
-        map.insert("140213", "山西省大同市平城区");
-        map.insert("140214", "山西省大同市云冈区");
-        map.insert("140215", "山西省大同市云州区");
-        map.insert("140221", "山西省大同市阳高县");
-        map.insert("140222", "山西省大同市天镇县");
-        map.insert("140223", "山西省大同市广灵县");
-        map.insert("140224", "山西省大同市灵丘县");
-        map.insert("140225", "山西省大同市浑源县");
-        map.insert("140226", "山西省大同市左云县");
-        map.insert("140300", "山西省阳泉市");
-        map.insert("140302", "山西省阳泉市城区");
-        map.insert("140303", "山西省阳泉市矿区");
-        map.insert("140311", "山西省阳泉市郊区");
-        map.insert("140321", "山西省阳泉市平定县");
-        map.insert("140322", "山西省阳泉市盂县");
-        map.insert("140400", "山西省长治市");
-        map.insert("140403", "山西省长治市潞州区");
-        map.insert("140404", "山西省长治市上党区");
-        map.insert("140405", "山西省长治市屯留区");
-        map.insert("140406", "山西省长治市潞城区");
-        map.insert("140423", "山西省长治市襄垣县");
-        map.insert("140425", "山西省长治市平顺县");
-        map.insert("140426", "山西省长治市黎城县");
-        map.insert("140427", "山西省长治市壶关县");
-        map.insert("140428", "山西省长治市长子县");
-        map.insert("140429", "山西省长治市武乡县");
-        map.insert("140430", "山西省长治市沁县");
-        map.insert("140431", "山西省长治市沁源县");
-        map.insert("140500", "山西省晋城市");
-        map.insert("140502", "山西省晋城市城区");
-        map.insert("140521", "山西省晋城市沁水县");
-        map.insert("140522", "山西省晋城市阳城县");
-        map.insert("140524", "山西省晋城市陵川县");
-        map.insert("140525", "山西省晋城市泽州县");
-        map.insert("140581", "山西省晋城市高平市");
-        map.insert("140600", "山西省朔州市");
-        map.insert("140602", "山西省朔州市朔城区");
-        map.insert("140603", "山西省朔州市平鲁区");
-        map.insert("140621", "山西省朔州市山阴县");
-        map.insert("140622", "山西省朔州市应县");
-        map.insert("140623", "山西省朔州市右玉县");
-        map.insert("140681", "山西省朔州市怀仁市");
-        map.insert("140700", "山西省晋中市");
-        map.insert("140702", "山西省晋中市榆次区");
-        map.insert("140703", "山西省晋中市太谷区");
-        map.insert("140721", "山西省晋中市榆社县");
-        map.insert("140722", "山西省晋中市左权县");
-        map.insert("140723", "山西省晋中市和顺县");
-        map.insert("140724", "山西省晋中市昔阳县");
-        map.insert("140725", "山西省晋中市寿阳县");
-        map.insert("140727", "山西省晋中市祁县");
-        map.insert("140728", "山西省晋中市平遥县");
-        map.insert("140729", "山西省晋中市灵石县");
-        map.insert("140781", "山西省晋中市介休市");
-        map.insert("140800", "山西省运城市");
-        map.insert("140802", "山西省运城市盐湖区");
-        map.insert("140821", "山西省运城市临猗县");
-        map.insert("140822", "山西省运城市万荣县");
-        map.insert("140823", "山西省运城市闻喜县");
-        map.insert("140824", "山西省运城市稷山县");
-        map.insert("140825", "山西省运城市新绛县");
-        map.insert("140826", "山西省运城市绛县");
-        map.insert("140827", "山西省运城市垣曲县");
-        map.insert("140828", "山西省运城市夏县");
-        map.insert("140829", "山西省运城市平陆县");
-        map.insert("140830", "山西省运城市芮城县");
-        map.insert("140881", "山西省运城市永济市");
-        map.insert("140882", "山西省运城市河津市");
-        map.insert("140900", "山西省忻州市");
-        map.insert("140902", "山西省忻州市忻府区");
-        map.insert("140921", "山西省忻州市定襄县");
-        map.insert("140922", "山西省忻州市五台县");
-        map.insert("140923", "山西省忻州市代县");
-        map.insert("140924", "山西省忻州市繁峙县");
-        map.insert("140925", "山西省忻州市宁武县");
-        map.insert("140926", "山西省忻州市静乐县");
-        map.insert("140927", "山西省忻州市神池县");
-        map.insert("140928", "山西省忻州市五寨县");
-        map.insert("140929", "山西省忻州市岢岚县");
-        map.insert("140930", "山西省忻州市河曲县");
-        map.insert("140931", "山西省忻州市保德县");
-        map.insert("140932", "山西省忻州市偏关县");
-        map.insert("140981", "山西省忻州市原平市");
-        map.insert("141000", "山西省临汾市");
-        map.insert("141002", "山西省临汾市尧都区");
-        map.insert("141021", "山西省临汾市曲沃县");
-        map.insert("141022", "山西省临汾市翼城县");
-        map.insert("141023", "山西省临汾市襄汾县");
-        map.insert("141024", "山西省临汾市洪洞县");
-        map.insert("141025", "山西省临汾市古县");
-        map.insert("141026", "山西省临汾市安泽县");
-        map.insert("141027", "山西省临汾市浮山县");
-        map.insert("141028", "山西省临汾市吉县");
-        map.insert("141029", "山西省临汾市乡宁县");
-        map.insert("141030", "山西省临汾市大宁县");
-        map.insert("141031", "山西省临汾市隰县");
-        map.insert("141032", "山西省临汾市永和县");
-        map.insert("141033", "山西省临汾市蒲县");
-        map.insert("141034", "山西省临汾市汾西县");
-        map.insert("141081", "山西省临汾市侯马市");
-        map.insert("141082", "山西省临汾市霍州市");
-        map.insert("141100", "山西省吕梁市");
-        map.insert("141102", "山西省吕梁市离石区");
-        map.insert("141121", "山西省吕梁市文水县");
-        map.insert("141122", "山西省吕梁市交城县");
-        map.insert("141123", "山西省吕梁市兴县");
-        map.insert("141124", "山西省吕梁市临县");
-        map.insert("141125", "山西省吕梁市柳林县");
-        map.insert("141126", "山西省吕梁市石楼县");
-        map.insert("141127", "山西省吕梁市岚县");
-        map.insert("141128", "山西省吕梁市方山县");
-        map.insert("141129", "山西省吕梁市中阳县");
-        map.insert("141130", "山西省吕梁市交口县");
-        map.insert("141181", "山西省吕梁市孝义市");
-        map.insert("141182", "山西省吕梁市汾阳市");
-        map.insert("150000", "内蒙古自治区");
-        map.insert("150100", "内蒙古自治区呼和浩特市");
-        map.insert("150102", "内蒙古自治区呼和浩特市新城区");
-        map.insert("150103", "内蒙古自治区呼和浩特市回民区");
-        map.insert("150104", "内蒙古自治区呼和浩特市玉泉区");
-        map.insert("150105", "内蒙古自治区呼和浩特市赛罕区");
-        map.insert("150121", "内蒙古自治区呼和浩特市土默特左旗");
-        map.insert("150122", "内蒙古自治区呼和浩特市托克托县");
-        map.insert("150123", "内蒙古自治区呼和浩特市和林格尔县");
-        map.insert("150124", "内蒙古自治区呼和浩特市清水河县");
-        map.insert("150125", "内蒙古自治区呼和浩特市武川县");
-        map.insert("150200", "内蒙古自治区包头市");
-        map.insert("150202", "内蒙古自治区包头市东河区");
-        map.insert("150203", "内蒙古自治区包头市昆都仑区");
-        map.insert("150204", "内蒙古自治区包头市青山区");
-        map.insert("150205", "内蒙古自治区包头市石拐区");
-        map.insert("150206", "内蒙古自治区包头市白云鄂博矿区");
-        map.insert("150207", "内蒙古自治区包头市九原区");
-        map.insert("150221", "内蒙古自治区包头市土默特右旗");
-        map.insert("150222", "内蒙古自治区包头市固阳县");
-        map.insert("150223", "内蒙古自治区包头市达尔罕茂明安联合旗");
-        map.insert("150300", "内蒙古自治区乌海市");
-        map.insert("150302", "内蒙古自治区乌海市海勃湾区");
-        map.insert("150303", "内蒙古自治区乌海市海南区");
-        map.insert("150304", "内蒙古自治区乌海市乌达区");
-        map.insert("150400", "内蒙古自治区赤峰市");
-        map.insert("150402", "内蒙古自治区赤峰市红山区");
-        map.insert("150403", "内蒙古自治区赤峰市元宝山区");
-        map.insert("150404", "内蒙古自治区赤峰市松山区");
-        map.insert("150421", "内蒙古自治区赤峰市阿鲁科尔沁旗");
-        map.insert("150422", "内蒙古自治区赤峰市巴林左旗");
-        map.insert("150423", "内蒙古自治区赤峰市巴林右旗");
-        map.insert("150424", "内蒙古自治区赤峰市林西县");
-        map.insert("150425", "内蒙古自治区赤峰市克什克腾旗");
-        map.insert("150426", "内蒙古自治区赤峰市翁牛特旗");
-        map.insert("150428", "内蒙古自治区赤峰市喀喇沁旗");
-        map.insert("150429", "内蒙古自治区赤峰市宁城县");
-        map.insert("150430", "内蒙古自治区赤峰市敖汉旗");
-        map.insert("150500", "内蒙古自治区通辽市");
-        map.insert("150502", "内蒙古自治区通辽市科尔沁区");
-        map.insert("150521", "内蒙古自治区通辽市科尔沁左翼中旗");
-        map.insert("150522", "内蒙古自治区通辽市科尔沁左翼后旗");
-        map.insert("150523", "内蒙古自治区通辽市开鲁县");
-        map.insert("150524", "内蒙古自治区通辽市库伦旗");
-        map.insert("150525", "内蒙古自治区通辽市奈曼旗");
-        map.insert("150526", "内蒙古自治区通辽市扎鲁特旗");
-        map.insert("150581", "内蒙古自治区通辽市霍林郭勒市");
-        map.insert("150600", "内蒙古自治区鄂尔多斯市");
-        map.insert("150602", "内蒙古自治区鄂尔多斯市东胜区");
-        map.insert("150603", "内蒙古自治区鄂尔多斯市康巴什区");
-        map.insert("150621", "内蒙古自治区鄂尔多斯市达拉特旗");
-        map.insert("150622", "内蒙古自治区鄂尔多斯市准格尔旗");
-        map.insert("150623", "内蒙古自治区鄂尔多斯市鄂托克前旗");
-        map.insert("150624", "内蒙古自治区鄂尔多斯市鄂托克旗");
-        map.insert("150625", "内蒙古自治区鄂尔多斯市杭锦旗");
-        map.insert("150626", "内蒙古自治区鄂尔多斯市乌审旗");
-        map.insert("150627", "内蒙古自治区鄂尔多斯市伊金霍洛旗");
-        map.insert("150700", "内蒙古自治区呼伦贝尔市");
-        map.insert("150702", "内蒙古自治区呼伦贝尔市海拉尔区");
-        map.insert("150703", "内蒙古自治区呼伦贝尔市扎赉诺尔区");
-        map.insert("150721", "内蒙古自治区呼伦贝尔市阿荣旗");
-        map.insert("150722", "内蒙古自治区呼伦贝尔市莫力达瓦达斡尔族自治旗");
-        map.insert("150723", "内蒙古自治区呼伦贝尔市鄂伦春自治旗");
-        map.insert("150724", "内蒙古自治区呼伦贝尔市鄂温克族自治旗");
-        map.insert("150725", "内蒙古自治区呼伦贝尔市陈巴尔虎旗");
-        map.insert("150726", "内蒙古自治区呼伦贝尔市新巴尔虎左旗");
-        map.insert("150727", "内蒙古自治区呼伦贝尔市新巴尔虎右旗");
-        map.insert("150781", "内蒙古自治区呼伦贝尔市满洲里市");
-        map.insert("150782", "内蒙古自治区呼伦贝尔市牙克石市");
-        map.insert("150783", "内蒙古自治区呼伦贝尔市扎兰屯市");
-        map.insert("150784", "内蒙古自治区呼伦贝尔市额尔古纳市");
-        map.insert("150785", "内蒙古自治区呼伦贝尔市根河市");
-        map.insert("150800", "内蒙古自治区巴彦淖尔市");
-        map.insert("150802", "内蒙古自治区巴彦淖尔市临河区");
-        map.insert("150821", "内蒙古自治区巴彦淖尔市五原县");
-        map.insert("150822", "内蒙古自治区巴彦淖尔市磴口县");
-        map.insert("150823", "内蒙古自治区巴彦淖尔市乌拉特前旗");
-        map.insert("150824", "内蒙古自治区巴彦淖尔市乌拉特中旗");
-        map.insert("150825", "内蒙古自治区巴彦淖尔市乌拉特后旗");
-        map.insert("150826", "内蒙古自治区巴彦淖尔市杭锦后旗");
-        map.insert("150900", "内蒙古自治区乌兰察布市");
-        map.insert("150902", "内蒙古自治区乌兰察布市集宁区");
-        map.insert("150921", "内蒙古自治区乌兰察布市卓资县");
-        map.insert("150922", "内蒙古自治区乌兰察布市化德县");
-        map.insert("150923", "内蒙古自治区乌兰察布市商都县");
-        map.insert("150924", "内蒙古自治区乌兰察布市兴和县");
-        map.insert("150925", "内蒙古自治区乌兰察布市凉城县");
-        map.insert("150926", "内蒙古自治区乌兰察布市察哈尔右翼前旗");
-        map.insert("150927", "内蒙古自治区乌兰察布市察哈尔右翼中旗");
-        map.insert("150928", "内蒙古自治区乌兰察布市察哈尔右翼后旗");
-        map.insert("150929", "内蒙古自治区乌兰察布市四子王旗");
-        map.insert("150981", "内蒙古自治区乌兰察布市丰镇市");
-        map.insert("152200", "内蒙古自治区兴安盟");
-        map.insert("152201", "内蒙古自治区兴安盟乌兰浩特市");
-        map.insert("152202", "内蒙古自治区兴安盟阿尔山市");
-        map.insert("152221", "内蒙古自治区兴安盟科尔沁右翼前旗");
-        map.insert("152222", "内蒙古自治区兴安盟科尔沁右翼中旗");
-        map.insert("152223", "内蒙古自治区兴安盟扎赉特旗");
-        map.insert("152224", "内蒙古自治区兴安盟突泉县");
-        map.insert("152500", "内蒙古自治区锡林郭勒盟");
-        map.insert("152501", "内蒙古自治区锡林郭勒盟二连浩特市");
-        map.insert("152502", "内蒙古自治区锡林郭勒盟锡林浩特市");
-        map.insert("152522", "内蒙古自治区锡林郭勒盟阿巴嘎旗");
-        map.insert("152523", "内蒙古自治区锡林郭勒盟苏尼特左旗");
-        map.insert("152524", "内蒙古自治区锡林郭勒盟苏尼特右旗");
-        map.insert("152525", "内蒙古自治区锡林郭勒盟东乌珠穆沁旗");
-        map.insert("152526", "内蒙古自治区锡林郭勒盟西乌珠穆沁旗");
-        map.insert("152527", "内蒙古自治区锡林郭勒盟太仆寺旗");
-        map.insert("152528", "内蒙古自治区锡林郭勒盟镶黄旗");
-        map.insert("152529", "内蒙古自治区锡林郭勒盟正镶白旗");
-        map.insert("152530", "内蒙古自治区锡林郭勒盟正蓝旗");
-        map.insert("152531", "内蒙古自治区锡林郭勒盟多伦县");
-        map.insert("152900", "内蒙古自治区阿拉善盟");
-        map.insert("152921", "内蒙古自治区阿拉善盟阿拉善左旗");
-        map.insert("152922", "内蒙古自治区阿拉善盟阿拉善右旗");
-        map.insert("152923", "内蒙古自治区阿拉善盟额济纳旗");
-        map.insert("210000", "辽宁省");
-        map.insert("210100", "辽宁省沈阳市");
-        map.insert("210102", "辽宁省沈阳市和平区");
-        map.insert("210103", "辽宁省沈阳市沈河区");
-        map.insert("210104", "辽宁省沈阳市大东区");
-        map.insert("210105", "辽宁省沈阳市皇姑区");
-        map.insert("210106", "辽宁省沈阳市铁西区");
-        map.insert("210111", "辽宁省沈阳市苏家屯区");
-        map.insert("210112", "辽宁省沈阳市浑南区");
-        map.insert("210113", "辽宁省沈阳市沈北新区");
-        map.insert("210114", "辽宁省沈阳市于洪区");
-        map.insert("210115", "辽宁省沈阳市辽中区");
-        map.insert("210123", "辽宁省沈阳市康平县");
-        map.insert("210124", "辽宁省沈阳市法库县");
-        map.insert("210181", "辽宁省沈阳市新民市");
-        map.insert("210200", "辽宁省大连市");
-        map.insert("210202", "辽宁省大连市中山区");
-        map.insert("210203", "辽宁省大连市西岗区");
-        map.insert("210204", "辽宁省大连市沙河口区");
-        map.insert("210211", "辽宁省大连市甘井子区");
-        map.insert("210212", "辽宁省大连市旅顺口区");
-        map.insert("210213", "辽宁省大连市金州区");
-        map.insert("210214", "辽宁省大连市普兰店区");
-        map.insert("210224", "辽宁省大连市长海县");
-        map.insert("210281", "辽宁省大连市瓦房店市");
-        map.insert("210283", "辽宁省大连市庄河市");
-        map.insert("210300", "辽宁省鞍山市");
-        map.insert("210302", "辽宁省鞍山市铁东区");
-        map.insert("210303", "辽宁省鞍山市铁西区");
-        map.insert("210304", "辽宁省鞍山市立山区");
-        map.insert("210311", "辽宁省鞍山市千山区");
-        map.insert("210321", "辽宁省鞍山市台安县");
-        map.insert("210323", "辽宁省鞍山市岫岩满族自治县");
-        map.insert("210381", "辽宁省鞍山市海城市");
-        map.insert("210400", "辽宁省抚顺市");
-        map.insert("210402", "辽宁省抚顺市新抚区");
-        map.insert("210403", "辽宁省抚顺市东洲区");
-        map.insert("210404", "辽宁省抚顺市望花区");
-        map.insert("210411", "辽宁省抚顺市顺城区");
-        map.insert("210421", "辽宁省抚顺市抚顺县");
-        map.insert("210422", "辽宁省抚顺市新宾满族自治县");
-        map.insert("210423", "辽宁省抚顺市清原满族自治县");
-        map.insert("210500", "辽宁省本溪市");
-        map.insert("210502", "辽宁省本溪市平山区");
-        map.insert("210503", "辽宁省本溪市溪湖区");
-        map.insert("210504", "辽宁省本溪市明山区");
-        map.insert("210505", "辽宁省本溪市南芬区");
-        map.insert("210521", "辽宁省本溪市本溪满族自治县");
-        map.insert("210522", "辽宁省本溪市桓仁满族自治县");
-        map.insert("210600", "辽宁省丹东市");
-        map.insert("210602", "辽宁省丹东市元宝区");
-        map.insert("210603", "辽宁省丹东市振兴区");
-        map.insert("210604", "辽宁省丹东市振安区");
-        map.insert("210624", "辽宁省丹东市宽甸满族自治县");
-        map.insert("210681", "辽宁省丹东市东港市");
-        map.insert("210682", "辽宁省丹东市凤城市");
-        map.insert("210700", "辽宁省锦州市");
-        map.insert("210702", "辽宁省锦州市古塔区");
-        map.insert("210703", "辽宁省锦州市凌河区");
-        map.insert("210711", "辽宁省锦州市太和区");
-        map.insert("210726", "辽宁省锦州市黑山县");
-        map.insert("210727", "辽宁省锦州市义县");
-        map.insert("210781", "辽宁省锦州市凌海市");
-        map.insert("210782", "辽宁省锦州市北镇市");
-        map.insert("210800", "辽宁省营口市");
-        map.insert("210802", "辽宁省营口市站前区");
-        map.insert("210803", "辽宁省营口市西市区");
-        map.insert("210804", "辽宁省营口市鲅鱼圈区");
-        map.insert("210811", "辽宁省营口市老边区");
-        map.insert("210881", "辽宁省营口市盖州市");
-        map.insert("210882", "辽宁省营口市大石桥市");
-        map.insert("210900", "辽宁省阜新市");
-        map.insert("210902", "辽宁省阜新市海州区");
-        map.insert("210903", "辽宁省阜新市新邱区");
-        map.insert("210904", "辽宁省阜新市太平区");
-        map.insert("210905", "辽宁省阜新市清河门区");
-        map.insert("210911", "辽宁省阜新市细河区");
-        map.insert("210921", "辽宁省阜新市阜新蒙古族自治县");
-        map.insert("210922", "辽宁省阜新市彰武县");
-        map.insert("211000", "辽宁省辽阳市");
-        map.insert("211002", "辽宁省辽阳市白塔区");
-        map.insert("211003", "辽宁省辽阳市文圣区");
-        map.insert("211004", "辽宁省辽阳市宏伟区");
-        map.insert("211005", "辽宁省辽阳市弓长岭区");
-        map.insert("211011", "辽宁省辽阳市太子河区");
-        map.insert("211021", "辽宁省辽阳市辽阳县");
-        map.insert("211081", "辽宁省辽阳市灯塔市");
-        map.insert("211100", "辽宁省盘锦市");
-        map.insert("211102", "辽宁省盘锦市双台子区");
-        map.insert("211103", "辽宁省盘锦市兴隆台区");
-        map.insert("211104", "辽宁省盘锦市大洼区");
-        map.insert("211122", "辽宁省盘锦市盘山县");
-        map.insert("211200", "辽宁省铁岭市");
-        map.insert("211202", "辽宁省铁岭市银州区");
-        map.insert("211204", "辽宁省铁岭市清河区");
-        map.insert("211221", "辽宁省铁岭市铁岭县");
-        map.insert("211223", "辽宁省铁岭市西丰县");
-        map.insert("211224", "辽宁省铁岭市昌图县");
-        map.insert("211281", "辽宁省铁岭市调兵山市");
-        map.insert("211282", "辽宁省铁岭市开原市");
-        map.insert("211300", "辽宁省朝阳市");
-        map.insert("211302", "辽宁省朝阳市双塔区");
-        map.insert("211303", "辽宁省朝阳市龙城区");
-        map.insert("211321", "辽宁省朝阳市朝阳县");
-        map.insert("211322", "辽宁省朝阳市建平县");
-        map.insert("211324", "辽宁省朝阳市喀喇沁左翼蒙古族自治县");
-        map.insert("211381", "辽宁省朝阳市北票市");
-        map.insert("211382", "辽宁省朝阳市凌源市");
-        map.insert("211400", "辽宁省葫芦岛市");
-        map.insert("211402", "辽宁省葫芦岛市连山区");
-        map.insert("211403", "辽宁省葫芦岛市龙港区");
-        map.insert("211404", "辽宁省葫芦岛市南票区");
-        map.insert("211421", "辽宁省葫芦岛市绥中县");
-        map.insert("211422", "辽宁省葫芦岛市建昌县");
-        map.insert("211481", "辽宁省葫芦岛市兴城市");
-        map.insert("220000", "吉林省");
-        map.insert("220100", "吉林省长春市");
-        map.insert("220102", "吉林省长春市南关区");
-        map.insert("220103", "吉林省长春市宽城区");
-        map.insert("220104", "吉林省长春市朝阳区");
-        map.insert("220105", "吉林省长春市二道区");
-        map.insert("220106", "吉林省长春市绿园区");
-        map.insert("220112", "吉林省长春市双阳区");
-        map.insert("220113", "吉林省长春市九台区");
-        map.insert("220122", "吉林省长春市农安县");
-        map.insert("220182", "吉林省长春市榆树市");
-        map.insert("220183", "吉林省长春市德惠市");
-        map.insert("220184", "吉林省长春市公主岭市");
-        map.insert("220200", "吉林省吉林市");
-        map.insert("220202", "吉林省吉林市昌邑区");
-        map.insert("220203", "吉林省吉林市龙潭区");
-        map.insert("220204", "吉林省吉林市船营区");
-        map.insert("220211", "吉林省吉林市丰满区");
-        map.insert("220221", "吉林省吉林市永吉县");
-        map.insert("220281", "吉林省吉林市蛟河市");
-        map.insert("220282", "吉林省吉林市桦甸市");
-        map.insert("220283", "吉林省吉林市舒兰市");
-        map.insert("220284", "吉林省吉林市磐石市");
-        map.insert("220300", "吉林省四平市");
-        map.insert("220302", "吉林省四平市铁西区");
-        map.insert("220303", "吉林省四平市铁东区");
-        map.insert("220322", "吉林省四平市梨树县");
-        map.insert("220323", "吉林省四平市伊通满族自治县");
-        map.insert("220382", "吉林省四平市双辽市");
-        map.insert("220400", "吉林省辽源市");
-        map.insert("220402", "吉林省辽源市龙山区");
-        map.insert("220403", "吉林省辽源市西安区");
-        map.insert("220421", "吉林省辽源市东丰县");
-        map.insert("220422", "吉林省辽源市东辽县");
-        map.insert("220500", "吉林省通化市");
-        map.insert("220502", "吉林省通化市东昌区");
-        map.insert("220503", "吉林省通化市二道江区");
-        map.insert("220521", "吉林省通化市通化县");
-        map.insert("220523", "吉林省通化市辉南县");
-        map.insert("220524", "吉林省通化市柳河县");
-        map.insert("220581", "吉林省通化市梅河口市");
-        map.insert("220582", "吉林省通化市集安市");
-        map.insert("220600", "吉林省白山市");
-        map.insert("220602", "吉林省白山市浑江区");
-        map.insert("220605", "吉林省白山市江源区");
-        map.insert("220621", "吉林省白山市抚松县");
-        map.insert("220622", "吉林省白山市靖宇县");
-        map.insert("220623", "吉林省白山市长白朝鲜族自治县");
-        map.insert("220681", "吉林省白山市临江市");
-        map.insert("220700", "吉林省松原市");
-        map.insert("220702", "吉林省松原市宁江区");
-        map.insert("220721", "吉林省松原市前郭尔罗斯蒙古族自治县");
-        map.insert("220722", "吉林省松原市长岭县");
-        map.insert("220723", "吉林省松原市乾安县");
-        map.insert("220781", "吉林省松原市扶余市");
-        map.insert("220800", "吉林省白城市");
-        map.insert("220802", "吉林省白城市洮北区");
-        map.insert("220821", "吉林省白城市镇赉县");
-        map.insert("220822", "吉林省白城市通榆县");
-        map.insert("220881", "吉林省白城市洮南市");
-        map.insert("220882", "吉林省白城市大安市");
-        map.insert("222400", "吉林省延边朝鲜族自治州");
-        map.insert("222401", "吉林省延边朝鲜族自治州延吉市");
-        map.insert("222402", "吉林省延边朝鲜族自治州图们市");
-        map.insert("222403", "吉林省延边朝鲜族自治州敦化市");
-        map.insert("222404", "吉林省延边朝鲜族自治州珲春市");
-        map.insert("222405", "吉林省延边朝鲜族自治州龙井市");
-        map.insert("222406", "吉林省延边朝鲜族自治州和龙市");
-        map.insert("222424", "吉林省延边朝鲜族自治州汪清县");
-        map.insert("222426", "吉林省延边朝鲜族自治州安图县");
-        map.insert("230000", "黑龙江省");
-        map.insert("230100", "黑龙江省哈尔滨市");
-        map.insert("230102", "黑龙江省哈尔滨市道里区");
-        map.insert("230103", "黑龙江省哈尔滨市南岗区");
-        map.insert("230104", "黑龙江省哈尔滨市道外区");
-        map.insert("230108", "黑龙江省哈尔滨市平房区");
-        map.insert("230109", "黑龙江省哈尔滨市松北区");
-        map.insert("230110", "黑龙江省哈尔滨市香坊区");
-        map.insert("230111", "黑龙江省哈尔滨市呼兰区");
-        map.insert("230112", "黑龙江省哈尔滨市阿城区");
-        map.insert("230113", "黑龙江省哈尔滨市双城区");
-        map.insert("230123", "黑龙江省哈尔滨市依兰县");
-        map.insert("230124", "黑龙江省哈尔滨市方正县");
-        map.insert("230125", "黑龙江省哈尔滨市宾县");
-        map.insert("230126", "黑龙江省哈尔滨市巴彦县");
-        map.insert("230127", "黑龙江省哈尔滨市木兰县");
-        map.insert("230128", "黑龙江省哈尔滨市通河县");
-        map.insert("230129", "黑龙江省哈尔滨市延寿县");
-        map.insert("230183", "黑龙江省哈尔滨市尚志市");
-        map.insert("230184", "黑龙江省哈尔滨市五常市");
-        map.insert("230200", "黑龙江省齐齐哈尔市");
-        map.insert("230202", "黑龙江省齐齐哈尔市龙沙区");
-        map.insert("230203", "黑龙江省齐齐哈尔市建华区");
-        map.insert("230204", "黑龙江省齐齐哈尔市铁锋区");
-        map.insert("230205", "黑龙江省齐齐哈尔市昂昂溪区");
-        map.insert("230206", "黑龙江省齐齐哈尔市富拉尔基区");
-        map.insert("230207", "黑龙江省齐齐哈尔市碾子山区");
-        map.insert("230208", "黑龙江省齐齐哈尔市梅里斯达斡尔族区");
-        map.insert("230221", "黑龙江省齐齐哈尔市龙江县");
-        map.insert("230223", "黑龙江省齐齐哈尔市依安县");
-        map.insert("230224", "黑龙江省齐齐哈尔市泰来县");
-        map.insert("230225", "黑龙江省齐齐哈尔市甘南县");
-        map.insert("230227", "黑龙江省齐齐哈尔市富裕县");
-        map.insert("230229", "黑龙江省齐齐哈尔市克山县");
-        map.insert("230230", "黑龙江省齐齐哈尔市克东县");
-        map.insert("230231", "黑龙江省齐齐哈尔市拜泉县");
-        map.insert("230281", "黑龙江省齐齐哈尔市讷河市");
-        map.insert("230300", "黑龙江省鸡西市");
-        map.insert("230302", "黑龙江省鸡西市鸡冠区");
-        map.insert("230303", "黑龙江省鸡西市恒山区");
-        map.insert("230304", "黑龙江省鸡西市滴道区");
-        map.insert("230305", "黑龙江省鸡西市梨树区");
-        map.insert("230306", "黑龙江省鸡西市城子河区");
-        map.insert("230307", "黑龙江省鸡西市麻山区");
-        map.insert("230321", "黑龙江省鸡西市鸡东县");
-        map.insert("230381", "黑龙江省鸡西市虎林市");
-        map.insert("230382", "黑龙江省鸡西市密山市");
-        map.insert("230400", "黑龙江省鹤岗市");
-        map.insert("230402", "黑龙江省鹤岗市向阳区");
-        map.insert("230403", "黑龙江省鹤岗市工农区");
-        map.insert("230404", "黑龙江省鹤岗市南山区");
-        map.insert("230405", "黑龙江省鹤岗市兴安区");
-        map.insert("230406", "黑龙江省鹤岗市东山区");
-        map.insert("230407", "黑龙江省鹤岗市兴山区");
-        map.insert("230421", "黑龙江省鹤岗市萝北县");
-        map.insert("230422", "黑龙江省鹤岗市绥滨县");
-        map.insert("230500", "黑龙江省双鸭山市");
-        map.insert("230502", "黑龙江省双鸭山市尖山区");
-        map.insert("230503", "黑龙江省双鸭山市岭东区");
-        map.insert("230505", "黑龙江省双鸭山市四方台区");
-        map.insert("230506", "黑龙江省双鸭山市宝山区");
-        map.insert("230521", "黑龙江省双鸭山市集贤县");
-        map.insert("230522", "黑龙江省双鸭山市友谊县");
-        map.insert("230523", "黑龙江省双鸭山市宝清县");
-        map.insert("230524", "黑龙江省双鸭山市饶河县");
-        map.insert("230600", "黑龙江省大庆市");
-        map.insert("230602", "黑龙江省大庆市萨尔图区");
-        map.insert("230603", "黑龙江省大庆市龙凤区");
-        map.insert("230604", "黑龙江省大庆市让胡路区");
-        map.insert("230605", "黑龙江省大庆市红岗区");
-        map.insert("230606", "黑龙江省大庆市大同区");
-        map.insert("230621", "黑龙江省大庆市肇州县");
-        map.insert("230622", "黑龙江省大庆市肇源县");
-        map.insert("230623", "黑龙江省大庆市林甸县");
-        map.insert("230624", "黑龙江省大庆市杜尔伯特蒙古族自治县");
-        map.insert("230700", "黑龙江省伊春市");
-        map.insert("230717", "黑龙江省伊春市伊美区");
-        map.insert("230718", "黑龙江省伊春市乌翠区");
-        map.insert("230719", "黑龙江省伊春市友好区");
-        map.insert("230722", "黑龙江省伊春市嘉荫县");
-        map.insert("230723", "黑龙江省伊春市汤旺县");
-        map.insert("230724", "黑龙江省伊春市丰林县");
-        map.insert("230725", "黑龙江省伊春市大箐山县");
-        map.insert("230726", "黑龙江省伊春市南岔县");
-        map.insert("230751", "黑龙江省伊春市金林区");
-        map.insert("230781", "黑龙江省伊春市铁力市");
-        map.insert("230800", "黑龙江省佳木斯市");
-        map.insert("230803", "黑龙江省佳木斯市向阳区");
-        map.insert("230804", "黑龙江省佳木斯市前进区");
-        map.insert("230805", "黑龙江省佳木斯市东风区");
-        map.insert("230811", "黑龙江省佳木斯市郊区");
-        map.insert("230822", "黑龙江省佳木斯市桦南县");
-        map.insert("230826", "黑龙江省佳木斯市桦川县");
-        map.insert("230828", "黑龙江省佳木斯市汤原县");
-        map.insert("230881", "黑龙江省佳木斯市同江市");
-        map.insert("230882", "黑龙江省佳木斯市富锦市");
-        map.insert("230883", "黑龙江省佳木斯市抚远市");
-        map.insert("230900", "黑龙江省七台河市");
-        map.insert("230902", "黑龙江省七台河市新兴区");
-        map.insert("230903", "黑龙江省七台河市桃山区");
-        map.insert("230904", "黑龙江省七台河市茄子河区");
-        map.insert("230921", "黑龙江省七台河市勃利县");
-        map.insert("231000", "黑龙江省牡丹江市");
-        map.insert("231002", "黑龙江省牡丹江市东安区");
-        map.insert("231003", "黑龙江省牡丹江市阳明区");
-        map.insert("231004", "黑龙江省牡丹江市爱民区");
-        map.insert("231005", "黑龙江省牡丹江市西安区");
-        map.insert("231025", "黑龙江省牡丹江市林口县");
-        map.insert("231081", "黑龙江省牡丹江市绥芬河市");
-        map.insert("231083", "黑龙江省牡丹江市海林市");
-        map.insert("231084", "黑龙江省牡丹江市宁安市");
-        map.insert("231085", "黑龙江省牡丹江市穆棱市");
-        map.insert("231086", "黑龙江省牡丹江市东宁市");
-        map.insert("231100", "黑龙江省黑河市");
-        map.insert("231102", "黑龙江省黑河市爱辉区");
-        map.insert("231123", "黑龙江省黑河市逊克县");
-        map.insert("231124", "黑龙江省黑河市孙吴县");
-        map.insert("231181", "黑龙江省黑河市北安市");
-        map.insert("231182", "黑龙江省黑河市五大连池市");
-        map.insert("231183", "黑龙江省黑河市嫩江市");
-        map.insert("231200", "黑龙江省绥化市");
-        map.insert("231202", "黑龙江省绥化市北林区");
-        map.insert("231221", "黑龙江省绥化市望奎县");
-        map.insert("231222", "黑龙江省绥化市兰西县");
-        map.insert("231223", "黑龙江省绥化市青冈县");
-        map.insert("231224", "黑龙江省绥化市庆安县");
-        map.insert("231225", "黑龙江省绥化市明水县");
-        map.insert("231226", "黑龙江省绥化市绥棱县");
-        map.insert("231281", "黑龙江省绥化市安达市");
-        map.insert("231282", "黑龙江省绥化市肇东市");
-        map.insert("231283", "黑龙江省绥化市海伦市");
-        map.insert("232700", "黑龙江省大兴安岭地区");
-        map.insert("232701", "黑龙江省大兴安岭地区漠河市");
-        map.insert("232721", "黑龙江省大兴安岭地区呼玛县");
-        map.insert("232722", "黑龙江省大兴安岭地区塔河县");
-        map.insert("310000", "上海市");
-        map.insert("310101", "上海市黄浦区");
-        map.insert("310104", "上海市徐汇区");
-        map.insert("310105", "上海市长宁区");
-        map.insert("310106", "上海市静安区");
-        map.insert("310107", "上海市普陀区");
-        map.insert("310109", "上海市虹口区");
-        map.insert("310110", "上海市杨浦区");
-        map.insert("310112", "上海市闵行区");
-        map.insert("310113", "上海市宝山区");
-        map.insert("310114", "上海市嘉定区");
-        map.insert("310115", "上海市浦东新区");
-        map.insert("310116", "上海市金山区");
-        map.insert("310117", "上海市松江区");
-        map.insert("310118", "上海市青浦区");
-        map.insert("310120", "上海市奉贤区");
-        map.insert("310151", "上海市崇明区");
-        map.insert("320000", "江苏省");
-        map.insert("320100", "江苏省南京市");
-        map.insert("320102", "江苏省南京市玄武区");
-        map.insert("320104", "江苏省南京市秦淮区");
-        map.insert("320105", "江苏省南京市建邺区");
-        map.insert("320106", "江苏省南京市鼓楼区");
-        map.insert("320111", "江苏省南京市浦口区");
-        map.insert("320113", "江苏省南京市栖霞区");
-        map.insert("320114", "江苏省南京市雨花台区");
-        map.insert("320115", "江苏省南京市江宁区");
-        map.insert("320116", "江苏省南京市六合区");
-        map.insert("320117", "江苏省南京市溧水区");
-        map.insert("320118", "江苏省南京市高淳区");
-        map.insert("320200", "江苏省无锡市");
-        map.insert("320205", "江苏省无锡市锡山区");
-        map.insert("320206", "江苏省无锡市惠山区");
-        map.insert("320211", "江苏省无锡市滨湖区");
-        map.insert("320213", "江苏省无锡市梁溪区");
-        map.insert("320214", "江苏省无锡市新吴区");
-        map.insert("320281", "江苏省无锡市江阴市");
-        map.insert("320282", "江苏省无锡市宜兴市");
-        map.insert("320300", "江苏省徐州市");
-        map.insert("320302", "江苏省徐州市鼓楼区");
-        map.insert("320303", "江苏省徐州市云龙区");
-        map.insert("320305", "江苏省徐州市贾汪区");
-        map.insert("320311", "江苏省徐州市泉山区");
-        map.insert("320312", "江苏省徐州市铜山区");
-        map.insert("320321", "江苏省徐州市丰县");
-        map.insert("320322", "江苏省徐州市沛县");
-        map.insert("320324", "江苏省徐州市睢宁县");
-        map.insert("320381", "江苏省徐州市新沂市");
-        map.insert("320382", "江苏省徐州市邳州市");
-        map.insert("320400", "江苏省常州市");
-        map.insert("320402", "江苏省常州市天宁区");
-        map.insert("320404", "江苏省常州市钟楼区");
-        map.insert("320411", "江苏省常州市新北区");
-        map.insert("320412", "江苏省常州市武进区");
-        map.insert("320413", "江苏省常州市金坛区");
-        map.insert("320481", "江苏省常州市溧阳市");
-        map.insert("320500", "江苏省苏州市");
-        map.insert("320505", "江苏省苏州市虎丘区");
-        map.insert("320506", "江苏省苏州市吴中区");
-        map.insert("320507", "江苏省苏州市相城区");
-        map.insert("320508", "江苏省苏州市姑苏区");
-        map.insert("320509", "江苏省苏州市吴江区");
-        map.insert("320581", "江苏省苏州市常熟市");
-        map.insert("320582", "江苏省苏州市张家港市");
-        map.insert("320583", "江苏省苏州市昆山市");
-        map.insert("320585", "江苏省苏州市太仓市");
-        map.insert("320600", "江苏省南通市");
-        map.insert("320612", "江苏省南通市通州区");
-        map.insert("320613", "江苏省南通市崇川区");
-        map.insert("320614", "江苏省南通市海门区");
-        map.insert("320623", "江苏省南通市如东县");
-        map.insert("320681", "江苏省南通市启东市");
-        map.insert("320682", "江苏省南通市如皋市");
-        map.insert("320685", "江苏省南通市海安市");
-        map.insert("320700", "江苏省连云港市");
-        map.insert("320703", "江苏省连云港市连云区");
-        map.insert("320706", "江苏省连云港市海州区");
-        map.insert("320707", "江苏省连云港市赣榆区");
-        map.insert("320722", "江苏省连云港市东海县");
-        map.insert("320723", "江苏省连云港市灌云县");
-        map.insert("320724", "江苏省连云港市灌南县");
-        map.insert("320800", "江苏省淮安市");
-        map.insert("320803", "江苏省淮安市淮安区");
-        map.insert("320804", "江苏省淮安市淮阴区");
-        map.insert("320812", "江苏省淮安市清江浦区");
-        map.insert("320813", "江苏省淮安市洪泽区");
-        map.insert("320826", "江苏省淮安市涟水县");
-        map.insert("320830", "江苏省淮安市盱眙县");
-        map.insert("320831", "江苏省淮安市金湖县");
-        map.insert("320900", "江苏省盐城市");
-        map.insert("320902", "江苏省盐城市亭湖区");
-        map.insert("320903", "江苏省盐城市盐都区");
-        map.insert("320904", "江苏省盐城市大丰区");
-        map.insert("320921", "江苏省盐城市响水县");
-        map.insert("320922", "江苏省盐城市滨海县");
-        map.insert("320923", "江苏省盐城市阜宁县");
-        map.insert("320924", "江苏省盐城市射阳县");
-        map.insert("320925", "江苏省盐城市建湖县");
-        map.insert("320981", "江苏省盐城市东台市");
-        map.insert("321000", "江苏省扬州市");
-        map.insert("321002", "江苏省扬州市广陵区");
-        map.insert("321003", "江苏省扬州市邗江区");
-        map.insert("321012", "江苏省扬州市江都区");
-        map.insert("321023", "江苏省扬州市宝应县");
-        map.insert("321081", "江苏省扬州市仪征市");
-        map.insert("321084", "江苏省扬州市高邮市");
-        map.insert("321100", "江苏省镇江市");
-        map.insert("321102", "江苏省镇江市京口区");
-        map.insert("321111", "江苏省镇江市润州区");
-        map.insert("321112", "江苏省镇江市丹徒区");
-        map.insert("321181", "江苏省镇江市丹阳市");
-        map.insert("321182", "江苏省镇江市扬中市");
-        map.insert("321183", "江苏省镇江市句容市");
-        map.insert("321200", "江苏省泰州市");
-        map.insert("321202", "江苏省泰州市海陵区");
-        map.insert("321203", "江苏省泰州市高港区");
-        map.insert("321204", "江苏省泰州市姜堰区");
-        map.insert("321281", "江苏省泰州市兴化市");
-        map.insert("321282", "江苏省泰州市靖江市");
-        map.insert("321283", "江苏省泰州市泰兴市");
-        map.insert("321300", "江苏省宿迁市");
-        map.insert("321302", "江苏省宿迁市宿城区");
-        map.insert("321311", "江苏省宿迁市宿豫区");
-        map.insert("321322", "江苏省宿迁市沭阳县");
-        map.insert("321323", "江苏省宿迁市泗阳县");
-        map.insert("321324", "江苏省宿迁市泗洪县");
-        map.insert("330000", "浙江省");
-        map.insert("330100", "浙江省杭州市");
-        map.insert("330102", "浙江省杭州市上城区");
-        map.insert("330105", "浙江省杭州市拱墅区");
-        map.insert("330106", "浙江省杭州市西湖区");
-        map.insert("330108", "浙江省杭州市滨江区");
-        map.insert("330109", "浙江省杭州市萧山区");
-        map.insert("330110", "浙江省杭州市余杭区");
-        map.insert("330111", "浙江省杭州市富阳区");
-        map.insert("330112", "浙江省杭州市临安区");
-        map.insert("330113", "浙江省杭州市临平区");
-        map.insert("330114", "浙江省杭州市钱塘区");
-        map.insert("330122", "浙江省杭州市桐庐县");
-        map.insert("330127", "浙江省杭州市淳安县");
-        map.insert("330182", "浙江省杭州市建德市");
-        map.insert("330200", "浙江省宁波市");
-        map.insert("330203", "浙江省宁波市海曙区");
-        map.insert("330205", "浙江省宁波市江北区");
-        map.insert("330206", "浙江省宁波市北仑区");
-        map.insert("330211", "浙江省宁波市镇海区");
-        map.insert("330212", "浙江省宁波市鄞州区");
-        map.insert("330213", "浙江省宁波市奉化区");
-        map.insert("330225", "浙江省宁波市象山县");
-        map.insert("330226", "浙江省宁波市宁海县");
-        map.insert("330281", "浙江省宁波市余姚市");
-        map.insert("330282", "浙江省宁波市慈溪市");
-        map.insert("330300", "浙江省温州市");
-        map.insert("330302", "浙江省温州市鹿城区");
-        map.insert("330303", "浙江省温州市龙湾区");
-        map.insert("330304", "浙江省温州市瓯海区");
-        map.insert("330305", "浙江省温州市洞头区");
-        map.insert("330324", "浙江省温州市永嘉县");
-        map.insert("330326", "浙江省温州市平阳县");
-        map.insert("330327", "浙江省温州市苍南县");
-        map.insert("330328", "浙江省温州市文成县");
-        map.insert("330329", "浙江省温州市泰顺县");
-        map.insert("330381", "浙江省温州市瑞安市");
-        map.insert("330382", "浙江省温州市乐清市");
-        map.insert("330383", "浙江省温州市龙港市");
-        map.insert("330400", "浙江省嘉兴市");
-        map.insert("330402", "浙江省嘉兴市南湖区");
-        map.insert("330411", "浙江省嘉兴市秀洲区");
-        map.insert("330421", "浙江省嘉兴市嘉善县");
-        map.insert("330424", "浙江省嘉兴市海盐县");
-        map.insert("330481", "浙江省嘉兴市海宁市");
-        map.insert("330482", "浙江省嘉兴市平湖市");
-        map.insert("330483", "浙江省嘉兴市桐乡市");
-        map.insert("330500", "浙江省湖州市");
-        map.insert("330502", "浙江省湖州市吴兴区");
-        map.insert("330503", "浙江省湖州市南浔区");
-        map.insert("330521", "浙江省湖州市德清县");
-        map.insert("330522", "浙江省湖州市长兴县");
-        map.insert("330523", "浙江省湖州市安吉县");
-        map.insert("330600", "浙江省绍兴市");
-        map.insert("330602", "浙江省绍兴市越城区");
-        map.insert("330603", "浙江省绍兴市柯桥区");
-        map.insert("330604", "浙江省绍兴市上虞区");
-        map.insert("330624", "浙江省绍兴市新昌县");
-        map.insert("330681", "浙江省绍兴市诸暨市");
-        map.insert("330683", "浙江省绍兴市嵊州市");
-        map.insert("330700", "浙江省金华市");
-        map.insert("330702", "浙江省金华市婺城区");
-        map.insert("330703", "浙江省金华市金东区");
-        map.insert("330723", "浙江省金华市武义县");
-        map.insert("330726", "浙江省金华市浦江县");
-        map.insert("330727", "浙江省金华市磐安县");
-        map.insert("330781", "浙江省金华市兰溪市");
-        map.insert("330782", "浙江省金华市义乌市");
-        map.insert("330783", "浙江省金华市东阳市");
-        map.insert("330784", "浙江省金华市永康市");
-        map.insert("330800", "浙江省衢州市");
-        map.insert("330802", "浙江省衢州市柯城区");
-        map.insert("330803", "浙江省衢州市衢江区");
-        map.insert("330822", "浙江省衢州市常山县");
-        map.insert("330824", "浙江省衢州市开化县");
-        map.insert("330825", "浙江省衢州市龙游县");
-        map.insert("330881", "浙江省衢州市江山市");
-        map.insert("330900", "浙江省舟山市");
-        map.insert("330902", "浙江省舟山市定海区");
-        map.insert("330903", "浙江省舟山市普陀区");
-        map.insert("330921", "浙江省舟山市岱山县");
-        map.insert("330922", "浙江省舟山市嵊泗县");
-        map.insert("331000", "浙江省台州市");
-        map.insert("331002", "浙江省台州市椒江区");
-        map.insert("331003", "浙江省台州市黄岩区");
-        map.insert("331004", "浙江省台州市路桥区");
-        map.insert("331022", "浙江省台州市三门县");
-        map.insert("331023", "浙江省台州市天台县");
-        map.insert("331024", "浙江省台州市仙居县");
-        map.insert("331081", "浙江省台州市温岭市");
-        map.insert("331082", "浙江省台州市临海市");
-        map.insert("331083", "浙江省台州市玉环市");
-        map.insert("331100", "浙江省丽水市");
-        map.insert("331102", "浙江省丽水市莲都区");
-        map.insert("331121", "浙江省丽水市青田县");
-        map.insert("331122", "浙江省丽水市缙云县");
-        map.insert("331123", "浙江省丽水市遂昌县");
-        map.insert("331124", "浙江省丽水市松阳县");
-        map.insert("331125", "浙江省丽水市云和县");
-        map.insert("331126", "浙江省丽水市庆元县");
-        map.insert("331127", "浙江省丽水市景宁畲族自治县");
-        map.insert("331181", "浙江省丽水市龙泉市");
-        map.insert("340000", "安徽省");
-        map.insert("340100", "安徽省合肥市");
-        map.insert("340102", "安徽省合肥市瑶海区");
-        map.insert("340103", "安徽省合肥市庐阳区");
-        map.insert("340104", "安徽省合肥市蜀山区");
-        map.insert("340111", "安徽省合肥市包河区");
-        map.insert("340121", "安徽省合肥市长丰县");
-        map.insert("340122", "安徽省合肥市肥东县");
-        map.insert("340123", "安徽省合肥市肥西县");
-        map.insert("340124", "安徽省合肥市庐江县");
-        map.insert("340181", "安徽省合肥市巢湖市");
-        map.insert("340200", "安徽省芜湖市");
-        map.insert("340202", "安徽省芜湖市镜湖区");
-        map.insert("340207", "安徽省芜湖市鸠江区");
-        map.insert("340209", "安徽省芜湖市弋江区");
-        map.insert("340210", "安徽省芜湖市湾沚区");
-        map.insert("340212", "安徽省芜湖市繁昌区");
-        map.insert("340223", "安徽省芜湖市南陵县");
-        map.insert("340281", "安徽省芜湖市无为市");
-        map.insert("340300", "安徽省蚌埠市");
-        map.insert("340302", "安徽省蚌埠市龙子湖区");
-        map.insert("340303", "安徽省蚌埠市蚌山区");
-        map.insert("340304", "安徽省蚌埠市禹会区");
-        map.insert("340311", "安徽省蚌埠市淮上区");
-        map.insert("340321", "安徽省蚌埠市怀远县");
-        map.insert("340322", "安徽省蚌埠市五河县");
-        map.insert("340323", "安徽省蚌埠市固镇县");
-        map.insert("340400", "安徽省淮南市");
-        map.insert("340402", "安徽省淮南市大通区");
-        map.insert("340403", "安徽省淮南市田家庵区");
-        map.insert("340404", "安徽省淮南市谢家集区");
-        map.insert("340405", "安徽省淮南市八公山区");
-        map.insert("340406", "安徽省淮南市潘集区");
-        map.insert("340421", "安徽省淮南市凤台县");
-        map.insert("340422", "安徽省淮南市寿县");
-        map.insert("340500", "安徽省马鞍山市");
-        map.insert("340503", "安徽省马鞍山市花山区");
-        map.insert("340504", "安徽省马鞍山市雨山区");
-        map.insert("340506", "安徽省马鞍山市博望区");
-        map.insert("340521", "安徽省马鞍山市当涂县");
-        map.insert("340522", "安徽省马鞍山市含山县");
-        map.insert("340523", "安徽省马鞍山市和县");
-        map.insert("340600", "安徽省淮北市");
-        map.insert("340602", "安徽省淮北市杜集区");
-        map.insert("340603", "安徽省淮北市相山区");
-        map.insert("340604", "安徽省淮北市烈山区");
-        map.insert("340621", "安徽省淮北市濉溪县");
-        map.insert("340700", "安徽省铜陵市");
-        map.insert("340705", "安徽省铜陵市铜官区");
-        map.insert("340706", "安徽省铜陵市义安区");
-        map.insert("340711", "安徽省铜陵市郊区");
-        map.insert("340722", "安徽省铜陵市枞阳县");
-        map.insert("340800", "安徽省安庆市");
-        map.insert("340802", "安徽省安庆市迎江区");
-        map.insert("340803", "安徽省安庆市大观区");
-        map.insert("340811", "安徽省安庆市宜秀区");
-        map.insert("340822", "安徽省安庆市怀宁县");
-        map.insert("340825", "安徽省安庆市太湖县");
-        map.insert("340826", "安徽省安庆市宿松县");
-        map.insert("340827", "安徽省安庆市望江县");
-        map.insert("340828", "安徽省安庆市岳西县");
-        map.insert("340881", "安徽省安庆市桐城市");
-        map.insert("340882", "安徽省安庆市潜山市");
-        map.insert("341000", "安徽省黄山市");
-        map.insert("341002", "安徽省黄山市屯溪区");
-        map.insert("341003", "安徽省黄山市黄山区");
-        map.insert("341004", "安徽省黄山市徽州区");
-        map.insert("341021", "安徽省黄山市歙县");
-        map.insert("341022", "安徽省黄山市休宁县");
-        map.insert("341023", "安徽省黄山市黟县");
-        map.insert("341024", "安徽省黄山市祁门县");
-        map.insert("341100", "安徽省滁州市");
-        map.insert("341102", "安徽省滁州市琅琊区");
-        map.insert("341103", "安徽省滁州市南谯区");
-        map.insert("341122", "安徽省滁州市来安县");
-        map.insert("341124", "安徽省滁州市全椒县");
-        map.insert("341125", "安徽省滁州市定远县");
-        map.insert("341126", "安徽省滁州市凤阳县");
-        map.insert("341181", "安徽省滁州市天长市");
-        map.insert("341182", "安徽省滁州市明光市");
-        map.insert("341200", "安徽省阜阳市");
-        map.insert("341202", "安徽省阜阳市颍州区");
-        map.insert("341203", "安徽省阜阳市颍东区");
-        map.insert("341204", "安徽省阜阳市颍泉区");
-        map.insert("341221", "安徽省阜阳市临泉县");
-        map.insert("341222", "安徽省阜阳市太和县");
-        map.insert("341225", "安徽省阜阳市阜南县");
-        map.insert("341226", "安徽省阜阳市颍上县");
-        map.insert("341282", "安徽省阜阳市界首市");
-        map.insert("341300", "安徽省宿州市");
-        map.insert("341302", "安徽省宿州市埇桥区");
-        map.insert("341321", "安徽省宿州市砀山县");
-        map.insert("341322", "安徽省宿州市萧县");
-        map.insert("341323", "安徽省宿州市灵璧县");
-        map.insert("341324", "安徽省宿州市泗县");
-        map.insert("341500", "安徽省六安市");
-        map.insert("341502", "安徽省六安市金安区");
-        map.insert("341503", "安徽省六安市裕安区");
-        map.insert("341504", "安徽省六安市叶集区");
-        map.insert("341522", "安徽省六安市霍邱县");
-        map.insert("341523", "安徽省六安市舒城县");
-        map.insert("341524", "安徽省六安市金寨县");
-        map.insert("341525", "安徽省六安市霍山县");
-        map.insert("341600", "安徽省亳州市");
-        map.insert("341602", "安徽省亳州市谯城区");
-        map.insert("341621", "安徽省亳州市涡阳县");
-        map.insert("341622", "安徽省亳州市蒙城县");
-        map.insert("341623", "安徽省亳州市利辛县");
-        map.insert("341700", "安徽省池州市");
-        map.insert("341702", "安徽省池州市贵池区");
-        map.insert("341721", "安徽省池州市东至县");
-        map.insert("341722", "安徽省池州市石台县");
-        map.insert("341723", "安徽省池州市青阳县");
-        map.insert("341800", "安徽省宣城市");
-        map.insert("341802", "安徽省宣城市宣州区");
-        map.insert("341821", "安徽省宣城市郎溪县");
-        map.insert("341823", "安徽省宣城市泾县");
-        map.insert("341824", "安徽省宣城市绩溪县");
-        map.insert("341825", "安徽省宣城市旌德县");
-        map.insert("341881", "安徽省宣城市宁国市");
-        map.insert("341882", "安徽省宣城市广德市");
-        map.insert("350000", "福建省");
-        map.insert("350100", "福建省福州市");
-        map.insert("350102", "福建省福州市鼓楼区");
-        map.insert("350103", "福建省福州市台江区");
-        map.insert("350104", "福建省福州市仓山区");
-        map.insert("350105", "福建省福州市马尾区");
-        map.insert("350111", "福建省福州市晋安区");
-        map.insert("350112", "福建省福州市长乐区");
-        map.insert("350121", "福建省福州市闽侯县");
-        map.insert("350122", "福建省福州市连江县");
-        map.insert("350123", "福建省福州市罗源县");
-        map.insert("350124", "福建省福州市闽清县");
-        map.insert("350125", "福建省福州市永泰县");
-        map.insert("350128", "福建省福州市平潭县");
-        map.insert("350181", "福建省福州市福清市");
-        map.insert("350200", "福建省厦门市");
-        map.insert("350203", "福建省厦门市思明区");
-        map.insert("350205", "福建省厦门市海沧区");
-        map.insert("350206", "福建省厦门市湖里区");
-        map.insert("350211", "福建省厦门市集美区");
-        map.insert("350212", "福建省厦门市同安区");
-        map.insert("350213", "福建省厦门市翔安区");
-        map.insert("350300", "福建省莆田市");
-        map.insert("350302", "福建省莆田市城厢区");
-        map.insert("350303", "福建省莆田市涵江区");
-        map.insert("350304", "福建省莆田市荔城区");
-        map.insert("350305", "福建省莆田市秀屿区");
-        map.insert("350322", "福建省莆田市仙游县");
-        map.insert("350400", "福建省三明市");
-        map.insert("350404", "福建省三明市三元区");
-        map.insert("350405", "福建省三明市沙县区");
-        map.insert("350421", "福建省三明市明溪县");
-        map.insert("350423", "福建省三明市清流县");
-        map.insert("350424", "福建省三明市宁化县");
-        map.insert("350425", "福建省三明市大田县");
-        map.insert("350426", "福建省三明市尤溪县");
-        map.insert("350428", "福建省三明市将乐县");
-        map.insert("350429", "福建省三明市泰宁县");
-        map.insert("350430", "福建省三明市建宁县");
-        map.insert("350481", "福建省三明市永安市");
-        map.insert("350500", "福建省泉州市");
-        map.insert("350502", "福建省泉州市鲤城区");
-        map.insert("350503", "福建省泉州市丰泽区");
-        map.insert("350504", "福建省泉州市洛江区");
-        map.insert("350505", "福建省泉州市泉港区");
-        map.insert("350521", "福建省泉州市惠安县");
-        map.insert("350524", "福建省泉州市安溪县");
-        map.insert("350525", "福建省泉州市永春县");
-        map.insert("350526", "福建省泉州市德化县");
-        map.insert("350527", "福建省泉州市金门县");
-        map.insert("350581", "福建省泉州市石狮市");
-        map.insert("350582", "福建省泉州市晋江市");
-        map.insert("350583", "福建省泉州市南安市");
-        map.insert("350600", "福建省漳州市");
-        map.insert("350602", "福建省漳州市芗城区");
-        map.insert("350603", "福建省漳州市龙文区");
-        map.insert("350604", "福建省漳州市龙海区");
-        map.insert("350605", "福建省漳州市长泰区");
-        map.insert("350622", "福建省漳州市云霄县");
-        map.insert("350623", "福建省漳州市漳浦县");
-        map.insert("350624", "福建省漳州市诏安县");
-        map.insert("350626", "福建省漳州市东山县");
-        map.insert("350627", "福建省漳州市南靖县");
-        map.insert("350628", "福建省漳州市平和县");
-        map.insert("350629", "福建省漳州市华安县");
-        map.insert("350700", "福建省南平市");
-        map.insert("350702", "福建省南平市延平区");
-        map.insert("350703", "福建省南平市建阳区");
-        map.insert("350721", "福建省南平市顺昌县");
-        map.insert("350722", "福建省南平市浦城县");
-        map.insert("350723", "福建省南平市光泽县");
-        map.insert("350724", "福建省南平市松溪县");
-        map.insert("350725", "福建省南平市政和县");
-        map.insert("350781", "福建省南平市邵武市");
-        map.insert("350782", "福建省南平市武夷山市");
-        map.insert("350783", "福建省南平市建瓯市");
-        map.insert("350800", "福建省龙岩市");
-        map.insert("350802", "福建省龙岩市新罗区");
-        map.insert("350803", "福建省龙岩市永定区");
-        map.insert("350821", "福建省龙岩市长汀县");
-        map.insert("350823", "福建省龙岩市上杭县");
-        map.insert("350824", "福建省龙岩市武平县");
-        map.insert("350825", "福建省龙岩市连城县");
-        map.insert("350881", "福建省龙岩市漳平市");
-        map.insert("350900", "福建省宁德市");
-        map.insert("350902", "福建省宁德市蕉城区");
-        map.insert("350921", "福建省宁德市霞浦县");
-        map.insert("350922", "福建省宁德市古田县");
-        map.insert("350923", "福建省宁德市屏南县");
-        map.insert("350924", "福建省宁德市寿宁县");
-        map.insert("350925", "福建省宁德市周宁县");
-        map.insert("350926", "福建省宁德市柘荣县");
-        map.insert("350981", "福建省宁德市福安市");
-        map.insert("350982", "福建省宁德市福鼎市");
-        map.insert("360000", "江西省");
-        map.insert("360100", "江西省南昌市");
-        map.insert("360102", "江西省南昌市东湖区");
-        map.insert("360103", "江西省南昌市西湖区");
-        map.insert("360104", "江西省南昌市青云谱区");
-        map.insert("360111", "江西省南昌市青山湖区");
-        map.insert("360112", "江西省南昌市新建区");
-        map.insert("360113", "江西省南昌市红谷滩区");
-        map.insert("360121", "江西省南昌市南昌县");
-        map.insert("360123", "江西省南昌市安义县");
-        map.insert("360124", "江西省南昌市进贤县");
-        map.insert("360200", "江西省景德镇市");
-        map.insert("360202", "江西省景德镇市昌江区");
-        map.insert("360203", "江西省景德镇市珠山区");
-        map.insert("360222", "江西省景德镇市浮梁县");
-        map.insert("360281", "江西省景德镇市乐平市");
-        map.insert("360300", "江西省萍乡市");
-        map.insert("360302", "江西省萍乡市安源区");
-        map.insert("360313", "江西省萍乡市湘东区");
-        map.insert("360321", "江西省萍乡市莲花县");
-        map.insert("360322", "江西省萍乡市上栗县");
-        map.insert("360323", "江西省萍乡市芦溪县");
-        map.insert("360400", "江西省九江市");
-        map.insert("360402", "江西省九江市濂溪区");
-        map.insert("360403", "江西省九江市浔阳区");
-        map.insert("360404", "江西省九江市柴桑区");
-        map.insert("360423", "江西省九江市武宁县");
-        map.insert("360424", "江西省九江市修水县");
-        map.insert("360425", "江西省九江市永修县");
-        map.insert("360426", "江西省九江市德安县");
-        map.insert("360428", "江西省九江市都昌县");
-        map.insert("360429", "江西省九江市湖口县");
-        map.insert("360430", "江西省九江市彭泽县");
-        map.insert("360481", "江西省九江市瑞昌市");
-        map.insert("360482", "江西省九江市共青城市");
-        map.insert("360483", "江西省九江市庐山市");
-        map.insert("360500", "江西省新余市");
-        map.insert("360502", "江西省新余市渝水区");
-        map.insert("360521", "江西省新余市分宜县");
-        map.insert("360600", "江西省鹰潭市");
-        map.insert("360602", "江西省鹰潭市月湖区");
-        map.insert("360603", "江西省鹰潭市余江区");
-        map.insert("360681", "江西省鹰潭市贵溪市");
-        map.insert("360700", "江西省赣州市");
-        map.insert("360702", "江西省赣州市章贡区");
-        map.insert("360703", "江西省赣州市南康区");
-        map.insert("360704", "江西省赣州市赣县区");
-        map.insert("360722", "江西省赣州市信丰县");
-        map.insert("360723", "江西省赣州市大余县");
-        map.insert("360724", "江西省赣州市上犹县");
-        map.insert("360725", "江西省赣州市崇义县");
-        map.insert("360726", "江西省赣州市安远县");
-        map.insert("360728", "江西省赣州市定南县");
-        map.insert("360729", "江西省赣州市全南县");
-        map.insert("360730", "江西省赣州市宁都县");
-        map.insert("360731", "江西省赣州市于都县");
-        map.insert("360732", "江西省赣州市兴国县");
-        map.insert("360733", "江西省赣州市会昌县");
-        map.insert("360734", "江西省赣州市寻乌县");
-        map.insert("360735", "江西省赣州市石城县");
-        map.insert("360781", "江西省赣州市瑞金市");
-        map.insert("360783", "江西省赣州市龙南市");
-        map.insert("360800", "江西省吉安市");
-        map.insert("360802", "江西省吉安市吉州区");
-        map.insert("360803", "江西省吉安市青原区");
-        map.insert("360821", "江西省吉安市吉安县");
-        map.insert("360822", "江西省吉安市吉水县");
-        map.insert("360823", "江西省吉安市峡江县");
-        map.insert("360824", "江西省吉安市新干县");
-        map.insert("360825", "江西省吉安市永丰县");
-        map.insert("360826", "江西省吉安市泰和县");
-        map.insert("360827", "江西省吉安市遂川县");
-        map.insert("360828", "江西省吉安市万安县");
-        map.insert("360829", "江西省吉安市安福县");
-        map.insert("360830", "江西省吉安市永新县");
-        map.insert("360881", "江西省吉安市井冈山市");
-        map.insert("360900", "江西省宜春市");
-        map.insert("360902", "江西省宜春市袁州区");
-        map.insert("360921", "江西省宜春市奉新县");
-        map.insert("360922", "江西省宜春市万载县");
-        map.insert("360923", "江西省宜春市上高县");
-        map.insert("360924", "江西省宜春市宜丰县");
-        map.insert("360925", "江西省宜春市靖安县");
-        map.insert("360926", "江西省宜春市铜鼓县");
-        map.insert("360981", "江西省宜春市丰城市");
-        map.insert("360982", "江西省宜春市樟树市");
-        map.insert("360983", "江西省宜春市高安市");
-        map.insert("361000", "江西省抚州市");
-        map.insert("361002", "江西省抚州市临川区");
-        map.insert("361003", "江西省抚州市东乡区");
-        map.insert("361021", "江西省抚州市南城县");
-        map.insert("361022", "江西省抚州市黎川县");
-        map.insert("361023", "江西省抚州市南丰县");
-        map.insert("361024", "江西省抚州市崇仁县");
-        map.insert("361025", "江西省抚州市乐安县");
-        map.insert("361026", "江西省抚州市宜黄县");
-        map.insert("361027", "江西省抚州市金溪县");
-        map.insert("361028", "江西省抚州市资溪县");
-        map.insert("361030", "江西省抚州市广昌县");
-        map.insert("361100", "江西省上饶市");
-        map.insert("361102", "江西省上饶市信州区");
-        map.insert("361103", "江西省上饶市广丰区");
-        map.insert("361104", "江西省上饶市广信区");
-        map.insert("361123", "江西省上饶市玉山县");
-        map.insert("361124", "江西省上饶市铅山县");
-        map.insert("361125", "江西省上饶市横峰县");
-        map.insert("361126", "江西省上饶市弋阳县");
-        map.insert("361127", "江西省上饶市余干县");
-        map.insert("361128", "江西省上饶市鄱阳县");
-        map.insert("361129", "江西省上饶市万年县");
-        map.insert("361130", "江西省上饶市婺源县");
-        map.insert("361181", "江西省上饶市德兴市");
-        map.insert("370000", "山东省");
-        map.insert("370100", "山东省济南市");
-        map.insert("370102", "山东省济南市历下区");
-        map.insert("370103", "山东省济南市市中区");
-        map.insert("370104", "山东省济南市槐荫区");
-        map.insert("370105", "山东省济南市天桥区");
-        map.insert("370112", "山东省济南市历城区");
-        map.insert("370113", "山东省济南市长清区");
-        map.insert("370114", "山东省济南市章丘区");
-        map.insert("370115", "山东省济南市济阳区");
-        map.insert("370116", "山东省济南市莱芜区");
-        map.insert("370117", "山东省济南市钢城区");
-        map.insert("370124", "山东省济南市平阴县");
-        map.insert("370126", "山东省济南市商河县");
-        map.insert("370200", "山东省青岛市");
-        map.insert("370202", "山东省青岛市市南区");
-        map.insert("370203", "山东省青岛市市北区");
-        map.insert("370211", "山东省青岛市黄岛区");
-        map.insert("370212", "山东省青岛市崂山区");
-        map.insert("370213", "山东省青岛市李沧区");
-        map.insert("370214", "山东省青岛市城阳区");
-        map.insert("370215", "山东省青岛市即墨区");
-        map.insert("370281", "山东省青岛市胶州市");
-        map.insert("370283", "山东省青岛市平度市");
-        map.insert("370285", "山东省青岛市莱西市");
-        map.insert("370300", "山东省淄博市");
-        map.insert("370302", "山东省淄博市淄川区");
-        map.insert("370303", "山东省淄博市张店区");
-        map.insert("370304", "山东省淄博市博山区");
-        map.insert("370305", "山东省淄博市临淄区");
-        map.insert("370306", "山东省淄博市周村区");
-        map.insert("370321", "山东省淄博市桓台县");
-        map.insert("370322", "山东省淄博市高青县");
-        map.insert("370323", "山东省淄博市沂源县");
-        map.insert("370400", "山东省枣庄市");
-        map.insert("370402", "山东省枣庄市市中区");
-        map.insert("370403", "山东省枣庄市薛城区");
-        map.insert("370404", "山东省枣庄市峄城区");
-        map.insert("370405", "山东省枣庄市台儿庄区");
-        map.insert("370406", "山东省枣庄市山亭区");
-        map.insert("370481", "山东省枣庄市滕州市");
-        map.insert("370500", "山东省东营市");
-        map.insert("370502", "山东省东营市东营区");
-        map.insert("370503", "山东省东营市河口区");
-        map.insert("370505", "山东省东营市垦利区");
-        map.insert("370522", "山东省东营市利津县");
-        map.insert("370523", "山东省东营市广饶县");
-        map.insert("370600", "山东省烟台市");
-        map.insert("370602", "山东省烟台市芝罘区");
-        map.insert("370611", "山东省烟台市福山区");
-        map.insert("370612", "山东省烟台市牟平区");
-        map.insert("370613", "山东省烟台市莱山区");
-        map.insert("370614", "山东省烟台市蓬莱区");
-        map.insert("370681", "山东省烟台市龙口市");
-        map.insert("370682", "山东省烟台市莱阳市");
-        map.insert("370683", "山东省烟台市莱州市");
-        map.insert("370685", "山东省烟台市招远市");
-        map.insert("370686", "山东省烟台市栖霞市");
-        map.insert("370687", "山东省烟台市海阳市");
-        map.insert("370700", "山东省潍坊市");
-        map.insert("370702", "山东省潍坊市潍城区");
-        map.insert("370703", "山东省潍坊市寒亭区");
-        map.insert("370704", "山东省潍坊市坊子区");
-        map.insert("370705", "山东省潍坊市奎文区");
-        map.insert("370724", "山东省潍坊市临朐县");
-        map.insert("370725", "山东省潍坊市昌乐县");
-        map.insert("370781", "山东省潍坊市青州市");
-        map.insert("370782", "山东省潍坊市诸城市");
-        map.insert("370783", "山东省潍坊市寿光市");
-        map.insert("370784", "山东省潍坊市安丘市");
-        map.insert("370785", "山东省潍坊市高密市");
-        map.insert("370786", "山东省潍坊市昌邑市");
-        map.insert("370800", "山东省济宁市");
-        map.insert("370811", "山东省济宁市任城区");
-        map.insert("370812", "山东省济宁市兖州区");
-        map.insert("370826", "山东省济宁市微山县");
-        map.insert("370827", "山东省济宁市鱼台县");
-        map.insert("370828", "山东省济宁市金乡县");
-        map.insert("370829", "山东省济宁市嘉祥县");
-        map.insert("370830", "山东省济宁市汶上县");
-        map.insert("370831", "山东省济宁市泗水县");
-        map.insert("370832", "山东省济宁市梁山县");
-        map.insert("370881", "山东省济宁市曲阜市");
-        map.insert("370883", "山东省济宁市邹城市");
-        map.insert("370900", "山东省泰安市");
-        map.insert("370902", "山东省泰安市泰山区");
-        map.insert("370911", "山东省泰安市岱岳区");
-        map.insert("370921", "山东省泰安市宁阳县");
-        map.insert("370923", "山东省泰安市东平县");
-        map.insert("370982", "山东省泰安市新泰市");
-        map.insert("370983", "山东省泰安市肥城市");
-        map.insert("371000", "山东省威海市");
-        map.insert("371002", "山东省威海市环翠区");
-        map.insert("371003", "山东省威海市文登区");
-        map.insert("371082", "山东省威海市荣成市");
-        map.insert("371083", "山东省威海市乳山市");
-        map.insert("371100", "山东省日照市");
-        map.insert("371102", "山东省日照市东港区");
-        map.insert("371103", "山东省日照市岚山区");
-        map.insert("371121", "山东省日照市五莲县");
-        map.insert("371122", "山东省日照市莒县");
-        map.insert("371300", "山东省临沂市");
-        map.insert("371302", "山东省临沂市兰山区");
-        map.insert("371311", "山东省临沂市罗庄区");
-        map.insert("371312", "山东省临沂市河东区");
-        map.insert("371321", "山东省临沂市沂南县");
-        map.insert("371322", "山东省临沂市郯城县");
-        map.insert("371323", "山东省临沂市沂水县");
-        map.insert("371324", "山东省临沂市兰陵县");
-        map.insert("371325", "山东省临沂市费县");
-        map.insert("371326", "山东省临沂市平邑县");
-        map.insert("371327", "山东省临沂市莒南县");
-        map.insert("371328", "山东省临沂市蒙阴县");
-        map.insert("371329", "山东省临沂市临沭县");
-        map.insert("371400", "山东省德州市");
-        map.insert("371402", "山东省德州市德城区");
-        map.insert("371403", "山东省德州市陵城区");
-        map.insert("371422", "山东省德州市宁津县");
-        map.insert("371423", "山东省德州市庆云县");
-        map.insert("371424", "山东省德州市临邑县");
-        map.insert("371425", "山东省德州市齐河县");
-        map.insert("371426", "山东省德州市平原县");
-        map.insert("371427", "山东省德州市夏津县");
-        map.insert("371428", "山东省德州市武城县");
-        map.insert("371481", "山东省德州市乐陵市");
-        map.insert("371482", "山东省德州市禹城市");
-        map.insert("371500", "山东省聊城市");
-        map.insert("371502", "山东省聊城市东昌府区");
-        map.insert("371503", "山东省聊城市茌平区");
-        map.insert("371521", "山东省聊城市阳谷县");
-        map.insert("371522", "山东省聊城市莘县");
-        map.insert("371524", "山东省聊城市东阿县");
-        map.insert("371525", "山东省聊城市冠县");
-        map.insert("371526", "山东省聊城市高唐县");
-        map.insert("371581", "山东省聊城市临清市");
-        map.insert("371600", "山东省滨州市");
-        map.insert("371602", "山东省滨州市滨城区");
-        map.insert("371603", "山东省滨州市沾化区");
-        map.insert("371621", "山东省滨州市惠民县");
-        map.insert("371622", "山东省滨州市阳信县");
-        map.insert("371623", "山东省滨州市无棣县");
-        map.insert("371625", "山东省滨州市博兴县");
-        map.insert("371681", "山东省滨州市邹平市");
-        map.insert("371700", "山东省菏泽市");
-        map.insert("371702", "山东省菏泽市牡丹区");
-        map.insert("371703", "山东省菏泽市定陶区");
-        map.insert("371721", "山东省菏泽市曹县");
-        map.insert("371722", "山东省菏泽市单县");
-        map.insert("371723", "山东省菏泽市成武县");
-        map.insert("371724", "山东省菏泽市巨野县");
-        map.insert("371725", "山东省菏泽市郓城县");
-        map.insert("371726", "山东省菏泽市鄄城县");
-        map.insert("371728", "山东省菏泽市东明县");
-        map.insert("410000", "河南省");
-        map.insert("410100", "河南省郑州市");
-        map.insert("410102", "河南省郑州市中原区");
-        map.insert("410103", "河南省郑州市二七区");
-        map.insert("410104", "河南省郑州市管城回族区");
-        map.insert("410105", "河南省郑州市金水区");
-        map.insert("410106", "河南省郑州市上街区");
-        map.insert("410108", "河南省郑州市惠济区");
-        map.insert("410122", "河南省郑州市中牟县");
-        map.insert("410181", "河南省郑州市巩义市");
-        map.insert("410182", "河南省郑州市荥阳市");
-        map.insert("410183", "河南省郑州市新密市");
-        map.insert("410184", "河南省郑州市新郑市");
-        map.insert("410185", "河南省郑州市登封市");
-        map.insert("410200", "河南省开封市");
-        map.insert("410202", "河南省开封市龙亭区");
-        map.insert("410203", "河南省开封市顺河回族区");
-        map.insert("410204", "河南省开封市鼓楼区");
-        map.insert("410205", "河南省开封市禹王台区");
-        map.insert("410212", "河南省开封市祥符区");
-        map.insert("410221", "河南省开封市杞县");
-        map.insert("410222", "河南省开封市通许县");
-        map.insert("410223", "河南省开封市尉氏县");
-        map.insert("410225", "河南省开封市兰考县");
-        map.insert("410300", "河南省洛阳市");
-        map.insert("410302", "河南省洛阳市老城区");
-        map.insert("410303", "河南省洛阳市西工区");
-        map.insert("410304", "河南省洛阳市瀍河回族区");
-        map.insert("410305", "河南省洛阳市涧西区");
-        map.insert("410307", "河南省洛阳市偃师区");
-        map.insert("410308", "河南省洛阳市孟津区");
-        map.insert("410311", "河南省洛阳市洛龙区");
-        map.insert("410323", "河南省洛阳市新安县");
-        map.insert("410324", "河南省洛阳市栾川县");
-        map.insert("410325", "河南省洛阳市嵩县");
-        map.insert("410326", "河南省洛阳市汝阳县");
-        map.insert("410327", "河南省洛阳市宜阳县");
-        map.insert("410328", "河南省洛阳市洛宁县");
-        map.insert("410329", "河南省洛阳市伊川县");
-        map.insert("410400", "河南省平顶山市");
-        map.insert("410402", "河南省平顶山市新华区");
-        map.insert("410403", "河南省平顶山市卫东区");
-        map.insert("410404", "河南省平顶山市石龙区");
-        map.insert("410411", "河南省平顶山市湛河区");
-        map.insert("410421", "河南省平顶山市宝丰县");
-        map.insert("410422", "河南省平顶山市叶县");
-        map.insert("410423", "河南省平顶山市鲁山县");
-        map.insert("410425", "河南省平顶山市郏县");
-        map.insert("410481", "河南省平顶山市舞钢市");
-        map.insert("410482", "河南省平顶山市汝州市");
-        map.insert("410500", "河南省安阳市");
-        map.insert("410502", "河南省安阳市文峰区");
-        map.insert("410503", "河南省安阳市北关区");
-        map.insert("410505", "河南省安阳市殷都区");
-        map.insert("410506", "河南省安阳市龙安区");
-        map.insert("410522", "河南省安阳市安阳县");
-        map.insert("410523", "河南省安阳市汤阴县");
-        map.insert("410526", "河南省安阳市滑县");
-        map.insert("410527", "河南省安阳市内黄县");
-        map.insert("410581", "河南省安阳市林州市");
-        map.insert("410600", "河南省鹤壁市");
-        map.insert("410602", "河南省鹤壁市鹤山区");
-        map.insert("410603", "河南省鹤壁市山城区");
-        map.insert("410611", "河南省鹤壁市淇滨区");
-        map.insert("410621", "河南省鹤壁市浚县");
-        map.insert("410622", "河南省鹤壁市淇县");
-        map.insert("410700", "河南省新乡市");
-        map.insert("410702", "河南省新乡市红旗区");
-        map.insert("410703", "河南省新乡市卫滨区");
-        map.insert("410704", "河南省新乡市凤泉区");
-        map.insert("410711", "河南省新乡市牧野区");
-        map.insert("410721", "河南省新乡市新乡县");
-        map.insert("410724", "河南省新乡市获嘉县");
-        map.insert("410725", "河南省新乡市原阳县");
-        map.insert("410726", "河南省新乡市延津县");
-        map.insert("410727", "河南省新乡市封丘县");
-        map.insert("410781", "河南省新乡市卫辉市");
-        map.insert("410782", "河南省新乡市辉县市");
-        map.insert("410783", "河南省新乡市长垣市");
-        map.insert("410800", "河南省焦作市");
-        map.insert("410802", "河南省焦作市解放区");
-        map.insert("410803", "河南省焦作市中站区");
-        map.insert("410804", "河南省焦作市马村区");
-        map.insert("410811", "河南省焦作市山阳区");
-        map.insert("410821", "河南省焦作市修武县");
-        map.insert("410822", "河南省焦作市博爱县");
-        map.insert("410823", "河南省焦作市武陟县");
-        map.insert("410825", "河南省焦作市温县");
-        map.insert("410882", "河南省焦作市沁阳市");
-        map.insert("410883", "河南省焦作市孟州市");
-        map.insert("410900", "河南省濮阳市");
-        map.insert("410902", "河南省濮阳市华龙区");
-        map.insert("410922", "河南省濮阳市清丰县");
-        map.insert("410923", "河南省濮阳市南乐县");
-        map.insert("410926", "河南省濮阳市范县");
-        map.insert("410927", "河南省濮阳市台前县");
-        map.insert("410928", "河南省濮阳市濮阳县");
-        map.insert("411000", "河南省许昌市");
-        map.insert("411002", "河南省许昌市魏都区");
-        map.insert("411003", "河南省许昌市建安区");
-        map.insert("411024", "河南省许昌市鄢陵县");
-        map.insert("411025", "河南省许昌市襄城县");
-        map.insert("411081", "河南省许昌市禹州市");
-        map.insert("411082", "河南省许昌市长葛市");
-        map.insert("411100", "河南省漯河市");
-        map.insert("411102", "河南省漯河市源汇区");
-        map.insert("411103", "河南省漯河市郾城区");
-        map.insert("411104", "河南省漯河市召陵区");
-        map.insert("411121", "河南省漯河市舞阳县");
-        map.insert("411122", "河南省漯河市临颍县");
-        map.insert("411200", "河南省三门峡市");
-        map.insert("411202", "河南省三门峡市湖滨区");
-        map.insert("411203", "河南省三门峡市陕州区");
-        map.insert("411221", "河南省三门峡市渑池县");
-        map.insert("411224", "河南省三门峡市卢氏县");
-        map.insert("411281", "河南省三门峡市义马市");
-        map.insert("411282", "河南省三门峡市灵宝市");
-        map.insert("411300", "河南省南阳市");
-        map.insert("411302", "河南省南阳市宛城区");
-        map.insert("411303", "河南省南阳市卧龙区");
-        map.insert("411321", "河南省南阳市南召县");
-        map.insert("411322", "河南省南阳市方城县");
-        map.insert("411323", "河南省南阳市西峡县");
-        map.insert("411324", "河南省南阳市镇平县");
-        map.insert("411325", "河南省南阳市内乡县");
-        map.insert("411326", "河南省南阳市淅川县");
-        map.insert("411327", "河南省南阳市社旗县");
-        map.insert("411328", "河南省南阳市唐河县");
-        map.insert("411329", "河南省南阳市新野县");
-        map.insert("411330", "河南省南阳市桐柏县");
-        map.insert("411381", "河南省南阳市邓州市");
-        map.insert("411400", "河南省商丘市");
-        map.insert("411402", "河南省商丘市梁园区");
-        map.insert("411403", "河南省商丘市睢阳区");
-        map.insert("411421", "河南省商丘市民权县");
-        map.insert("411422", "河南省商丘市睢县");
-        map.insert("411423", "河南省商丘市宁陵县");
-        map.insert("411424", "河南省商丘市柘城县");
-        map.insert("411425", "河南省商丘市虞城县");
-        map.insert("411426", "河南省商丘市夏邑县");
-        map.insert("411481", "河南省商丘市永城市");
-        map.insert("411500", "河南省信阳市");
-        map.insert("411502", "河南省信阳市浉河区");
-        map.insert("411503", "河南省信阳市平桥区");
-        map.insert("411521", "河南省信阳市罗山县");
-        map.insert("411522", "河南省信阳市光山县");
-        map.insert("411523", "河南省信阳市新县");
-        map.insert("411524", "河南省信阳市商城县");
-        map.insert("411525", "河南省信阳市固始县");
-        map.insert("411526", "河南省信阳市潢川县");
-        map.insert("411527", "河南省信阳市淮滨县");
-        map.insert("411528", "河南省信阳市息县");
-        map.insert("411600", "河南省周口市");
-        map.insert("411602", "河南省周口市川汇区");
-        map.insert("411603", "河南省周口市淮阳区");
-        map.insert("411621", "河南省周口市扶沟县");
-        map.insert("411622", "河南省周口市西华县");
-        map.insert("411623", "河南省周口市商水县");
-        map.insert("411624", "河南省周口市沈丘县");
-        map.insert("411625", "河南省周口市郸城县");
-        map.insert("411627", "河南省周口市太康县");
-        map.insert("411628", "河南省周口市鹿邑县");
-        map.insert("411681", "河南省周口市项城市");
-        map.insert("411700", "河南省驻马店市");
-        map.insert("411702", "河南省驻马店市驿城区");
-        map.insert("411721", "河南省驻马店市西平县");
-        map.insert("411722", "河南省驻马店市上蔡县");
-        map.insert("411723", "河南省驻马店市平舆县");
-        map.insert("411724", "河南省驻马店市正阳县");
-        map.insert("411725", "河南省驻马店市确山县");
-        map.insert("411726", "河南省驻马店市泌阳县");
-        map.insert("411727", "河南省驻马店市汝南县");
-        map.insert("411728", "河南省驻马店市遂平县");
-        map.insert("411729", "河南省驻马店市新蔡县");
-        map.insert("419001", "河南省济源市");
-        map.insert("420000", "湖北省");
-        map.insert("420100", "湖北省武汉市");
-        map.insert("420102", "湖北省武汉市江岸区");
-        map.insert("420103", "湖北省武汉市江汉区");
-        map.insert("420104", "湖北省武汉市硚口区");
-        map.insert("420105", "湖北省武汉市汉阳区");
-        map.insert("420106", "湖北省武汉市武昌区");
-        map.insert("420107", "湖北省武汉市青山区");
-        map.insert("420111", "湖北省武汉市洪山区");
-        map.insert("420112", "湖北省武汉市东西湖区");
-        map.insert("420113", "湖北省武汉市汉南区");
-        map.insert("420114", "湖北省武汉市蔡甸区");
-        map.insert("420115", "湖北省武汉市江夏区");
-        map.insert("420116", "湖北省武汉市黄陂区");
-        map.insert("420117", "湖北省武汉市新洲区");
-        map.insert("420200", "湖北省黄石市");
-        map.insert("420202", "湖北省黄石市黄石港区");
-        map.insert("420203", "湖北省黄石市西塞山区");
-        map.insert("420204", "湖北省黄石市下陆区");
-        map.insert("420205", "湖北省黄石市铁山区");
-        map.insert("420222", "湖北省黄石市阳新县");
-        map.insert("420281", "湖北省黄石市大冶市");
-        map.insert("420300", "湖北省十堰市");
-        map.insert("420302", "湖北省十堰市茅箭区");
-        map.insert("420303", "湖北省十堰市张湾区");
-        map.insert("420304", "湖北省十堰市郧阳区");
-        map.insert("420322", "湖北省十堰市郧西县");
-        map.insert("420323", "湖北省十堰市竹山县");
-        map.insert("420324", "湖北省十堰市竹溪县");
-        map.insert("420325", "湖北省十堰市房县");
-        map.insert("420381", "湖北省十堰市丹江口市");
-        map.insert("420500", "湖北省宜昌市");
-        map.insert("420502", "湖北省宜昌市西陵区");
-        map.insert("420503", "湖北省宜昌市伍家岗区");
-        map.insert("420504", "湖北省宜昌市点军区");
-        map.insert("420505", "湖北省宜昌市猇亭区");
-        map.insert("420506", "湖北省宜昌市夷陵区");
-        map.insert("420525", "湖北省宜昌市远安县");
-        map.insert("420526", "湖北省宜昌市兴山县");
-        map.insert("420527", "湖北省宜昌市秭归县");
-        map.insert("420528", "湖北省宜昌市长阳土家族自治县");
-        map.insert("420529", "湖北省宜昌市五峰土家族自治县");
-        map.insert("420581", "湖北省宜昌市宜都市");
-        map.insert("420582", "湖北省宜昌市当阳市");
-        map.insert("420583", "湖北省宜昌市枝江市");
-        map.insert("420600", "湖北省襄阳市");
-        map.insert("420602", "湖北省襄阳市襄城区");
-        map.insert("420606", "湖北省襄阳市樊城区");
-        map.insert("420607", "湖北省襄阳市襄州区");
-        map.insert("420624", "湖北省襄阳市南漳县");
-        map.insert("420625", "湖北省襄阳市谷城县");
-        map.insert("420626", "湖北省襄阳市保康县");
-        map.insert("420682", "湖北省襄阳市老河口市");
-        map.insert("420683", "湖北省襄阳市枣阳市");
-        map.insert("420684", "湖北省襄阳市宜城市");
-        map.insert("420700", "湖北省鄂州市");
-        map.insert("420702", "湖北省鄂州市梁子湖区");
-        map.insert("420703", "湖北省鄂州市华容区");
-        map.insert("420704", "湖北省鄂州市鄂城区");
-        map.insert("420800", "湖北省荆门市");
-        map.insert("420802", "湖北省荆门市东宝区");
-        map.insert("420804", "湖北省荆门市掇刀区");
-        map.insert("420822", "湖北省荆门市沙洋县");
-        map.insert("420881", "湖北省荆门市钟祥市");
-        map.insert("420882", "湖北省荆门市京山市");
-        map.insert("420900", "湖北省孝感市");
-        map.insert("420902", "湖北省孝感市孝南区");
-        map.insert("420921", "湖北省孝感市孝昌县");
-        map.insert("420922", "湖北省孝感市大悟县");
-        map.insert("420923", "湖北省孝感市云梦县");
-        map.insert("420981", "湖北省孝感市应城市");
-        map.insert("420982", "湖北省孝感市安陆市");
-        map.insert("420984", "湖北省孝感市汉川市");
-        map.insert("421000", "湖北省荆州市");
-        map.insert("421002", "湖北省荆州市沙市区");
-        map.insert("421003", "湖北省荆州市荆州区");
-        map.insert("421022", "湖北省荆州市公安县");
-        map.insert("421024", "湖北省荆州市江陵县");
-        map.insert("421081", "湖北省荆州市石首市");
-        map.insert("421083", "湖北省荆州市洪湖市");
-        map.insert("421087", "湖北省荆州市松滋市");
-        map.insert("421088", "湖北省荆州市监利市");
-        map.insert("421100", "湖北省黄冈市");
-        map.insert("421102", "湖北省黄冈市黄州区");
-        map.insert("421121", "湖北省黄冈市团风县");
-        map.insert("421122", "湖北省黄冈市红安县");
-        map.insert("421123", "湖北省黄冈市罗田县");
-        map.insert("421124", "湖北省黄冈市英山县");
-        map.insert("421125", "湖北省黄冈市浠水县");
-        map.insert("421126", "湖北省黄冈市蕲春县");
-        map.insert("421127", "湖北省黄冈市黄梅县");
-        map.insert("421181", "湖北省黄冈市麻城市");
-        map.insert("421182", "湖北省黄冈市武穴市");
-        map.insert("421200", "湖北省咸宁市");
-        map.insert("421202", "湖北省咸宁市咸安区");
-        map.insert("421221", "湖北省咸宁市嘉鱼县");
-        map.insert("421222", "湖北省咸宁市通城县");
-        map.insert("421223", "湖北省咸宁市崇阳县");
-        map.insert("421224", "湖北省咸宁市通山县");
-        map.insert("421281", "湖北省咸宁市赤壁市");
-        map.insert("421300", "湖北省随州市");
-        map.insert("421303", "湖北省随州市曾都区");
-        map.insert("421321", "湖北省随州市随县");
-        map.insert("421381", "湖北省随州市广水市");
-        map.insert("422800", "湖北省恩施土家族苗族自治州");
-        map.insert("422801", "湖北省恩施土家族苗族自治州恩施市");
-        map.insert("422802", "湖北省恩施土家族苗族自治州利川市");
-        map.insert("422822", "湖北省恩施土家族苗族自治州建始县");
-        map.insert("422823", "湖北省恩施土家族苗族自治州巴东县");
-        map.insert("422825", "湖北省恩施土家族苗族自治州宣恩县");
-        map.insert("422826", "湖北省恩施土家族苗族自治州咸丰县");
-        map.insert("422827", "湖北省恩施土家族苗族自治州来凤县");
-        map.insert("422828", "湖北省恩施土家族苗族自治州鹤峰县");
-        map.insert("429004", "湖北省仙桃市");
-        map.insert("429005", "湖北省潜江市");
-        map.insert("429006", "湖北省天门市");
-        map.insert("429021", "湖北省神农架林区");
-        map.insert("430000", "湖南省");
-        map.insert("430100", "湖南省长沙市");
-        map.insert("430102", "湖南省长沙市芙蓉区");
-        map.insert("430103", "湖南省长沙市天心区");
-        map.insert("430104", "湖南省长沙市岳麓区");
-        map.insert("430105", "湖南省长沙市开福区");
-        map.insert("430111", "湖南省长沙市雨花区");
-        map.insert("430112", "湖南省长沙市望城区");
-        map.insert("430121", "湖南省长沙市长沙县");
-        map.insert("430181", "湖南省长沙市浏阳市");
-        map.insert("430182", "湖南省长沙市宁乡市");
-        map.insert("430200", "湖南省株洲市");
-        map.insert("430202", "湖南省株洲市荷塘区");
-        map.insert("430203", "湖南省株洲市芦淞区");
-        map.insert("430204", "湖南省株洲市石峰区");
-        map.insert("430211", "湖南省株洲市天元区");
-        map.insert("430212", "湖南省株洲市渌口区");
-        map.insert("430223", "湖南省株洲市攸县");
-        map.insert("430224", "湖南省株洲市茶陵县");
-        map.insert("430225", "湖南省株洲市炎陵县");
-        map.insert("430281", "湖南省株洲市醴陵市");
-        map.insert("430300", "湖南省湘潭市");
-        map.insert("430302", "湖南省湘潭市雨湖区");
-        map.insert("430304", "湖南省湘潭市岳塘区");
-        map.insert("430321", "湖南省湘潭市湘潭县");
-        map.insert("430381", "湖南省湘潭市湘乡市");
-        map.insert("430382", "湖南省湘潭市韶山市");
-        map.insert("430400", "湖南省衡阳市");
-        map.insert("430405", "湖南省衡阳市珠晖区");
-        map.insert("430406", "湖南省衡阳市雁峰区");
-        map.insert("430407", "湖南省衡阳市石鼓区");
-        map.insert("430408", "湖南省衡阳市蒸湘区");
-        map.insert("430412", "湖南省衡阳市南岳区");
-        map.insert("430421", "湖南省衡阳市衡阳县");
-        map.insert("430422", "湖南省衡阳市衡南县");
-        map.insert("430423", "湖南省衡阳市衡山县");
-        map.insert("430424", "湖南省衡阳市衡东县");
-        map.insert("430426", "湖南省衡阳市祁东县");
-        map.insert("430481", "湖南省衡阳市耒阳市");
-        map.insert("430482", "湖南省衡阳市常宁市");
-        map.insert("430500", "湖南省邵阳市");
-        map.insert("430502", "湖南省邵阳市双清区");
-        map.insert("430503", "湖南省邵阳市大祥区");
-        map.insert("430511", "湖南省邵阳市北塔区");
-        map.insert("430522", "湖南省邵阳市新邵县");
-        map.insert("430523", "湖南省邵阳市邵阳县");
-        map.insert("430524", "湖南省邵阳市隆回县");
-        map.insert("430525", "湖南省邵阳市洞口县");
-        map.insert("430527", "湖南省邵阳市绥宁县");
-        map.insert("430528", "湖南省邵阳市新宁县");
-        map.insert("430529", "湖南省邵阳市城步苗族自治县");
-        map.insert("430581", "湖南省邵阳市武冈市");
-        map.insert("430582", "湖南省邵阳市邵东市");
-        map.insert("430600", "湖南省岳阳市");
-        map.insert("430602", "湖南省岳阳市岳阳楼区");
-        map.insert("430603", "湖南省岳阳市云溪区");
-        map.insert("430611", "湖南省岳阳市君山区");
-        map.insert("430621", "湖南省岳阳市岳阳县");
-        map.insert("430623", "湖南省岳阳市华容县");
-        map.insert("430624", "湖南省岳阳市湘阴县");
-        map.insert("430626", "湖南省岳阳市平江县");
-        map.insert("430681", "湖南省岳阳市汨罗市");
-        map.insert("430682", "湖南省岳阳市临湘市");
-        map.insert("430700", "湖南省常德市");
-        map.insert("430702", "湖南省常德市武陵区");
-        map.insert("430703", "湖南省常德市鼎城区");
-        map.insert("430721", "湖南省常德市安乡县");
-        map.insert("430722", "湖南省常德市汉寿县");
-        map.insert("430723", "湖南省常德市澧县");
-        map.insert("430724", "湖南省常德市临澧县");
-        map.insert("430725", "湖南省常德市桃源县");
-        map.insert("430726", "湖南省常德市石门县");
-        map.insert("430781", "湖南省常德市津市市");
-        map.insert("430800", "湖南省张家界市");
-        map.insert("430802", "湖南省张家界市永定区");
-        map.insert("430811", "湖南省张家界市武陵源区");
-        map.insert("430821", "湖南省张家界市慈利县");
-        map.insert("430822", "湖南省张家界市桑植县");
-        map.insert("430900", "湖南省益阳市");
-        map.insert("430902", "湖南省益阳市资阳区");
-        map.insert("430903", "湖南省益阳市赫山区");
-        map.insert("430921", "湖南省益阳市南县");
-        map.insert("430922", "湖南省益阳市桃江县");
-        map.insert("430923", "湖南省益阳市安化县");
-        map.insert("430981", "湖南省益阳市沅江市");
-        map.insert("431000", "湖南省郴州市");
-        map.insert("431002", "湖南省郴州市北湖区");
-        map.insert("431003", "湖南省郴州市苏仙区");
-        map.insert("431021", "湖南省郴州市桂阳县");
-        map.insert("431022", "湖南省郴州市宜章县");
-        map.insert("431023", "湖南省郴州市永兴县");
-        map.insert("431024", "湖南省郴州市嘉禾县");
-        map.insert("431025", "湖南省郴州市临武县");
-        map.insert("431026", "湖南省郴州市汝城县");
-        map.insert("431027", "湖南省郴州市桂东县");
-        map.insert("431028", "湖南省郴州市安仁县");
-        map.insert("431081", "湖南省郴州市资兴市");
-        map.insert("431100", "湖南省永州市");
-        map.insert("431102", "湖南省永州市零陵区");
-        map.insert("431103", "湖南省永州市冷水滩区");
-        map.insert("431122", "湖南省永州市东安县");
-        map.insert("431123", "湖南省永州市双牌县");
-        map.insert("431124", "湖南省永州市道县");
-        map.insert("431125", "湖南省永州市江永县");
-        map.insert("431126", "湖南省永州市宁远县");
-        map.insert("431127", "湖南省永州市蓝山县");
-        map.insert("431128", "湖南省永州市新田县");
-        map.insert("431129", "湖南省永州市江华瑶族自治县");
-        map.insert("431181", "湖南省永州市祁阳市");
-        map.insert("431200", "湖南省怀化市");
-        map.insert("431202", "湖南省怀化市鹤城区");
-        map.insert("431221", "湖南省怀化市中方县");
-        map.insert("431222", "湖南省怀化市沅陵县");
-        map.insert("431223", "湖南省怀化市辰溪县");
-        map.insert("431224", "湖南省怀化市溆浦县");
-        map.insert("431225", "湖南省怀化市会同县");
-        map.insert("431226", "湖南省怀化市麻阳苗族自治县");
-        map.insert("431227", "湖南省怀化市新晃侗族自治县");
-        map.insert("431228", "湖南省怀化市芷江侗族自治县");
-        map.insert("431229", "湖南省怀化市靖州苗族侗族自治县");
-        map.insert("431230", "湖南省怀化市通道侗族自治县");
-        map.insert("431281", "湖南省怀化市洪江市");
-        map.insert("431300", "湖南省娄底市");
-        map.insert("431302", "湖南省娄底市娄星区");
-        map.insert("431321", "湖南省娄底市双峰县");
-        map.insert("431322", "湖南省娄底市新化县");
-        map.insert("431381", "湖南省娄底市冷水江市");
-        map.insert("431382", "湖南省娄底市涟源市");
-        map.insert("433100", "湖南省湘西土家族苗族自治州");
-        map.insert("433101", "湖南省湘西土家族苗族自治州吉首市");
-        map.insert("433122", "湖南省湘西土家族苗族自治州泸溪县");
-        map.insert("433123", "湖南省湘西土家族苗族自治州凤凰县");
-        map.insert("433124", "湖南省湘西土家族苗族自治州花垣县");
-        map.insert("433125", "湖南省湘西土家族苗族自治州保靖县");
-        map.insert("433126", "湖南省湘西土家族苗族自治州古丈县");
-        map.insert("433127", "湖南省湘西土家族苗族自治州永顺县");
-        map.insert("433130", "湖南省湘西土家族苗族自治州龙山县");
-        map.insert("440000", "广东省");
-        map.insert("440100", "广东省广州市");
-        map.insert("440103", "广东省广州市荔湾区");
-        map.insert("440104", "广东省广州市越秀区");
-        map.insert("440105", "广东省广州市海珠区");
-        map.insert("440106", "广东省广州市天河区");
-        map.insert("440111", "广东省广州市白云区");
-        map.insert("440112", "广东省广州市黄埔区");
-        map.insert("440113", "广东省广州市番禺区");
-        map.insert("440114", "广东省广州市花都区");
-        map.insert("440115", "广东省广州市南沙区");
-        map.insert("440117", "广东省广州市从化区");
-        map.insert("440118", "广东省广州市增城区");
-        map.insert("440200", "广东省韶关市");
-        map.insert("440203", "广东省韶关市武江区");
-        map.insert("440204", "广东省韶关市浈江区");
-        map.insert("440205", "广东省韶关市曲江区");
-        map.insert("440222", "广东省韶关市始兴县");
-        map.insert("440224", "广东省韶关市仁化县");
-        map.insert("440229", "广东省韶关市翁源县");
-        map.insert("440232", "广东省韶关市乳源瑶族自治县");
-        map.insert("440233", "广东省韶关市新丰县");
-        map.insert("440281", "广东省韶关市乐昌市");
-        map.insert("440282", "广东省韶关市南雄市");
-        map.insert("440300", "广东省深圳市");
-        map.insert("440303", "广东省深圳市罗湖区");
-        map.insert("440304", "广东省深圳市福田区");
-        map.insert("440305", "广东省深圳市南山区");
-        map.insert("440306", "广东省深圳市宝安区");
-        map.insert("440307", "广东省深圳市龙岗区");
-        map.insert("440308", "广东省深圳市盐田区");
-        map.insert("440309", "广东省深圳市龙华区");
-        map.insert("440310", "广东省深圳市坪山区");
-        map.insert("440311", "广东省深圳市光明区");
-        map.insert("440400", "广东省珠海市");
-        map.insert("440402", "广东省珠海市香洲区");
-        map.insert("440403", "广东省珠海市斗门区");
-        map.insert("440404", "广东省珠海市金湾区");
-        map.insert("440500", "广东省汕头市");
-        map.insert("440507", "广东省汕头市龙湖区");
-        map.insert("440511", "广东省汕头市金平区");
-        map.insert("440512", "广东省汕头市濠江区");
-        map.insert("440513", "广东省汕头市潮阳区");
-        map.insert("440514", "广东省汕头市潮南区");
-        map.insert("440515", "广东省汕头市澄海区");
-        map.insert("440523", "广东省汕头市南澳县");
-        map.insert("440600", "广东省佛山市");
-        map.insert("440604", "广东省佛山市禅城区");
-        map.insert("440605", "广东省佛山市南海区");
-        map.insert("440606", "广东省佛山市顺德区");
-        map.insert("440607", "广东省佛山市三水区");
-        map.insert("440608", "广东省佛山市高明区");
-        map.insert("440700", "广东省江门市");
-        map.insert("440703", "广东省江门市蓬江区");
-        map.insert("440704", "广东省江门市江海区");
-        map.insert("440705", "广东省江门市新会区");
-        map.insert("440781", "广东省江门市台山市");
-        map.insert("440783", "广东省江门市开平市");
-        map.insert("440784", "广东省江门市鹤山市");
-        map.insert("440785", "广东省江门市恩平市");
-        map.insert("440800", "广东省湛江市");
-        map.insert("440802", "广东省湛江市赤坎区");
-        map.insert("440803", "广东省湛江市霞山区");
-        map.insert("440804", "广东省湛江市坡头区");
-        map.insert("440811", "广东省湛江市麻章区");
-        map.insert("440823", "广东省湛江市遂溪县");
-        map.insert("440825", "广东省湛江市徐闻县");
-        map.insert("440881", "广东省湛江市廉江市");
-        map.insert("440882", "广东省湛江市雷州市");
-        map.insert("440883", "广东省湛江市吴川市");
-        map.insert("440900", "广东省茂名市");
-        map.insert("440902", "广东省茂名市茂南区");
-        map.insert("440904", "广东省茂名市电白区");
-        map.insert("440981", "广东省茂名市高州市");
-        map.insert("440982", "广东省茂名市化州市");
-        map.insert("440983", "广东省茂名市信宜市");
-        map.insert("441200", "广东省肇庆市");
-        map.insert("441202", "广东省肇庆市端州区");
-        map.insert("441203", "广东省肇庆市鼎湖区");
-        map.insert("441204", "广东省肇庆市高要区");
-        map.insert("441223", "广东省肇庆市广宁县");
-        map.insert("441224", "广东省肇庆市怀集县");
-        map.insert("441225", "广东省肇庆市封开县");
-        map.insert("441226", "广东省肇庆市德庆县");
-        map.insert("441284", "广东省肇庆市四会市");
-        map.insert("441300", "广东省惠州市");
-        map.insert("441302", "广东省惠州市惠城区");
-        map.insert("441303", "广东省惠州市惠阳区");
-        map.insert("441322", "广东省惠州市博罗县");
-        map.insert("441323", "广东省惠州市惠东县");
-        map.insert("441324", "广东省惠州市龙门县");
-        map.insert("441400", "广东省梅州市");
-        map.insert("441402", "广东省梅州市梅江区");
-        map.insert("441403", "广东省梅州市梅县区");
-        map.insert("441422", "广东省梅州市大埔县");
-        map.insert("441423", "广东省梅州市丰顺县");
-        map.insert("441424", "广东省梅州市五华县");
-        map.insert("441426", "广东省梅州市平远县");
-        map.insert("441427", "广东省梅州市蕉岭县");
-        map.insert("441481", "广东省梅州市兴宁市");
-        map.insert("441500", "广东省汕尾市");
-        map.insert("441502", "广东省汕尾市城区");
-        map.insert("441521", "广东省汕尾市海丰县");
-        map.insert("441523", "广东省汕尾市陆河县");
-        map.insert("441581", "广东省汕尾市陆丰市");
-        map.insert("441600", "广东省河源市");
-        map.insert("441602", "广东省河源市源城区");
-        map.insert("441621", "广东省河源市紫金县");
-        map.insert("441622", "广东省河源市龙川县");
-        map.insert("441623", "广东省河源市连平县");
-        map.insert("441624", "广东省河源市和平县");
-        map.insert("441625", "广东省河源市东源县");
-        map.insert("441700", "广东省阳江市");
-        map.insert("441702", "广东省阳江市江城区");
-        map.insert("441704", "广东省阳江市阳东区");
-        map.insert("441721", "广东省阳江市阳西县");
-        map.insert("441781", "广东省阳江市阳春市");
-        map.insert("441800", "广东省清远市");
-        map.insert("441802", "广东省清远市清城区");
-        map.insert("441803", "广东省清远市清新区");
-        map.insert("441821", "广东省清远市佛冈县");
-        map.insert("441823", "广东省清远市阳山县");
-        map.insert("441825", "广东省清远市连山壮族瑶族自治县");
-        map.insert("441826", "广东省清远市连南瑶族自治县");
-        map.insert("441881", "广东省清远市英德市");
-        map.insert("441882", "广东省清远市连州市");
-        map.insert("441900", "广东省东莞市");
-        map.insert("442000", "广东省中山市");
-        map.insert("445100", "广东省潮州市");
-        map.insert("445102", "广东省潮州市湘桥区");
-        map.insert("445103", "广东省潮州市潮安区");
-        map.insert("445122", "广东省潮州市饶平县");
-        map.insert("445200", "广东省揭阳市");
-        map.insert("445202", "广东省揭阳市榕城区");
-        map.insert("445203", "广东省揭阳市揭东区");
-        map.insert("445222", "广东省揭阳市揭西县");
-        map.insert("445224", "广东省揭阳市惠来县");
-        map.insert("445281", "广东省揭阳市普宁市");
-        map.insert("445300", "广东省云浮市");
-        map.insert("445302", "广东省云浮市云城区");
-        map.insert("445303", "广东省云浮市云安区");
-        map.insert("445321", "广东省云浮市新兴县");
-        map.insert("445322", "广东省云浮市郁南县");
-        map.insert("445381", "广东省云浮市罗定市");
-        map.insert("450000", "广西壮族自治区");
-        map.insert("450100", "广西壮族自治区南宁市");
-        map.insert("450102", "广西壮族自治区南宁市兴宁区");
-        map.insert("450103", "广西壮族自治区南宁市青秀区");
-        map.insert("450105", "广西壮族自治区南宁市江南区");
-        map.insert("450107", "广西壮族自治区南宁市西乡塘区");
-        map.insert("450108", "广西壮族自治区南宁市良庆区");
-        map.insert("450109", "广西壮族自治区南宁市邕宁区");
-        map.insert("450110", "广西壮族自治区南宁市武鸣区");
-        map.insert("450123", "广西壮族自治区南宁市隆安县");
-        map.insert("450124", "广西壮族自治区南宁市马山县");
-        map.insert("450125", "广西壮族自治区南宁市上林县");
-        map.insert("450126", "广西壮族自治区南宁市宾阳县");
-        map.insert("450181", "广西壮族自治区南宁市横州市");
-        map.insert("450200", "广西壮族自治区柳州市");
-        map.insert("450202", "广西壮族自治区柳州市城中区");
-        map.insert("450203", "广西壮族自治区柳州市鱼峰区");
-        map.insert("450204", "广西壮族自治区柳州市柳南区");
-        map.insert("450205", "广西壮族自治区柳州市柳北区");
-        map.insert("450206", "广西壮族自治区柳州市柳江区");
-        map.insert("450222", "广西壮族自治区柳州市柳城县");
-        map.insert("450223", "广西壮族自治区柳州市鹿寨县");
-        map.insert("450224", "广西壮族自治区柳州市融安县");
-        map.insert("450225", "广西壮族自治区柳州市融水苗族自治县");
-        map.insert("450226", "广西壮族自治区柳州市三江侗族自治县");
-        map.insert("450300", "广西壮族自治区桂林市");
-        map.insert("450302", "广西壮族自治区桂林市秀峰区");
-        map.insert("450303", "广西壮族自治区桂林市叠彩区");
-        map.insert("450304", "广西壮族自治区桂林市象山区");
-        map.insert("450305", "广西壮族自治区桂林市七星区");
-        map.insert("450311", "广西壮族自治区桂林市雁山区");
-        map.insert("450312", "广西壮族自治区桂林市临桂区");
-        map.insert("450321", "广西壮族自治区桂林市阳朔县");
-        map.insert("450323", "广西壮族自治区桂林市灵川县");
-        map.insert("450324", "广西壮族自治区桂林市全州县");
-        map.insert("450325", "广西壮族自治区桂林市兴安县");
-        map.insert("450326", "广西壮族自治区桂林市永福县");
-        map.insert("450327", "广西壮族自治区桂林市灌阳县");
-        map.insert("450328", "广西壮族自治区桂林市龙胜各族自治县");
-        map.insert("450329", "广西壮族自治区桂林市资源县");
-        map.insert("450330", "广西壮族自治区桂林市平乐县");
-        map.insert("450332", "广西壮族自治区桂林市恭城瑶族自治县");
-        map.insert("450381", "广西壮族自治区桂林市荔浦市");
-        map.insert("450400", "广西壮族自治区梧州市");
-        map.insert("450403", "广西壮族自治区梧州市万秀区");
-        map.insert("450405", "广西壮族自治区梧州市长洲区");
-        map.insert("450406", "广西壮族自治区梧州市龙圩区");
-        map.insert("450421", "广西壮族自治区梧州市苍梧县");
-        map.insert("450422", "广西壮族自治区梧州市藤县");
-        map.insert("450423", "广西壮族自治区梧州市蒙山县");
-        map.insert("450481", "广西壮族自治区梧州市岑溪市");
-        map.insert("450500", "广西壮族自治区北海市");
-        map.insert("450502", "广西壮族自治区北海市海城区");
-        map.insert("450503", "广西壮族自治区北海市银海区");
-        map.insert("450512", "广西壮族自治区北海市铁山港区");
-        map.insert("450521", "广西壮族自治区北海市合浦县");
-        map.insert("450600", "广西壮族自治区防城港市");
-        map.insert("450602", "广西壮族自治区防城港市港口区");
-        map.insert("450603", "广西壮族自治区防城港市防城区");
-        map.insert("450621", "广西壮族自治区防城港市上思县");
-        map.insert("450681", "广西壮族自治区防城港市东兴市");
-        map.insert("450700", "广西壮族自治区钦州市");
-        map.insert("450702", "广西壮族自治区钦州市钦南区");
-        map.insert("450703", "广西壮族自治区钦州市钦北区");
-        map.insert("450721", "广西壮族自治区钦州市灵山县");
-        map.insert("450722", "广西壮族自治区钦州市浦北县");
-        map.insert("450800", "广西壮族自治区贵港市");
-        map.insert("450802", "广西壮族自治区贵港市港北区");
-        map.insert("450803", "广西壮族自治区贵港市港南区");
-        map.insert("450804", "广西壮族自治区贵港市覃塘区");
-        map.insert("450821", "广西壮族自治区贵港市平南县");
-        map.insert("450881", "广西壮族自治区贵港市桂平市");
-        map.insert("450900", "广西壮族自治区玉林市");
-        map.insert("450902", "广西壮族自治区玉林市玉州区");
-        map.insert("450903", "广西壮族自治区玉林市福绵区");
-        map.insert("450921", "广西壮族自治区玉林市容县");
-        map.insert("450922", "广西壮族自治区玉林市陆川县");
-        map.insert("450923", "广西壮族自治区玉林市博白县");
-        map.insert("450924", "广西壮族自治区玉林市兴业县");
-        map.insert("450981", "广西壮族自治区玉林市北流市");
-        map.insert("451000", "广西壮族自治区百色市");
-        map.insert("451002", "广西壮族自治区百色市右江区");
-        map.insert("451003", "广西壮族自治区百色市田阳区");
-        map.insert("451022", "广西壮族自治区百色市田东县");
-        map.insert("451024", "广西壮族自治区百色市德保县");
-        map.insert("451026", "广西壮族自治区百色市那坡县");
-        map.insert("451027", "广西壮族自治区百色市凌云县");
-        map.insert("451028", "广西壮族自治区百色市乐业县");
-        map.insert("451029", "广西壮族自治区百色市田林县");
-        map.insert("451030", "广西壮族自治区百色市西林县");
-        map.insert("451031", "广西壮族自治区百色市隆林各族自治县");
-        map.insert("451081", "广西壮族自治区百色市靖西市");
-        map.insert("451082", "广西壮族自治区百色市平果市");
-        map.insert("451100", "广西壮族自治区贺州市");
-        map.insert("451102", "广西壮族自治区贺州市八步区");
-        map.insert("451103", "广西壮族自治区贺州市平桂区");
-        map.insert("451121", "广西壮族自治区贺州市昭平县");
-        map.insert("451122", "广西壮族自治区贺州市钟山县");
-        map.insert("451123", "广西壮族自治区贺州市富川瑶族自治县");
-        map.insert("451200", "广西壮族自治区河池市");
-        map.insert("451202", "广西壮族自治区河池市金城江区");
-        map.insert("451203", "广西壮族自治区河池市宜州区");
-        map.insert("451221", "广西壮族自治区河池市南丹县");
-        map.insert("451222", "广西壮族自治区河池市天峨县");
-        map.insert("451223", "广西壮族自治区河池市凤山县");
-        map.insert("451224", "广西壮族自治区河池市东兰县");
-        map.insert("451225", "广西壮族自治区河池市罗城仫佬族自治县");
-        map.insert("451226", "广西壮族自治区河池市环江毛南族自治县");
-        map.insert("451227", "广西壮族自治区河池市巴马瑶族自治县");
-        map.insert("451228", "广西壮族自治区河池市都安瑶族自治县");
-        map.insert("451229", "广西壮族自治区河池市大化瑶族自治县");
-        map.insert("451300", "广西壮族自治区来宾市");
-        map.insert("451302", "广西壮族自治区来宾市兴宾区");
-        map.insert("451321", "广西壮族自治区来宾市忻城县");
-        map.insert("451322", "广西壮族自治区来宾市象州县");
-        map.insert("451323", "广西壮族自治区来宾市武宣县");
-        map.insert("451324", "广西壮族自治区来宾市金秀瑶族自治县");
-        map.insert("451381", "广西壮族自治区来宾市合山市");
-        map.insert("451400", "广西壮族自治区崇左市");
-        map.insert("451402", "广西壮族自治区崇左市江州区");
-        map.insert("451421", "广西壮族自治区崇左市扶绥县");
-        map.insert("451422", "广西壮族自治区崇左市宁明县");
-        map.insert("451423", "广西壮族自治区崇左市龙州县");
-        map.insert("451424", "广西壮族自治区崇左市大新县");
-        map.insert("451425", "广西壮族自治区崇左市天等县");
-        map.insert("451481", "广西壮族自治区崇左市凭祥市");
-        map.insert("460000", "海南省");
-        map.insert("460100", "海南省海口市");
-        map.insert("460105", "海南省海口市秀英区");
-        map.insert("460106", "海南省海口市龙华区");
-        map.insert("460107", "海南省海口市琼山区");
-        map.insert("460108", "海南省海口市美兰区");
-        map.insert("460200", "海南省三亚市");
-        map.insert("460202", "海南省三亚市海棠区");
-        map.insert("460203", "海南省三亚市吉阳区");
-        map.insert("460204", "海南省三亚市天涯区");
-        map.insert("460205", "海南省三亚市崖州区");
-        map.insert("460300", "海南省三沙市");
-        map.insert("460400", "海南省儋州市");
-        map.insert("469001", "海南省五指山市");
-        map.insert("469002", "海南省琼海市");
-        map.insert("469005", "海南省文昌市");
-        map.insert("469006", "海南省万宁市");
-        map.insert("469007", "海南省东方市");
-        map.insert("469021", "海南省定安县");
-        map.insert("469022", "海南省屯昌县");
-        map.insert("469023", "海南省澄迈县");
-        map.insert("469024", "海南省临高县");
-        map.insert("469025", "海南省白沙黎族自治县");
-        map.insert("469026", "海南省昌江黎族自治县");
-        map.insert("469027", "海南省乐东黎族自治县");
-        map.insert("469028", "海南省陵水黎族自治县");
-        map.insert("469029", "海南省保亭黎族苗族自治县");
-        map.insert("469030", "海南省琼中黎族苗族自治县");
-        map.insert("500000", "重庆市");
-        map.insert("500101", "重庆市万州区");
-        map.insert("500102", "重庆市涪陵区");
-        map.insert("500103", "重庆市渝中区");
-        map.insert("500104", "重庆市大渡口区");
-        map.insert("500105", "重庆市江北区");
-        map.insert("500106", "重庆市沙坪坝区");
-        map.insert("500107", "重庆市九龙坡区");
-        map.insert("500108", "重庆市南岸区");
-        map.insert("500109", "重庆市北碚区");
-        map.insert("500110", "重庆市綦江区");
-        map.insert("500111", "重庆市大足区");
-        map.insert("500112", "重庆市渝北区");
-        map.insert("500113", "重庆市巴南区");
-        map.insert("500114", "重庆市黔江区");
-        map.insert("500115", "重庆市长寿区");
-        map.insert("500116", "重庆市江津区");
-        map.insert("500117", "重庆市合川区");
-        map.insert("500118", "重庆市永川区");
-        map.insert("500119", "重庆市南川区");
-        map.insert("500120", "重庆市璧山区");
-        map.insert("500151", "重庆市铜梁区");
-        map.insert("500152", "重庆市潼南区");
-        map.insert("500153", "重庆市荣昌区");
-        map.insert("500154", "重庆市开州区");
-        map.insert("500155", "重庆市梁平区");
-        map.insert("500156", "重庆市武隆区");
-        map.insert("500229", "重庆市城口县");
-        map.insert("500230", "重庆市丰都县");
-        map.insert("500231", "重庆市垫江县");
-        map.insert("500233", "重庆市忠县");
-        map.insert("500235", "重庆市云阳县");
-        map.insert("500236", "重庆市奉节县");
-        map.insert("500237", "重庆市巫山县");
-        map.insert("500238", "重庆市巫溪县");
-        map.insert("500240", "重庆市石柱土家族自治县");
-        map.insert("500241", "重庆市秀山土家族苗族自治县");
-        map.insert("500242", "重庆市酉阳土家族苗族自治县");
-        map.insert("500243", "重庆市彭水苗族土家族自治县");
-        map.insert("510000", "四川省");
-        map.insert("510100", "四川省成都市");
-        map.insert("510104", "四川省成都市锦江区");
-        map.insert("510105", "四川省成都市青羊区");
-        map.insert("510106", "四川省成都市金牛区");
-        map.insert("510107", "四川省成都市武侯区");
-        map.insert("510108", "四川省成都市成华区");
-        map.insert("510112", "四川省成都市龙泉驿区");
-        map.insert("510113", "四川省成都市青白江区");
-        map.insert("510114", "四川省成都市新都区");
-        map.insert("510115", "四川省成都市温江区");
-        map.insert("510116", "四川省成都市双流区");
-        map.insert("510117", "四川省成都市郫都区");
-        map.insert("510118", "四川省成都市新津区");
-        map.insert("510121", "四川省成都市金堂县");
-        map.insert("510129", "四川省成都市大邑县");
-        map.insert("510131", "四川省成都市蒲江县");
-        map.insert("510181", "四川省成都市都江堰市");
-        map.insert("510182", "四川省成都市彭州市");
-        map.insert("510183", "四川省成都市邛崃市");
-        map.insert("510184", "四川省成都市崇州市");
-        map.insert("510185", "四川省成都市简阳市");
-        map.insert("510300", "四川省自贡市");
-        map.insert("510302", "四川省自贡市自流井区");
-        map.insert("510303", "四川省自贡市贡井区");
-        map.insert("510304", "四川省自贡市大安区");
-        map.insert("510311", "四川省自贡市沿滩区");
-        map.insert("510321", "四川省自贡市荣县");
-        map.insert("510322", "四川省自贡市富顺县");
-        map.insert("510400", "四川省攀枝花市");
-        map.insert("510402", "四川省攀枝花市东区");
-        map.insert("510403", "四川省攀枝花市西区");
-        map.insert("510411", "四川省攀枝花市仁和区");
-        map.insert("510421", "四川省攀枝花市米易县");
-        map.insert("510422", "四川省攀枝花市盐边县");
-        map.insert("510500", "四川省泸州市");
-        map.insert("510502", "四川省泸州市江阳区");
-        map.insert("510503", "四川省泸州市纳溪区");
-        map.insert("510504", "四川省泸州市龙马潭区");
-        map.insert("510521", "四川省泸州市泸县");
-        map.insert("510522", "四川省泸州市合江县");
-        map.insert("510524", "四川省泸州市叙永县");
-        map.insert("510525", "四川省泸州市古蔺县");
-        map.insert("510600", "四川省德阳市");
-        map.insert("510603", "四川省德阳市旌阳区");
-        map.insert("510604", "四川省德阳市罗江区");
-        map.insert("510623", "四川省德阳市中江县");
-        map.insert("510681", "四川省德阳市广汉市");
-        map.insert("510682", "四川省德阳市什邡市");
-        map.insert("510683", "四川省德阳市绵竹市");
-        map.insert("510700", "四川省绵阳市");
-        map.insert("510703", "四川省绵阳市涪城区");
-        map.insert("510704", "四川省绵阳市游仙区");
-        map.insert("510705", "四川省绵阳市安州区");
-        map.insert("510722", "四川省绵阳市三台县");
-        map.insert("510723", "四川省绵阳市盐亭县");
-        map.insert("510725", "四川省绵阳市梓潼县");
-        map.insert("510726", "四川省绵阳市北川羌族自治县");
-        map.insert("510727", "四川省绵阳市平武县");
-        map.insert("510781", "四川省绵阳市江油市");
-        map.insert("510800", "四川省广元市");
-        map.insert("510802", "四川省广元市利州区");
-        map.insert("510811", "四川省广元市昭化区");
-        map.insert("510812", "四川省广元市朝天区");
-        map.insert("510821", "四川省广元市旺苍县");
-        map.insert("510822", "四川省广元市青川县");
-        map.insert("510823", "四川省广元市剑阁县");
-        map.insert("510824", "四川省广元市苍溪县");
-        map.insert("510900", "四川省遂宁市");
-        map.insert("510903", "四川省遂宁市船山区");
-        map.insert("510904", "四川省遂宁市安居区");
-        map.insert("510921", "四川省遂宁市蓬溪县");
-        map.insert("510923", "四川省遂宁市大英县");
-        map.insert("510981", "四川省遂宁市射洪市");
-        map.insert("511000", "四川省内江市");
-        map.insert("511002", "四川省内江市市中区");
-        map.insert("511011", "四川省内江市东兴区");
-        map.insert("511024", "四川省内江市威远县");
-        map.insert("511025", "四川省内江市资中县");
-        map.insert("511083", "四川省内江市隆昌市");
-        map.insert("511100", "四川省乐山市");
-        map.insert("511102", "四川省乐山市市中区");
-        map.insert("511111", "四川省乐山市沙湾区");
-        map.insert("511112", "四川省乐山市五通桥区");
-        map.insert("511113", "四川省乐山市金口河区");
-        map.insert("511123", "四川省乐山市犍为县");
-        map.insert("511124", "四川省乐山市井研县");
-        map.insert("511126", "四川省乐山市夹江县");
-        map.insert("511129", "四川省乐山市沐川县");
-        map.insert("511132", "四川省乐山市峨边彝族自治县");
-        map.insert("511133", "四川省乐山市马边彝族自治县");
-        map.insert("511181", "四川省乐山市峨眉山市");
-        map.insert("511300", "四川省南充市");
-        map.insert("511302", "四川省南充市顺庆区");
-        map.insert("511303", "四川省南充市高坪区");
-        map.insert("511304", "四川省南充市嘉陵区");
-        map.insert("511321", "四川省南充市南部县");
-        map.insert("511322", "四川省南充市营山县");
-        map.insert("511323", "四川省南充市蓬安县");
-        map.insert("511324", "四川省南充市仪陇县");
-        map.insert("511325", "四川省南充市西充县");
-        map.insert("511381", "四川省南充市阆中市");
-        map.insert("511400", "四川省眉山市");
-        map.insert("511402", "四川省眉山市东坡区");
-        map.insert("511403", "四川省眉山市彭山区");
-        map.insert("511421", "四川省眉山市仁寿县");
-        map.insert("511423", "四川省眉山市洪雅县");
-        map.insert("511424", "四川省眉山市丹棱县");
-        map.insert("511425", "四川省眉山市青神县");
-        map.insert("511500", "四川省宜宾市");
-        map.insert("511502", "四川省宜宾市翠屏区");
-        map.insert("511503", "四川省宜宾市南溪区");
-        map.insert("511504", "四川省宜宾市叙州区");
-        map.insert("511523", "四川省宜宾市江安县");
-        map.insert("511524", "四川省宜宾市长宁县");
-        map.insert("511525", "四川省宜宾市高县");
-        map.insert("511526", "四川省宜宾市珙县");
-        map.insert("511527", "四川省宜宾市筠连县");
-        map.insert("511528", "四川省宜宾市兴文县");
-        map.insert("511529", "四川省宜宾市屏山县");
-        map.insert("511600", "四川省广安市");
-        map.insert("511602", "四川省广安市广安区");
-        map.insert("511603", "四川省广安市前锋区");
-        map.insert("511621", "四川省广安市岳池县");
-        map.insert("511622", "四川省广安市武胜县");
-        map.insert("511623", "四川省广安市邻水县");
-        map.insert("511681", "四川省广安市华蓥市");
-        map.insert("511700", "四川省达州市");
-        map.insert("511702", "四川省达州市通川区");
-        map.insert("511703", "四川省达州市达川区");
-        map.insert("511722", "四川省达州市宣汉县");
-        map.insert("511723", "四川省达州市开江县");
-        map.insert("511724", "四川省达州市大竹县");
-        map.insert("511725", "四川省达州市渠县");
-        map.insert("511781", "四川省达州市万源市");
-        map.insert("511800", "四川省雅安市");
-        map.insert("511802", "四川省雅安市雨城区");
-        map.insert("511803", "四川省雅安市名山区");
-        map.insert("511822", "四川省雅安市荥经县");
-        map.insert("511823", "四川省雅安市汉源县");
-        map.insert("511824", "四川省雅安市石棉县");
-        map.insert("511825", "四川省雅安市天全县");
-        map.insert("511826", "四川省雅安市芦山县");
-        map.insert("511827", "四川省雅安市宝兴县");
-        map.insert("511900", "四川省巴中市");
-        map.insert("511902", "四川省巴中市巴州区");
-        map.insert("511903", "四川省巴中市恩阳区");
-        map.insert("511921", "四川省巴中市通江县");
-        map.insert("511922", "四川省巴中市南江县");
-        map.insert("511923", "四川省巴中市平昌县");
-        map.insert("512000", "四川省资阳市");
-        map.insert("512002", "四川省资阳市雁江区");
-        map.insert("512021", "四川省资阳市安岳县");
-        map.insert("512022", "四川省资阳市乐至县");
-        map.insert("513200", "四川省阿坝藏族羌族自治州");
-        map.insert("513201", "四川省阿坝藏族羌族自治州马尔康市");
-        map.insert("513221", "四川省阿坝藏族羌族自治州汶川县");
-        map.insert("513222", "四川省阿坝藏族羌族自治州理县");
-        map.insert("513223", "四川省阿坝藏族羌族自治州茂县");
-        map.insert("513224", "四川省阿坝藏族羌族自治州松潘县");
-        map.insert("513225", "四川省阿坝藏族羌族自治州九寨沟县");
-        map.insert("513226", "四川省阿坝藏族羌族自治州金川县");
-        map.insert("513227", "四川省阿坝藏族羌族自治州小金县");
-        map.insert("513228", "四川省阿坝藏族羌族自治州黑水县");
-        map.insert("513230", "四川省阿坝藏族羌族自治州壤塘县");
-        map.insert("513231", "四川省阿坝藏族羌族自治州阿坝县");
-        map.insert("513232", "四川省阿坝藏族羌族自治州若尔盖县");
-        map.insert("513233", "四川省阿坝藏族羌族自治州红原县");
-        map.insert("513300", "四川省甘孜藏族自治州");
-        map.insert("513301", "四川省甘孜藏族自治州康定市");
-        map.insert("513322", "四川省甘孜藏族自治州泸定县");
-        map.insert("513323", "四川省甘孜藏族自治州丹巴县");
-        map.insert("513324", "四川省甘孜藏族自治州九龙县");
-        map.insert("513325", "四川省甘孜藏族自治州雅江县");
-        map.insert("513326", "四川省甘孜藏族自治州道孚县");
-        map.insert("513327", "四川省甘孜藏族自治州炉霍县");
-        map.insert("513328", "四川省甘孜藏族自治州甘孜县");
-        map.insert("513329", "四川省甘孜藏族自治州新龙县");
-        map.insert("513330", "四川省甘孜藏族自治州德格县");
-        map.insert("513331", "四川省甘孜藏族自治州白玉县");
-        map.insert("513332", "四川省甘孜藏族自治州石渠县");
-        map.insert("513333", "四川省甘孜藏族自治州色达县");
-        map.insert("513334", "四川省甘孜藏族自治州理塘县");
-        map.insert("513335", "四川省甘孜藏族自治州巴塘县");
-        map.insert("513336", "四川省甘孜藏族自治州乡城县");
-        map.insert("513337", "四川省甘孜藏族自治州稻城县");
-        map.insert("513338", "四川省甘孜藏族自治州得荣县");
-        map.insert("513400", "四川省凉山彝族自治州");
-        map.insert("513401", "四川省凉山彝族自治州西昌市");
-        map.insert("513402", "四川省凉山彝族自治州会理市");
-        map.insert("513422", "四川省凉山彝族自治州木里藏族自治县");
-        map.insert("513423", "四川省凉山彝族自治州盐源县");
-        map.insert("513424", "四川省凉山彝族自治州德昌县");
-        map.insert("513426", "四川省凉山彝族自治州会东县");
-        map.insert("513427", "四川省凉山彝族自治州宁南县");
-        map.insert("513428", "四川省凉山彝族自治州普格县");
-        map.insert("513429", "四川省凉山彝族自治州布拖县");
-        map.insert("513430", "四川省凉山彝族自治州金阳县");
-        map.insert("513431", "四川省凉山彝族自治州昭觉县");
-        map.insert("513432", "四川省凉山彝族自治州喜德县");
-        map.insert("513433", "四川省凉山彝族自治州冕宁县");
-        map.insert("513434", "四川省凉山彝族自治州越西县");
-        map.insert("513435", "四川省凉山彝族自治州甘洛县");
-        map.insert("513436", "四川省凉山彝族自治州美姑县");
-        map.insert("513437", "四川省凉山彝族自治州雷波县");
-        map.insert("520000", "贵州省");
-        map.insert("520100", "贵州省贵阳市");
-        map.insert("520102", "贵州省贵阳市南明区");
-        map.insert("520103", "贵州省贵阳市云岩区");
-        map.insert("520111", "贵州省贵阳市花溪区");
-        map.insert("520112", "贵州省贵阳市乌当区");
-        map.insert("520113", "贵州省贵阳市白云区");
-        map.insert("520115", "贵州省贵阳市观山湖区");
-        map.insert("520121", "贵州省贵阳市开阳县");
-        map.insert("520122", "贵州省贵阳市息烽县");
-        map.insert("520123", "贵州省贵阳市修文县");
-        map.insert("520181", "贵州省贵阳市清镇市");
-        map.insert("520200", "贵州省六盘水市");
-        map.insert("520201", "贵州省六盘水市钟山区");
-        map.insert("520203", "贵州省六盘水市六枝特区");
-        map.insert("520204", "贵州省六盘水市水城区");
-        map.insert("520281", "贵州省六盘水市盘州市");
-        map.insert("520300", "贵州省遵义市");
-        map.insert("520302", "贵州省遵义市红花岗区");
-        map.insert("520303", "贵州省遵义市汇川区");
-        map.insert("520304", "贵州省遵义市播州区");
-        map.insert("520322", "贵州省遵义市桐梓县");
-        map.insert("520323", "贵州省遵义市绥阳县");
-        map.insert("520324", "贵州省遵义市正安县");
-        map.insert("520325", "贵州省遵义市道真仡佬族苗族自治县");
-        map.insert("520326", "贵州省遵义市务川仡佬族苗族自治县");
-        map.insert("520327", "贵州省遵义市凤冈县");
-        map.insert("520328", "贵州省遵义市湄潭县");
-        map.insert("520329", "贵州省遵义市余庆县");
-        map.insert("520330", "贵州省遵义市习水县");
-        map.insert("520381", "贵州省遵义市赤水市");
-        map.insert("520382", "贵州省遵义市仁怀市");
-        map.insert("520400", "贵州省安顺市");
-        map.insert("520402", "贵州省安顺市西秀区");
-        map.insert("520403", "贵州省安顺市平坝区");
-        map.insert("520422", "贵州省安顺市普定县");
-        map.insert("520423", "贵州省安顺市镇宁布依族苗族自治县");
-        map.insert("520424", "贵州省安顺市关岭布依族苗族自治县");
-        map.insert("520425", "贵州省安顺市紫云苗族布依族自治县");
-        map.insert("520500", "贵州省毕节市");
-        map.insert("520502", "贵州省毕节市七星关区");
-        map.insert("520521", "贵州省毕节市大方县");
-        map.insert("520523", "贵州省毕节市金沙县");
-        map.insert("520524", "贵州省毕节市织金县");
-        map.insert("520525", "贵州省毕节市纳雍县");
-        map.insert("520526", "贵州省毕节市威宁彝族回族苗族自治县");
-        map.insert("520527", "贵州省毕节市赫章县");
-        map.insert("520581", "贵州省毕节市黔西市");
-        map.insert("520600", "贵州省铜仁市");
-        map.insert("520602", "贵州省铜仁市碧江区");
-        map.insert("520603", "贵州省铜仁市万山区");
-        map.insert("520621", "贵州省铜仁市江口县");
-        map.insert("520622", "贵州省铜仁市玉屏侗族自治县");
-        map.insert("520623", "贵州省铜仁市石阡县");
-        map.insert("520624", "贵州省铜仁市思南县");
-        map.insert("520625", "贵州省铜仁市印江土家族苗族自治县");
-        map.insert("520626", "贵州省铜仁市德江县");
-        map.insert("520627", "贵州省铜仁市沿河土家族自治县");
-        map.insert("520628", "贵州省铜仁市松桃苗族自治县");
-        map.insert("522300", "贵州省黔西南布依族苗族自治州");
-        map.insert("522301", "贵州省黔西南布依族苗族自治州兴义市");
-        map.insert("522302", "贵州省黔西南布依族苗族自治州兴仁市");
-        map.insert("522323", "贵州省黔西南布依族苗族自治州普安县");
-        map.insert("522324", "贵州省黔西南布依族苗族自治州晴隆县");
-        map.insert("522325", "贵州省黔西南布依族苗族自治州贞丰县");
-        map.insert("522326", "贵州省黔西南布依族苗族自治州望谟县");
-        map.insert("522327", "贵州省黔西南布依族苗族自治州册亨县");
-        map.insert("522328", "贵州省黔西南布依族苗族自治州安龙县");
-        map.insert("522600", "贵州省黔东南苗族侗族自治州");
-        map.insert("522601", "贵州省黔东南苗族侗族自治州凯里市");
-        map.insert("522622", "贵州省黔东南苗族侗族自治州黄平县");
-        map.insert("522623", "贵州省黔东南苗族侗族自治州施秉县");
-        map.insert("522624", "贵州省黔东南苗族侗族自治州三穗县");
-        map.insert("522625", "贵州省黔东南苗族侗族自治州镇远县");
-        map.insert("522626", "贵州省黔东南苗族侗族自治州岑巩县");
-        map.insert("522627", "贵州省黔东南苗族侗族自治州天柱县");
-        map.insert("522628", "贵州省黔东南苗族侗族自治州锦屏县");
-        map.insert("522629", "贵州省黔东南苗族侗族自治州剑河县");
-        map.insert("522630", "贵州省黔东南苗族侗族自治州台江县");
-        map.insert("522631", "贵州省黔东南苗族侗族自治州黎平县");
-        map.insert("522632", "贵州省黔东南苗族侗族自治州榕江县");
-        map.insert("522633", "贵州省黔东南苗族侗族自治州从江县");
-        map.insert("522634", "贵州省黔东南苗族侗族自治州雷山县");
-        map.insert("522635", "贵州省黔东南苗族侗族自治州麻江县");
-        map.insert("522636", "贵州省黔东南苗族侗族自治州丹寨县");
-        map.insert("522700", "贵州省黔南布依族苗族自治州");
-        map.insert("522701", "贵州省黔南布依族苗族自治州都匀市");
-        map.insert("522702", "贵州省黔南布依族苗族自治州福泉市");
-        map.insert("522722", "贵州省黔南布依族苗族自治州荔波县");
-        map.insert("522723", "贵州省黔南布依族苗族自治州贵定县");
-        map.insert("522725", "贵州省黔南布依族苗族自治州瓮安县");
-        map.insert("522726", "贵州省黔南布依族苗族自治州独山县");
-        map.insert("522727", "贵州省黔南布依族苗族自治州平塘县");
-        map.insert("522728", "贵州省黔南布依族苗族自治州罗甸县");
-        map.insert("522729", "贵州省黔南布依族苗族自治州长顺县");
-        map.insert("522730", "贵州省黔南布依族苗族自治州龙里县");
-        map.insert("522731", "贵州省黔南布依族苗族自治州惠水县");
-        map.insert("522732", "贵州省黔南布依族苗族自治州三都水族自治县");
-        map.insert("530000", "云南省");
-        map.insert("530100", "云南省昆明市");
-        map.insert("530102", "云南省昆明市五华区");
-        map.insert("530103", "云南省昆明市盘龙区");
-        map.insert("530111", "云南省昆明市官渡区");
-        map.insert("530112", "云南省昆明市西山区");
-        map.insert("530113", "云南省昆明市东川区");
-        map.insert("530114", "云南省昆明市呈贡区");
-        map.insert("530115", "云南省昆明市晋宁区");
-        map.insert("530124", "云南省昆明市富民县");
-        map.insert("530125", "云南省昆明市宜良县");
-        map.insert("530126", "云南省昆明市石林彝族自治县");
-        map.insert("530127", "云南省昆明市嵩明县");
-        map.insert("530128", "云南省昆明市禄劝彝族苗族自治县");
-        map.insert("530129", "云南省昆明市寻甸回族彝族自治县");
-        map.insert("530181", "云南省昆明市安宁市");
-        map.insert("530300", "云南省曲靖市");
-        map.insert("530302", "云南省曲靖市麒麟区");
-        map.insert("530303", "云南省曲靖市沾益区");
-        map.insert("530304", "云南省曲靖市马龙区");
-        map.insert("530322", "云南省曲靖市陆良县");
-        map.insert("530323", "云南省曲靖市师宗县");
-        map.insert("530324", "云南省曲靖市罗平县");
-        map.insert("530325", "云南省曲靖市富源县");
-        map.insert("530326", "云南省曲靖市会泽县");
-        map.insert("530381", "云南省曲靖市宣威市");
-        map.insert("530400", "云南省玉溪市");
-        map.insert("530402", "云南省玉溪市红塔区");
-        map.insert("530403", "云南省玉溪市江川区");
-        map.insert("530423", "云南省玉溪市通海县");
-        map.insert("530424", "云南省玉溪市华宁县");
-        map.insert("530425", "云南省玉溪市易门县");
-        map.insert("530426", "云南省玉溪市峨山彝族自治县");
-        map.insert("530427", "云南省玉溪市新平彝族傣族自治县");
-        map.insert("530428", "云南省玉溪市元江哈尼族彝族傣族自治县");
-        map.insert("530481", "云南省玉溪市澄江市");
-        map.insert("530500", "云南省保山市");
-        map.insert("530502", "云南省保山市隆阳区");
-        map.insert("530521", "云南省保山市施甸县");
-        map.insert("530523", "云南省保山市龙陵县");
-        map.insert("530524", "云南省保山市昌宁县");
-        map.insert("530581", "云南省保山市腾冲市");
-        map.insert("530600", "云南省昭通市");
-        map.insert("530602", "云南省昭通市昭阳区");
-        map.insert("530621", "云南省昭通市鲁甸县");
-        map.insert("530622", "云南省昭通市巧家县");
-        map.insert("530623", "云南省昭通市盐津县");
-        map.insert("530624", "云南省昭通市大关县");
-        map.insert("530625", "云南省昭通市永善县");
-        map.insert("530626", "云南省昭通市绥江县");
-        map.insert("530627", "云南省昭通市镇雄县");
-        map.insert("530628", "云南省昭通市彝良县");
-        map.insert("530629", "云南省昭通市威信县");
-        map.insert("530681", "云南省昭通市水富市");
-        map.insert("530700", "云南省丽江市");
-        map.insert("530702", "云南省丽江市古城区");
-        map.insert("530721", "云南省丽江市玉龙纳西族自治县");
-        map.insert("530722", "云南省丽江市永胜县");
-        map.insert("530723", "云南省丽江市华坪县");
-        map.insert("530724", "云南省丽江市宁蒗彝族自治县");
-        map.insert("530800", "云南省普洱市");
-        map.insert("530802", "云南省普洱市思茅区");
-        map.insert("530821", "云南省普洱市宁洱哈尼族彝族自治县");
-        map.insert("530822", "云南省普洱市墨江哈尼族自治县");
-        map.insert("530823", "云南省普洱市景东彝族自治县");
-        map.insert("530824", "云南省普洱市景谷傣族彝族自治县");
-        map.insert("530825", "云南省普洱市镇沅彝族哈尼族拉祜族自治县");
-        map.insert("530826", "云南省普洱市江城哈尼族彝族自治县");
-        map.insert("530827", "云南省普洱市孟连傣族拉祜族佤族自治县");
-        map.insert("530828", "云南省普洱市澜沧拉祜族自治县");
-        map.insert("530829", "云南省普洱市西盟佤族自治县");
-        map.insert("530900", "云南省临沧市");
-        map.insert("530902", "云南省临沧市临翔区");
-        map.insert("530921", "云南省临沧市凤庆县");
-        map.insert("530922", "云南省临沧市云县");
-        map.insert("530923", "云南省临沧市永德县");
-        map.insert("530924", "云南省临沧市镇康县");
-        map.insert("530925", "云南省临沧市双江拉祜族佤族布朗族傣族自治县");
-        map.insert("530926", "云南省临沧市耿马傣族佤族自治县");
-        map.insert("530927", "云南省临沧市沧源佤族自治县");
-        map.insert("532300", "云南省楚雄彝族自治州");
-        map.insert("532301", "云南省楚雄彝族自治州楚雄市");
-        map.insert("532302", "云南省楚雄彝族自治州禄丰市");
-        map.insert("532322", "云南省楚雄彝族自治州双柏县");
-        map.insert("532323", "云南省楚雄彝族自治州牟定县");
-        map.insert("532324", "云南省楚雄彝族自治州南华县");
-        map.insert("532325", "云南省楚雄彝族自治州姚安县");
-        map.insert("532326", "云南省楚雄彝族自治州大姚县");
-        map.insert("532327", "云南省楚雄彝族自治州永仁县");
-        map.insert("532328", "云南省楚雄彝族自治州元谋县");
-        map.insert("532329", "云南省楚雄彝族自治州武定县");
-        map.insert("532500", "云南省红河哈尼族彝族自治州");
-        map.insert("532501", "云南省红河哈尼族彝族自治州个旧市");
-        map.insert("532502", "云南省红河哈尼族彝族自治州开远市");
-        map.insert("532503", "云南省红河哈尼族彝族自治州蒙自市");
-        map.insert("532504", "云南省红河哈尼族彝族自治州弥勒市");
-        map.insert("532523", "云南省红河哈尼族彝族自治州屏边苗族自治县");
-        map.insert("532524", "云南省红河哈尼族彝族自治州建水县");
-        map.insert("532525", "云南省红河哈尼族彝族自治州石屏县");
-        map.insert("532527", "云南省红河哈尼族彝族自治州泸西县");
-        map.insert("532528", "云南省红河哈尼族彝族自治州元阳县");
-        map.insert("532529", "云南省红河哈尼族彝族自治州红河县");
-        map.insert("532530", "云南省红河哈尼族彝族自治州金平苗族瑶族傣族自治县");
-        map.insert("532531", "云南省红河哈尼族彝族自治州绿春县");
-        map.insert("532532", "云南省红河哈尼族彝族自治州河口瑶族自治县");
-        map.insert("532600", "云南省文山壮族苗族自治州");
-        map.insert("532601", "云南省文山壮族苗族自治州文山市");
-        map.insert("532622", "云南省文山壮族苗族自治州砚山县");
-        map.insert("532623", "云南省文山壮族苗族自治州西畴县");
-        map.insert("532624", "云南省文山壮族苗族自治州麻栗坡县");
-        map.insert("532625", "云南省文山壮族苗族自治州马关县");
-        map.insert("532626", "云南省文山壮族苗族自治州丘北县");
-        map.insert("532627", "云南省文山壮族苗族自治州广南县");
-        map.insert("532628", "云南省文山壮族苗族自治州富宁县");
-        map.insert("532800", "云南省西双版纳傣族自治州");
-        map.insert("532801", "云南省西双版纳傣族自治州景洪市");
-        map.insert("532822", "云南省西双版纳傣族自治州勐海县");
-        map.insert("532823", "云南省西双版纳傣族自治州勐腊县");
-        map.insert("532900", "云南省大理白族自治州");
-        map.insert("532901", "云南省大理白族自治州大理市");
-        map.insert("532922", "云南省大理白族自治州漾濞彝族自治县");
-        map.insert("532923", "云南省大理白族自治州祥云县");
-        map.insert("532924", "云南省大理白族自治州宾川县");
-        map.insert("532925", "云南省大理白族自治州弥渡县");
-        map.insert("532926", "云南省大理白族自治州南涧彝族自治县");
-        map.insert("532927", "云南省大理白族自治州巍山彝族回族自治县");
-        map.insert("532928", "云南省大理白族自治州永平县");
-        map.insert("532929", "云南省大理白族自治州云龙县");
-        map.insert("532930", "云南省大理白族自治州洱源县");
-        map.insert("532931", "云南省大理白族自治州剑川县");
-        map.insert("532932", "云南省大理白族自治州鹤庆县");
-        map.insert("533100", "云南省德宏傣族景颇族自治州");
-        map.insert("533102", "云南省德宏傣族景颇族自治州瑞丽市");
-        map.insert("533103", "云南省德宏傣族景颇族自治州芒市");
-        map.insert("533122", "云南省德宏傣族景颇族自治州梁河县");
-        map.insert("533123", "云南省德宏傣族景颇族自治州盈江县");
-        map.insert("533124", "云南省德宏傣族景颇族自治州陇川县");
-        map.insert("533300", "云南省怒江傈僳族自治州");
-        map.insert("533301", "云南省怒江傈僳族自治州泸水市");
-        map.insert("533323", "云南省怒江傈僳族自治州福贡县");
-        map.insert("533324", "云南省怒江傈僳族自治州贡山独龙族怒族自治县");
-        map.insert("533325", "云南省怒江傈僳族自治州兰坪白族普米族自治县");
-        map.insert("533400", "云南省迪庆藏族自治州");
-        map.insert("533401", "云南省迪庆藏族自治州香格里拉市");
-        map.insert("533422", "云南省迪庆藏族自治州德钦县");
-        map.insert("533423", "云南省迪庆藏族自治州维西傈僳族自治县");
-        map.insert("540000", "西藏自治区");
-        map.insert("540100", "西藏自治区拉萨市");
-        map.insert("540102", "西藏自治区拉萨市城关区");
-        map.insert("540103", "西藏自治区拉萨市堆龙德庆区");
-        map.insert("540104", "西藏自治区拉萨市达孜区");
-        map.insert("540121", "西藏自治区拉萨市林周县");
-        map.insert("540122", "西藏自治区拉萨市当雄县");
-        map.insert("540123", "西藏自治区拉萨市尼木县");
-        map.insert("540124", "西藏自治区拉萨市曲水县");
-        map.insert("540127", "西藏自治区拉萨市墨竹工卡县");
-        map.insert("540200", "西藏自治区日喀则市");
-        map.insert("540202", "西藏自治区日喀则市桑珠孜区");
-        map.insert("540221", "西藏自治区日喀则市南木林县");
-        map.insert("540222", "西藏自治区日喀则市江孜县");
-        map.insert("540223", "西藏自治区日喀则市定日县");
-        map.insert("540224", "西藏自治区日喀则市萨迦县");
-        map.insert("540225", "西藏自治区日喀则市拉孜县");
-        map.insert("540226", "西藏自治区日喀则市昂仁县");
-        map.insert("540227", "西藏自治区日喀则市谢通门县");
-        map.insert("540228", "西藏自治区日喀则市白朗县");
-        map.insert("540229", "西藏自治区日喀则市仁布县");
-        map.insert("540230", "西藏自治区日喀则市康马县");
-        map.insert("540231", "西藏自治区日喀则市定结县");
-        map.insert("540232", "西藏自治区日喀则市仲巴县");
-        map.insert("540233", "西藏自治区日喀则市亚东县");
-        map.insert("540234", "西藏自治区日喀则市吉隆县");
-        map.insert("540235", "西藏自治区日喀则市聂拉木县");
-        map.insert("540236", "西藏自治区日喀则市萨嘎县");
-        map.insert("540237", "西藏自治区日喀则市岗巴县");
-        map.insert("540300", "西藏自治区昌都市");
-        map.insert("540302", "西藏自治区昌都市卡若区");
-        map.insert("540321", "西藏自治区昌都市江达县");
-        map.insert("540322", "西藏自治区昌都市贡觉县");
-        map.insert("540323", "西藏自治区昌都市类乌齐县");
-        map.insert("540324", "西藏自治区昌都市丁青县");
-        map.insert("540325", "西藏自治区昌都市察雅县");
-        map.insert("540326", "西藏自治区昌都市八宿县");
-        map.insert("540327", "西藏自治区昌都市左贡县");
-        map.insert("540328", "西藏自治区昌都市芒康县");
-        map.insert("540329", "西藏自治区昌都市洛隆县");
-        map.insert("540330", "西藏自治区昌都市边坝县");
-        map.insert("540400", "西藏自治区林芝市");
-        map.insert("540402", "西藏自治区林芝市巴宜区");
-        map.insert("540421", "西藏自治区林芝市工布江达县");
-        map.insert("540422", "西藏自治区林芝市米林县");
-        map.insert("540423", "西藏自治区林芝市墨脱县");
-        map.insert("540424", "西藏自治区林芝市波密县");
-        map.insert("540425", "西藏自治区林芝市察隅县");
-        map.insert("540426", "西藏自治区林芝市朗县");
-        map.insert("540500", "西藏自治区山南市");
-        map.insert("540502", "西藏自治区山南市乃东区");
-        map.insert("540521", "西藏自治区山南市扎囊县");
-        map.insert("540522", "西藏自治区山南市贡嘎县");
-        map.insert("540523", "西藏自治区山南市桑日县");
-        map.insert("540524", "西藏自治区山南市琼结县");
-        map.insert("540525", "西藏自治区山南市曲松县");
-        map.insert("540526", "西藏自治区山南市措美县");
-        map.insert("540527", "西藏自治区山南市洛扎县");
-        map.insert("540528", "西藏自治区山南市加查县");
-        map.insert("540529", "西藏自治区山南市隆子县");
-        map.insert("540530", "西藏自治区山南市错那县");
-        map.insert("540531", "西藏自治区山南市浪卡子县");
-        map.insert("540600", "西藏自治区那曲市");
-        map.insert("540602", "西藏自治区那曲市色尼区");
-        map.insert("540621", "西藏自治区那曲市嘉黎县");
-        map.insert("540622", "西藏自治区那曲市比如县");
-        map.insert("540623", "西藏自治区那曲市聂荣县");
-        map.insert("540624", "西藏自治区那曲市安多县");
-        map.insert("540625", "西藏自治区那曲市申扎县");
-        map.insert("540626", "西藏自治区那曲市索县");
-        map.insert("540627", "西藏自治区那曲市班戈县");
-        map.insert("540628", "西藏自治区那曲市巴青县");
-        map.insert("540629", "西藏自治区那曲市尼玛县");
-        map.insert("540630", "西藏自治区那曲市双湖县");
-        map.insert("542500", "西藏自治区阿里地区");
-        map.insert("542521", "西藏自治区阿里地区普兰县");
-        map.insert("542522", "西藏自治区阿里地区札达县");
-        map.insert("542523", "西藏自治区阿里地区噶尔县");
-        map.insert("542524", "西藏自治区阿里地区日土县");
-        map.insert("542525", "西藏自治区阿里地区革吉县");
-        map.insert("542526", "西藏自治区阿里地区改则县");
-        map.insert("542527", "西藏自治区阿里地区措勤县");
-        map.insert("610000", "陕西省");
-        map.insert("610100", "陕西省西安市");
-        map.insert("610102", "陕西省西安市新城区");
-        map.insert("610103", "陕西省西安市碑林区");
-        map.insert("610104", "陕西省西安市莲湖区");
-        map.insert("610111", "陕西省西安市灞桥区");
-        map.insert("610112", "陕西省西安市未央区");
-        map.insert("610113", "陕西省西安市雁塔区");
-        map.insert("610114", "陕西省西安市阎良区");
-        map.insert("610115", "陕西省西安市临潼区");
-        map.insert("610116", "陕西省西安市长安区");
-        map.insert("610117", "陕西省西安市高陵区");
-        map.insert("610118", "陕西省西安市鄠邑区");
-        map.insert("610122", "陕西省西安市蓝田县");
-        map.insert("610124", "陕西省西安市周至县");
-        map.insert("610200", "陕西省铜川市");
-        map.insert("610202", "陕西省铜川市王益区");
-        map.insert("610203", "陕西省铜川市印台区");
-        map.insert("610204", "陕西省铜川市耀州区");
-        map.insert("610222", "陕西省铜川市宜君县");
-        map.insert("610300", "陕西省宝鸡市");
-        map.insert("610302", "陕西省宝鸡市渭滨区");
-        map.insert("610303", "陕西省宝鸡市金台区");
-        map.insert("610304", "陕西省宝鸡市陈仓区");
-        map.insert("610305", "陕西省宝鸡市凤翔区");
-        map.insert("610323", "陕西省宝鸡市岐山县");
-        map.insert("610324", "陕西省宝鸡市扶风县");
-        map.insert("610326", "陕西省宝鸡市眉县");
-        map.insert("610327", "陕西省宝鸡市陇县");
-        map.insert("610328", "陕西省宝鸡市千阳县");
-        map.insert("610329", "陕西省宝鸡市麟游县");
-        map.insert("610330", "陕西省宝鸡市凤县");
-        map.insert("610331", "陕西省宝鸡市太白县");
-        map.insert("610400", "陕西省咸阳市");
-        map.insert("610402", "陕西省咸阳市秦都区");
-        map.insert("610403", "陕西省咸阳市杨陵区");
-        map.insert("610404", "陕西省咸阳市渭城区");
-        map.insert("610422", "陕西省咸阳市三原县");
-        map.insert("610423", "陕西省咸阳市泾阳县");
-        map.insert("610424", "陕西省咸阳市乾县");
-        map.insert("610425", "陕西省咸阳市礼泉县");
-        map.insert("610426", "陕西省咸阳市永寿县");
-        map.insert("610428", "陕西省咸阳市长武县");
-        map.insert("610429", "陕西省咸阳市旬邑县");
-        map.insert("610430", "陕西省咸阳市淳化县");
-        map.insert("610431", "陕西省咸阳市武功县");
-        map.insert("610481", "陕西省咸阳市兴平市");
-        map.insert("610482", "陕西省咸阳市彬州市");
-        map.insert("610500", "陕西省渭南市");
-        map.insert("610502", "陕西省渭南市临渭区");
-        map.insert("610503", "陕西省渭南市华州区");
-        map.insert("610522", "陕西省渭南市潼关县");
-        map.insert("610523", "陕西省渭南市大荔县");
-        map.insert("610524", "陕西省渭南市合阳县");
-        map.insert("610525", "陕西省渭南市澄城县");
-        map.insert("610526", "陕西省渭南市蒲城县");
-        map.insert("610527", "陕西省渭南市白水县");
-        map.insert("610528", "陕西省渭南市富平县");
-        map.insert("610581", "陕西省渭南市韩城市");
-        map.insert("610582", "陕西省渭南市华阴市");
-        map.insert("610600", "陕西省延安市");
-        map.insert("610602", "陕西省延安市宝塔区");
-        map.insert("610603", "陕西省延安市安塞区");
-        map.insert("610621", "陕西省延安市延长县");
-        map.insert("610622", "陕西省延安市延川县");
-        map.insert("610625", "陕西省延安市志丹县");
-        map.insert("610626", "陕西省延安市吴起县");
-        map.insert("610627", "陕西省延安市甘泉县");
-        map.insert("610628", "陕西省延安市富县");
-        map.insert("610629", "陕西省延安市洛川县");
-        map.insert("610630", "陕西省延安市宜川县");
-        map.insert("610631", "陕西省延安市黄龙县");
-        map.insert("610632", "陕西省延安市黄陵县");
-        map.insert("610681", "陕西省延安市子长市");
-        map.insert("610700", "陕西省汉中市");
-        map.insert("610702", "陕西省汉中市汉台区");
-        map.insert("610703", "陕西省汉中市南郑区");
-        map.insert("610722", "陕西省汉中市城固县");
-        map.insert("610723", "陕西省汉中市洋县");
-        map.insert("610724", "陕西省汉中市西乡县");
-        map.insert("610725", "陕西省汉中市勉县");
-        map.insert("610726", "陕西省汉中市宁强县");
-        map.insert("610727", "陕西省汉中市略阳县");
-        map.insert("610728", "陕西省汉中市镇巴县");
-        map.insert("610729", "陕西省汉中市留坝县");
-        map.insert("610730", "陕西省汉中市佛坪县");
-        map.insert("610800", "陕西省榆林市");
-        map.insert("610802", "陕西省榆林市榆阳区");
-        map.insert("610803", "陕西省榆林市横山区");
-        map.insert("610822", "陕西省榆林市府谷县");
-        map.insert("610824", "陕西省榆林市靖边县");
-        map.insert("610825", "陕西省榆林市定边县");
-        map.insert("610826", "陕西省榆林市绥德县");
-        map.insert("610827", "陕西省榆林市米脂县");
-        map.insert("610828", "陕西省榆林市佳县");
-        map.insert("610829", "陕西省榆林市吴堡县");
-        map.insert("610830", "陕西省榆林市清涧县");
-        map.insert("610831", "陕西省榆林市子洲县");
-        map.insert("610881", "陕西省榆林市神木市");
-        map.insert("610900", "陕西省安康市");
-        map.insert("610902", "陕西省安康市汉滨区");
-        map.insert("610921", "陕西省安康市汉阴县");
-        map.insert("610922", "陕西省安康市石泉县");
-        map.insert("610923", "陕西省安康市宁陕县");
-        map.insert("610924", "陕西省安康市紫阳县");
-        map.insert("610925", "陕西省安康市岚皋县");
-        map.insert("610926", "陕西省安康市平利县");
-        map.insert("610927", "陕西省安康市镇坪县");
-        map.insert("610929", "陕西省安康市白河县");
-        map.insert("610981", "陕西省安康市旬阳市");
-        map.insert("611000", "陕西省商洛市");
-        map.insert("611002", "陕西省商洛市商州区");
-        map.insert("611021", "陕西省商洛市洛南县");
-        map.insert("611022", "陕西省商洛市丹凤县");
-        map.insert("611023", "陕西省商洛市商南县");
-        map.insert("611024", "陕西省商洛市山阳县");
-        map.insert("611025", "陕西省商洛市镇安县");
-        map.insert("611026", "陕西省商洛市柞水县");
-        map.insert("620000", "甘肃省");
-        map.insert("620100", "甘肃省兰州市");
-        map.insert("620102", "甘肃省兰州市城关区");
-        map.insert("620103", "甘肃省兰州市七里河区");
-        map.insert("620104", "甘肃省兰州市西固区");
-        map.insert("620105", "甘肃省兰州市安宁区");
-        map.insert("620111", "甘肃省兰州市红古区");
-        map.insert("620121", "甘肃省兰州市永登县");
-        map.insert("620122", "甘肃省兰州市皋兰县");
-        map.insert("620123", "甘肃省兰州市榆中县");
-        map.insert("620200", "甘肃省嘉峪关市");
-        map.insert("620300", "甘肃省金昌市");
-        map.insert("620302", "甘肃省金昌市金川区");
-        map.insert("620321", "甘肃省金昌市永昌县");
-        map.insert("620400", "甘肃省白银市");
-        map.insert("620402", "甘肃省白银市白银区");
-        map.insert("620403", "甘肃省白银市平川区");
-        map.insert("620421", "甘肃省白银市靖远县");
-        map.insert("620422", "甘肃省白银市会宁县");
-        map.insert("620423", "甘肃省白银市景泰县");
-        map.insert("620500", "甘肃省天水市");
-        map.insert("620502", "甘肃省天水市秦州区");
-        map.insert("620503", "甘肃省天水市麦积区");
-        map.insert("620521", "甘肃省天水市清水县");
-        map.insert("620522", "甘肃省天水市秦安县");
-        map.insert("620523", "甘肃省天水市甘谷县");
-        map.insert("620524", "甘肃省天水市武山县");
-        map.insert("620525", "甘肃省天水市张家川回族自治县");
-        map.insert("620600", "甘肃省武威市");
-        map.insert("620602", "甘肃省武威市凉州区");
-        map.insert("620621", "甘肃省武威市民勤县");
-        map.insert("620622", "甘肃省武威市古浪县");
-        map.insert("620623", "甘肃省武威市天祝藏族自治县");
-        map.insert("620700", "甘肃省张掖市");
-        map.insert("620702", "甘肃省张掖市甘州区");
-        map.insert("620721", "甘肃省张掖市肃南裕固族自治县");
-        map.insert("620722", "甘肃省张掖市民乐县");
-        map.insert("620723", "甘肃省张掖市临泽县");
-        map.insert("620724", "甘肃省张掖市高台县");
-        map.insert("620725", "甘肃省张掖市山丹县");
-        map.insert("620800", "甘肃省平凉市");
-        map.insert("620802", "甘肃省平凉市崆峒区");
-        map.insert("620821", "甘肃省平凉市泾川县");
-        map.insert("620822", "甘肃省平凉市灵台县");
-        map.insert("620823", "甘肃省平凉市崇信县");
-        map.insert("620825", "甘肃省平凉市庄浪县");
-        map.insert("620826", "甘肃省平凉市静宁县");
-        map.insert("620881", "甘肃省平凉市华亭市");
-        map.insert("620900", "甘肃省酒泉市");
-        map.insert("620902", "甘肃省酒泉市肃州区");
-        map.insert("620921", "甘肃省酒泉市金塔县");
-        map.insert("620922", "甘肃省酒泉市瓜州县");
-        map.insert("620923", "甘肃省酒泉市肃北蒙古族自治县");
-        map.insert("620924", "甘肃省酒泉市阿克塞哈萨克族自治县");
-        map.insert("620981", "甘肃省酒泉市玉门市");
-        map.insert("620982", "甘肃省酒泉市敦煌市");
-        map.insert("621000", "甘肃省庆阳市");
-        map.insert("621002", "甘肃省庆阳市西峰区");
-        map.insert("621021", "甘肃省庆阳市庆城县");
-        map.insert("621022", "甘肃省庆阳市环县");
-        map.insert("621023", "甘肃省庆阳市华池县");
-        map.insert("621024", "甘肃省庆阳市合水县");
-        map.insert("621025", "甘肃省庆阳市正宁县");
-        map.insert("621026", "甘肃省庆阳市宁县");
-        map.insert("621027", "甘肃省庆阳市镇原县");
-        map.insert("621100", "甘肃省定西市");
-        map.insert("621102", "甘肃省定西市安定区");
-        map.insert("621121", "甘肃省定西市通渭县");
-        map.insert("621122", "甘肃省定西市陇西县");
-        map.insert("621123", "甘肃省定西市渭源县");
-        map.insert("621124", "甘肃省定西市临洮县");
-        map.insert("621125", "甘肃省定西市漳县");
-        map.insert("621126", "甘肃省定西市岷县");
-        map.insert("621200", "甘肃省陇南市");
-        map.insert("621202", "甘肃省陇南市武都区");
-        map.insert("621221", "甘肃省陇南市成县");
-        map.insert("621222", "甘肃省陇南市文县");
-        map.insert("621223", "甘肃省陇南市宕昌县");
-        map.insert("621224", "甘肃省陇南市康县");
-        map.insert("621225", "甘肃省陇南市西和县");
-        map.insert("621226", "甘肃省陇南市礼县");
-        map.insert("621227", "甘肃省陇南市徽县");
-        map.insert("621228", "甘肃省陇南市两当县");
-        map.insert("622900", "甘肃省临夏回族自治州");
-        map.insert("622901", "甘肃省临夏回族自治州临夏市");
-        map.insert("622921", "甘肃省临夏回族自治州临夏县");
-        map.insert("622922", "甘肃省临夏回族自治州康乐县");
-        map.insert("622923", "甘肃省临夏回族自治州永靖县");
-        map.insert("622924", "甘肃省临夏回族自治州广河县");
-        map.insert("622925", "甘肃省临夏回族自治州和政县");
-        map.insert("622926", "甘肃省临夏回族自治州东乡族自治县");
-        map.insert("622927", "甘肃省临夏回族自治州积石山保安族东乡族撒拉族自治县");
-        map.insert("623000", "甘肃省甘南藏族自治州");
-        map.insert("623001", "甘肃省甘南藏族自治州合作市");
-        map.insert("623021", "甘肃省甘南藏族自治州临潭县");
-        map.insert("623022", "甘肃省甘南藏族自治州卓尼县");
-        map.insert("623023", "甘肃省甘南藏族自治州舟曲县");
-        map.insert("623024", "甘肃省甘南藏族自治州迭部县");
-        map.insert("623025", "甘肃省甘南藏族自治州玛曲县");
-        map.insert("623026", "甘肃省甘南藏族自治州碌曲县");
-        map.insert("623027", "甘肃省甘南藏族自治州夏河县");
-        map.insert("630000", "青海省");
-        map.insert("630100", "青海省西宁市");
-        map.insert("630102", "青海省西宁市城东区");
-        map.insert("630103", "青海省西宁市城中区");
-        map.insert("630104", "青海省西宁市城西区");
-        map.insert("630105", "青海省西宁市城北区");
-        map.insert("630106", "青海省西宁市湟中区");
-        map.insert("630121", "青海省西宁市大通回族土族自治县");
-        map.insert("630123", "青海省西宁市湟源县");
-        map.insert("630200", "青海省海东市");
-        map.insert("630202", "青海省海东市乐都区");
-        map.insert("630203", "青海省海东市平安区");
-        map.insert("630222", "青海省海东市民和回族土族自治县");
-        map.insert("630223", "青海省海东市互助土族自治县");
-        map.insert("630224", "青海省海东市化隆回族自治县");
-        map.insert("630225", "青海省海东市循化撒拉族自治县");
-        map.insert("632200", "青海省海北藏族自治州");
-        map.insert("632221", "青海省海北藏族自治州门源回族自治县");
-        map.insert("632222", "青海省海北藏族自治州祁连县");
-        map.insert("632223", "青海省海北藏族自治州海晏县");
-        map.insert("632224", "青海省海北藏族自治州刚察县");
-        map.insert("632300", "青海省黄南藏族自治州");
-        map.insert("632301", "青海省黄南藏族自治州同仁市");
-        map.insert("632322", "青海省黄南藏族自治州尖扎县");
-        map.insert("632323", "青海省黄南藏族自治州泽库县");
-        map.insert("632324", "青海省黄南藏族自治州河南蒙古族自治县");
-        map.insert("632500", "青海省海南藏族自治州");
-        map.insert("632521", "青海省海南藏族自治州共和县");
-        map.insert("632522", "青海省海南藏族自治州同德县");
-        map.insert("632523", "青海省海南藏族自治州贵德县");
-        map.insert("632524", "青海省海南藏族自治州兴海县");
-        map.insert("632525", "青海省海南藏族自治州贵南县");
-        map.insert("632600", "青海省果洛藏族自治州");
-        map.insert("632621", "青海省果洛藏族自治州玛沁县");
-        map.insert("632622", "青海省果洛藏族自治州班玛县");
-        map.insert("632623", "青海省果洛藏族自治州甘德县");
-        map.insert("632624", "青海省果洛藏族自治州达日县");
-        map.insert("632625", "青海省果洛藏族自治州久治县");
-        map.insert("632626", "青海省果洛藏族自治州玛多县");
-        map.insert("632700", "青海省玉树藏族自治州");
-        map.insert("632701", "青海省玉树藏族自治州玉树市");
-        map.insert("632722", "青海省玉树藏族自治州杂多县");
-        map.insert("632723", "青海省玉树藏族自治州称多县");
-        map.insert("632724", "青海省玉树藏族自治州治多县");
-        map.insert("632725", "青海省玉树藏族自治州囊谦县");
-        map.insert("632726", "青海省玉树藏族自治州曲麻莱县");
-        map.insert("632800", "青海省海西蒙古族藏族自治州");
-        map.insert("632801", "青海省海西蒙古族藏族自治州格尔木市");
-        map.insert("632802", "青海省海西蒙古族藏族自治州德令哈市");
-        map.insert("632803", "青海省海西蒙古族藏族自治州茫崖市");
-        map.insert("632821", "青海省海西蒙古族藏族自治州乌兰县");
-        map.insert("632822", "青海省海西蒙古族藏族自治州都兰县");
-        map.insert("632823", "青海省海西蒙古族藏族自治州天峻县");
-        map.insert("640000", "宁夏回族自治区");
-        map.insert("640100", "宁夏回族自治区银川市");
-        map.insert("640104", "宁夏回族自治区银川市兴庆区");
-        map.insert("640105", "宁夏回族自治区银川市西夏区");
-        map.insert("640106", "宁夏回族自治区银川市金凤区");
-        map.insert("640121", "宁夏回族自治区银川市永宁县");
-        map.insert("640122", "宁夏回族自治区银川市贺兰县");
-        map.insert("640181", "宁夏回族自治区银川市灵武市");
-        map.insert("640200", "宁夏回族自治区石嘴山市");
-        map.insert("640202", "宁夏回族自治区石嘴山市大武口区");
-        map.insert("640205", "宁夏回族自治区石嘴山市惠农区");
-        map.insert("640221", "宁夏回族自治区石嘴山市平罗县");
-        map.insert("640300", "宁夏回族自治区吴忠市");
-        map.insert("640302", "宁夏回族自治区吴忠市利通区");
-        map.insert("640303", "宁夏回族自治区吴忠市红寺堡区");
-        map.insert("640323", "宁夏回族自治区吴忠市盐池县");
-        map.insert("640324", "宁夏回族自治区吴忠市同心县");
-        map.insert("640381", "宁夏回族自治区吴忠市青铜峡市");
-        map.insert("640400", "宁夏回族自治区固原市");
-        map.insert("640402", "宁夏回族自治区固原市原州区");
-        map.insert("640422", "宁夏回族自治区固原市西吉县");
-        map.insert("640423", "宁夏回族自治区固原市隆德县");
-        map.insert("640424", "宁夏回族自治区固原市泾源县");
-        map.insert("640425", "宁夏回族自治区固原市彭阳县");
-        map.insert("640500", "宁夏回族自治区中卫市");
-        map.insert("640502", "宁夏回族自治区中卫市沙坡头区");
-        map.insert("640521", "宁夏回族自治区中卫市中宁县");
-        map.insert("640522", "宁夏回族自治区中卫市海原县");
-        map.insert("650000", "新疆维吾尔自治区");
-        map.insert("650100", "新疆维吾尔自治区乌鲁木齐市");
-        map.insert("650102", "新疆维吾尔自治区乌鲁木齐市天山区");
-        map.insert("650103", "新疆维吾尔自治区乌鲁木齐市沙依巴克区");
-        map.insert("650104", "新疆维吾尔自治区乌鲁木齐市新市区");
-        map.insert("650105", "新疆维吾尔自治区乌鲁木齐市水磨沟区");
-        map.insert("650106", "新疆维吾尔自治区乌鲁木齐市头屯河区");
-        map.insert("650107", "新疆维吾尔自治区乌鲁木齐市达坂城区");
-        map.insert("650109", "新疆维吾尔自治区乌鲁木齐市米东区");
-        map.insert("650121", "新疆维吾尔自治区乌鲁木齐市乌鲁木齐县");
-        map.insert("650200", "新疆维吾尔自治区克拉玛依市");
-        map.insert("650202", "新疆维吾尔自治区克拉玛依市独山子区");
-        map.insert("650203", "新疆维吾尔自治区克拉玛依市克拉玛依区");
-        map.insert("650204", "新疆维吾尔自治区克拉玛依市白碱滩区");
-        map.insert("650205", "新疆维吾尔自治区克拉玛依市乌尔禾区");
-        map.insert("650400", "新疆维吾尔自治区吐鲁番市");
-        map.insert("650402", "新疆维吾尔自治区吐鲁番市高昌区");
-        map.insert("650421", "新疆维吾尔自治区吐鲁番市鄯善县");
-        map.insert("650422", "新疆维吾尔自治区吐鲁番市托克逊县");
-        map.insert("650500", "新疆维吾尔自治区哈密市");
-        map.insert("650502", "新疆维吾尔自治区哈密市伊州区");
-        map.insert("650521", "新疆维吾尔自治区哈密市巴里坤哈萨克自治县");
-        map.insert("650522", "新疆维吾尔自治区哈密市伊吾县");
-        map.insert("652300", "新疆维吾尔自治区昌吉回族自治州");
-        map.insert("652301", "新疆维吾尔自治区昌吉回族自治州昌吉市");
-        map.insert("652302", "新疆维吾尔自治区昌吉回族自治州阜康市");
-        map.insert("652323", "新疆维吾尔自治区昌吉回族自治州呼图壁县");
-        map.insert("652324", "新疆维吾尔自治区昌吉回族自治州玛纳斯县");
-        map.insert("652325", "新疆维吾尔自治区昌吉回族自治州奇台县");
-        map.insert("652327", "新疆维吾尔自治区昌吉回族自治州吉木萨尔县");
-        map.insert("652328", "新疆维吾尔自治区昌吉回族自治州木垒哈萨克自治县");
-        map.insert("652700", "新疆维吾尔自治区博尔塔拉蒙古自治州");
-        map.insert("652701", "新疆维吾尔自治区博尔塔拉蒙古自治州博乐市");
-        map.insert("652702", "新疆维吾尔自治区博尔塔拉蒙古自治州阿拉山口市");
-        map.insert("652722", "新疆维吾尔自治区博尔塔拉蒙古自治州精河县");
-        map.insert("652723", "新疆维吾尔自治区博尔塔拉蒙古自治州温泉县");
-        map.insert("652800", "新疆维吾尔自治区巴音郭楞蒙古自治州");
-        map.insert("652801", "新疆维吾尔自治区巴音郭楞蒙古自治州库尔勒市");
-        map.insert("652822", "新疆维吾尔自治区巴音郭楞蒙古自治州轮台县");
-        map.insert("652823", "新疆维吾尔自治区巴音郭楞蒙古自治州尉犁县");
-        map.insert("652824", "新疆维吾尔自治区巴音郭楞蒙古自治州若羌县");
-        map.insert("652825", "新疆维吾尔自治区巴音郭楞蒙古自治州且末县");
-        map.insert("652826", "新疆维吾尔自治区巴音郭楞蒙古自治州焉耆回族自治县");
-        map.insert("652827", "新疆维吾尔自治区巴音郭楞蒙古自治州和静县");
-        map.insert("652828", "新疆维吾尔自治区巴音郭楞蒙古自治州和硕县");
-        map.insert("652829", "新疆维吾尔自治区巴音郭楞蒙古自治州博湖县");
-        map.insert("652900", "新疆维吾尔自治区阿克苏地区");
-        map.insert("652901", "新疆维吾尔自治区阿克苏地区阿克苏市");
-        map.insert("652902", "新疆维吾尔自治区阿克苏地区库车市");
-        map.insert("652922", "新疆维吾尔自治区阿克苏地区温宿县");
-        map.insert("652924", "新疆维吾尔自治区阿克苏地区沙雅县");
-        map.insert("652925", "新疆维吾尔自治区阿克苏地区新和县");
-        map.insert("652926", "新疆维吾尔自治区阿克苏地区拜城县");
-        map.insert("652927", "新疆维吾尔自治区阿克苏地区乌什县");
-        map.insert("652928", "新疆维吾尔自治区阿克苏地区阿瓦提县");
-        map.insert("652929", "新疆维吾尔自治区阿克苏地区柯坪县");
-        map.insert("653000", "新疆维吾尔自治区克孜勒苏柯尔克孜自治州");
-        map.insert("653001", "新疆维吾尔自治区克孜勒苏柯尔克孜自治州阿图什市");
-        map.insert("653022", "新疆维吾尔自治区克孜勒苏柯尔克孜自治州阿克陶县");
-        map.insert("653023", "新疆维吾尔自治区克孜勒苏柯尔克孜自治州阿合奇县");
-        map.insert("653024", "新疆维吾尔自治区克孜勒苏柯尔克孜自治州乌恰县");
-        map.insert("653100", "新疆维吾尔自治区喀什地区");
-        map.insert("653101", "新疆维吾尔自治区喀什地区喀什市");
-        map.insert("653121", "新疆维吾尔自治区喀什地区疏附县");
-        map.insert("653122", "新疆维吾尔自治区喀什地区疏勒县");
-        map.insert("653123", "新疆维吾尔自治区喀什地区英吉沙县");
-        map.insert("653124", "新疆维吾尔自治区喀什地区泽普县");
-        map.insert("653125", "新疆维吾尔自治区喀什地区莎车县");
-        map.insert("653126", "新疆维吾尔自治区喀什地区叶城县");
-        map.insert("653127", "新疆维吾尔自治区喀什地区麦盖提县");
-        map.insert("653128", "新疆维吾尔自治区喀什地区岳普湖县");
-        map.insert("653129", "新疆维吾尔自治区喀什地区伽师县");
-        map.insert("653130", "新疆维吾尔自治区喀什地区巴楚县");
-        map.insert("653131", "新疆维吾尔自治区喀什地区塔什库尔干塔吉克自治县");
-        map.insert("653200", "新疆维吾尔自治区和田地区");
-        map.insert("653201", "新疆维吾尔自治区和田地区和田市");
-        map.insert("653221", "新疆维吾尔自治区和田地区和田县");
-        map.insert("653222", "新疆维吾尔自治区和田地区墨玉县");
-        map.insert("653223", "新疆维吾尔自治区和田地区皮山县");
-        map.insert("653224", "新疆维吾尔自治区和田地区洛浦县");
-        map.insert("653225", "新疆维吾尔自治区和田地区策勒县");
-        map.insert("653226", "新疆维吾尔自治区和田地区于田县");
-        map.insert("653227", "新疆维吾尔自治区和田地区民丰县");
-        map.insert("654000", "新疆维吾尔自治区伊犁哈萨克自治州");
-        map.insert("654002", "新疆维吾尔自治区伊犁哈萨克自治州伊宁市");
-        map.insert("654003", "新疆维吾尔自治区伊犁哈萨克自治州奎屯市");
-        map.insert("654004", "新疆维吾尔自治区伊犁哈萨克自治州霍尔果斯市");
-        map.insert("654021", "新疆维吾尔自治区伊犁哈萨克自治州伊宁县");
-        map.insert("654022", "新疆维吾尔自治区伊犁哈萨克自治州察布查尔锡伯自治县");
-        map.insert("654023", "新疆维吾尔自治区伊犁哈萨克自治州霍城县");
-        map.insert("654024", "新疆维吾尔自治区伊犁哈萨克自治州巩留县");
-        map.insert("654025", "新疆维吾尔自治区伊犁哈萨克自治州新源县");
-        map.insert("654026", "新疆维吾尔自治区伊犁哈萨克自治州昭苏县");
-        map.insert("654027", "新疆维吾尔自治区伊犁哈萨克自治州特克斯县");
-        map.insert("654028", "新疆维吾尔自治区伊犁哈萨克自治州尼勒克县");
-        map.insert("654200", "新疆维吾尔自治区塔城地区");
-        map.insert("654201", "新疆维吾尔自治区塔城地区塔城市");
-        map.insert("654202", "新疆维吾尔自治区塔城地区乌苏市");
-        map.insert("654203", "新疆维吾尔自治区塔城地区沙湾市");
-        map.insert("654221", "新疆维吾尔自治区塔城地区额敏县");
-        map.insert("654224", "新疆维吾尔自治区塔城地区托里县");
-        map.insert("654225", "新疆维吾尔自治区塔城地区裕民县");
-        map.insert("654226", "新疆维吾尔自治区塔城地区和布克赛尔蒙古自治县");
-        map.insert("654300", "新疆维吾尔自治区阿勒泰地区");
-        map.insert("654301", "新疆维吾尔自治区阿勒泰地区阿勒泰市");
-        map.insert("654321", "新疆维吾尔自治区阿勒泰地区布尔津县");
-        map.insert("654322", "新疆维吾尔自治区阿勒泰地区富蕴县");
-        map.insert("654323", "新疆维吾尔自治区阿勒泰地区福海县");
-        map.insert("654324", "新疆维吾尔自治区阿勒泰地区哈巴河县");
-        map.insert("654325", "新疆维吾尔自治区阿勒泰地区青河县");
-        map.insert("654326", "新疆维吾尔自治区阿勒泰地区吉木乃县");
-        map.insert("659001", "新疆维吾尔自治区石河子市");
-        map.insert("659002", "新疆维吾尔自治区阿拉尔市");
-        map.insert("659003", "新疆维吾尔自治区图木舒克市");
-        map.insert("659004", "新疆维吾尔自治区五家渠市");
-        map.insert("659005", "新疆维吾尔自治区北屯市");
-        map.insert("659006", "新疆维吾尔自治区铁门关市");
-        map.insert("659007", "新疆维吾尔自治区双河市");
-        map.insert("659008", "新疆维吾尔自治区可克达拉市");
-        map.insert("659009", "新疆维吾尔自治区昆玉市");
-        map.insert("659010", "新疆维吾尔自治区胡杨河市");
-        map.insert("659011", "新疆维吾尔自治区新星市");
-        map.insert("710000", "台湾省");
-        map.insert("810000", "香港特别行政区");
-        map.insert("820000", "澳门特别行政区");
-        map
-    };
-    static ref CODES: Vec<&'static str> = REGIONS.keys().map(|k| *k).collect();
-}
+use std::ops::Range;
+use std::sync::RwLock;
+
+mod data;
+mod roman;
+
+use data::{AREA_CODES, CODES, HISTORY, KEYS, NAMES, PINYIN, POSTCODES};
+
+const CODE_LEN: usize = 6;
 
 /// Returns the region name that matches the given code.
 pub fn query(code: &str) -> Option<&str> {
-    if code.len() != 6 {
+    name_by_key(code_key(code)?)
+}
+
+/// Returns the region name that the given code had in the given year, which
+/// also covers the abolished codes, e.g. `110103` is `北京市崇文区` before
+/// 2010.
+pub fn query_at(code: &str, year: u32) -> Option<&'static str> {
+    history(code)
+        .iter()
+        .find(|&&(_, until, _, _)| year < until)
+        .map(|&(_, _, name, _)| name)
+        .filter(|_| cfg!(feature = "region-names"))
+        .or_else(|| name_by_key(code_key(code)?))
+}
+
+/// The status of a region code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeStatus {
+    /// The code is in use.
+    Active,
+    /// The region was given the new code, e.g. a county turned into a
+    /// district.
+    Renamed(&'static str),
+    /// The region was merged into the region with the code.
+    MergedInto(&'static str),
+    /// The region was abolished without a single successor, e.g. split up.
+    Abolished,
+}
+
+/// Returns the status of the given code, or `None` if it's unknown.
+pub fn status(code: &str) -> Option<CodeStatus> {
+    if region_by_key(code_key(code)?).is_some() {
+        return Some(CodeStatus::Active);
+    }
+    history(code).last().map(|&(_, _, _, status)| status)
+}
+
+/// Returns the code in use that the given code maps to, following the renames
+/// and merges, e.g. `310225`(南汇县) gives `310115`(浦东新区). An active code
+/// gives itself, and an abolished or unknown code gives `None`.
+pub fn successor(code: &str) -> Option<&'static str> {
+    match status(code)? {
+        CodeStatus::Active => lookup(code).map(|r| r.code()),
+        CodeStatus::Renamed(next) | CodeStatus::MergedInto(next) => successor(next),
+        CodeStatus::Abolished => None,
+    }
+}
+
+/// Returns the historical entries of the given code, sorted by year.
+fn history(code: &str) -> &'static [(&'static str, u32, &'static str, CodeStatus)] {
+    let start = HISTORY.partition_point(|&(c, _, _, _)| c < code);
+    let end = HISTORY.partition_point(|&(c, _, _, _)| c <= code);
+    &HISTORY[start..end]
+}
+
+/// A source of the division data, which can be installed by `set_provider` (or
+/// on a `ValidationPolicy`) in place of the built-in database.
+pub trait RegionProvider: Send + Sync {
+    /// Returns the region name that matches the given code.
+    fn name(&self, code: &str) -> Option<&str>;
+
+    /// Returns the region name that the given code had in the given year, the
+    /// same as `name` by default.
+    fn name_at(&self, code: &str, year: u32) -> Option<&str> {
+        let _ = year;
+        self.name(code)
+    }
+
+    /// Returns whether the given code was in use in the given year, the same as
+    /// `name_at(code, year).is_some()` by default.
+    fn contains_at(&self, code: &str, year: u32) -> bool {
+        self.name_at(code, year).is_some()
+    }
+
+    /// Returns the codes which start with the given prefix, the fake numbers
+    /// are generated from them.
+    fn codes_with_prefix<'a>(&'a self, prefix: &str) -> Box<dyn Iterator<Item = &'a str> + 'a>;
+}
+
+/// The built-in division database.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuiltinProvider;
+
+impl RegionProvider for BuiltinProvider {
+    fn name(&self, code: &str) -> Option<&str> {
+        name_by_key(code_key(code)?)
+    }
+
+    fn name_at(&self, code: &str, year: u32) -> Option<&str> {
+        query_at(code, year)
+    }
+
+    fn contains_at(&self, code: &str, year: u32) -> bool {
+        history(code).iter().any(|&(_, until, _, _)| year < until) || lookup(code).is_some()
+    }
+
+    fn codes_with_prefix<'a>(&'a self, prefix: &str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        Box::new(with_prefix(prefix).map(|i| -> &str { code_at(i) }))
+    }
+}
+
+static PROVIDER: RwLock<Option<&'static dyn RegionProvider>> = RwLock::new(None);
+
+/// Installs the provider globally, which is consulted by `Identity::region`,
+/// the validation and the fake numbers instead of the built-in database.
+pub fn set_provider(provider: &'static dyn RegionProvider) {
+    *PROVIDER.write().unwrap_or_else(|e| e.into_inner()) = Some(provider);
+}
+
+/// Uninstalls the global provider, the built-in database is used again.
+pub fn reset_provider() {
+    *PROVIDER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Returns the global provider, or the built-in one if none is installed.
+pub fn provider() -> &'static dyn RegionProvider {
+    PROVIDER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .unwrap_or(&BuiltinProvider)
+}
+
+/// Returns the name of the region with the given numeric code.
+fn name_by_key(key: u32) -> Option<&'static str> {
+    KEYS.binary_search(&key)
+        .ok()
+        .and_then(|i| NAMES.get(i).copied())
+}
+
+/// A region in the division database, with its province, city and district
+/// levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    code: &'static str,
+    key: u32,
+}
+
+impl Region {
+    /// Returns the 6-digit region code.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the full region name, e.g. `四川省达州市通川区`.
+    pub fn name(&self) -> &'static str {
+        name_by_key(self.key).unwrap_or_default()
+    }
+
+    /// Returns the province code(the first 2 digits).
+    pub fn province_code(&self) -> &'static str {
+        &self.code[0..2]
+    }
+
+    /// Returns the city code(the first 4 digits).
+    pub fn city_code(&self) -> &'static str {
+        &self.code[0..4]
+    }
+
+    /// Returns the district code(all the 6 digits).
+    pub fn district_code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the province name, e.g. `四川省`.
+    pub fn province(&self) -> Option<&'static str> {
+        name_by_key(self.province_key())
+    }
+
+    /// Returns the city name without the province, e.g. `达州市`. It returns
+    /// `None` for a province, or a region not belonging to any city(e.g. the
+    /// districts of the municipalities).
+    pub fn city(&self) -> Option<&'static str> {
+        let city_key = self.city_key();
+        if city_key == self.province_key() {
+            return None;
+        }
+        let city = name_by_key(city_key)?;
+        city.strip_prefix(self.province().unwrap_or_default())
+    }
+
+    /// Returns the district name without the province and the city, e.g.
+    /// `通川区`. It returns `None` for a province or a city.
+    pub fn district(&self) -> Option<&'static str> {
+        if self.key == self.city_key() {
+            return None;
+        }
+        let parent = name_by_key(self.city_key())
+            .or_else(|| self.province())
+            .unwrap_or_default();
+        self.name().strip_prefix(parent)
+    }
+
+    /// Returns the full name in pinyin, e.g. `Sichuan Sheng Dazhou Shi
+    /// Tongchuan Qu`.
+    pub fn name_pinyin(&self) -> Option<String> {
+        let names: Option<Vec<_>> = self.levels().map(roman::name_pinyin).collect();
+        Some(names?.join(" "))
+    }
+
+    /// Returns the full name in English, from the province to the district,
+    /// e.g. `Sichuan, Dazhou, Tongchuan District`.
+    pub fn name_en(&self) -> Option<String> {
+        let names: Option<Vec<_>> = self.levels().map(roman::name_en).collect();
+        Some(names?.join(", "))
+    }
+
+    /// Returns the administrative level.
+    pub fn level(&self) -> Level {
+        key_level(self.key)
+    }
+
+    /// Checks if the region has no subdivisions, i.e. a county-level division,
+    /// or a city or province without any in the database(e.g. `441900`).
+    pub fn is_leaf(&self) -> bool {
+        children(self.code).next().is_none()
+    }
+
+    /// Returns the postcode of a city, e.g. `310000` for `杭州市`. The data is
+    /// at the city level, i.e. the provinces, the prefectures and the
+    /// county-level cities directly under a province(e.g. `济源市`), it
+    /// returns `None` for the other county-level divisions, as their postcodes
+    /// differ from the city's. There is no county-level `postal_code`, since
+    /// no county-level postcode dataset under a compatible license is at hand.
+    pub fn city_postal_code(&self) -> Option<&'static str> {
+        let i = KEYS.binary_search(&self.key).ok()?;
+        POSTCODES.get(i).copied().filter(|s| !s.is_empty())
+    }
+
+    /// Returns the telephone area code, or the nearest ancestor's, e.g. `0571`
+    /// for `杭州市西湖区`.
+    pub fn area_code(&self) -> Option<&'static str> {
+        self.nearest(&AREA_CODES)
+    }
+
+    /// Returns the approximate coordinates(latitude, longitude) of the seat.
+    /// The coordinates are known for the provinces, the prefectures and a few
+    /// county-level cities, the other county-level divisions inherit their
+    /// city's, e.g. `(30.27, 120.16)` of `杭州市` for `杭州市西湖区`. See
+    /// `centroid_with_level` to tell the inherited ones.
+    #[cfg(feature = "geo")]
+    pub fn centroid(&self) -> Option<(f64, f64)> {
+        self.centroid_with_level().map(|(centroid, _)| centroid)
+    }
+
+    /// Returns the approximate coordinates like `centroid`, along with the
+    /// level of the region they belong to, which is higher than the region's
+    /// own level if they are inherited, e.g. `Level::Prefecture` for
+    /// `杭州市西湖区`.
+    #[cfg(feature = "geo")]
+    pub fn centroid_with_level(&self) -> Option<((f64, f64), Level)> {
+        self.levels().rev().find_map(|i| {
+            let centroid = data::CENTROIDS.get(i).copied().flatten()?;
+            Some((centroid, key_level(KEYS[i])))
+        })
+    }
+
+    /// Returns the first non-empty entry of the table, from the region itself
+    /// up to the province.
+    fn nearest(&self, table: &[&'static str]) -> Option<&'static str> {
+        self.levels()
+            .rev()
+            .filter_map(|i| table.get(i).copied())
+            .find(|s| !s.is_empty())
+    }
+
+    /// Returns the table indices of the province, the city and the region
+    /// itself, skipping the missing levels.
+    fn levels(&self) -> impl DoubleEndedIterator<Item = usize> {
+        let mut keys = vec![self.province_key(), self.city_key(), self.key];
+        keys.dedup();
+        keys.into_iter()
+            .filter_map(|key| KEYS.binary_search(&key).ok())
+    }
+
+    fn province_key(&self) -> u32 {
+        self.key / 10000 * 10000
+    }
+
+    fn city_key(&self) -> u32 {
+        self.key / 100 * 100
+    }
+}
+
+/// Returns an iterator over all the pairs of code and full name, sorted by
+/// code.
+pub fn iter() -> impl Iterator<Item = (&'static str, &'static str)> {
+    NAMES
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (code_at(i), *name))
+}
+
+/// Returns an iterator over the county-level codes which start with the given
+/// prefix, e.g. `3301` gives all the districts of Hangzhou.
+pub fn codes_with_prefix(prefix: &str) -> impl Iterator<Item = &'static str> {
+    with_prefix(prefix)
+        .filter(|&i| key_level(KEYS[i]) == Level::County)
+        .map(code_at)
+}
+
+/// Returns the English name of the region that matches the given code, e.g.
+/// `Sichuan, Dazhou, Tongchuan District`.
+pub fn query_en(code: &str) -> Option<String> {
+    lookup(code)?.name_en()
+}
+
+/// Returns the postcode(邮政编码) of the city that matches the given code,
+/// see `Region::city_postal_code`.
+pub fn city_postal_code(code: &str) -> Option<&'static str> {
+    lookup(code)?.city_postal_code()
+}
+
+/// Returns the fixed-line area code(长途区号) of the region that matches the
+/// given code, see `Region::area_code`.
+pub fn area_code(code: &str) -> Option<&'static str> {
+    lookup(code)?.area_code()
+}
+
+/// Returns the approximate coordinates(latitude, longitude) of the region
+/// that matches the given code, see `Region::centroid`.
+#[cfg(feature = "geo")]
+pub fn centroid(code: &str) -> Option<(f64, f64)> {
+    lookup(code)?.centroid()
+}
+
+/// Returns the approximate coordinates of the region that matches the given
+/// code, along with the level of the region they belong to, see
+/// `Region::centroid_with_level`.
+#[cfg(feature = "geo")]
+pub fn centroid_with_level(code: &str) -> Option<((f64, f64), Level)> {
+    lookup(code)?.centroid_with_level()
+}
+
+/// Returns the region that matches the given code.
+pub fn lookup(code: &str) -> Option<Region> {
+    region_by_key(code_key(code)?)
+}
+
+/// Finds the regions whose names contain the given name, returns the pairs
+/// of code and full name. The regions whose names end with the given name are
+/// listed first, e.g. `杭州市` gives the city before its districts.
+pub fn find_by_name(name: &str) -> Vec<(&'static str, &'static str)> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Vec::new();
+    }
+    let mut found: Vec<_> = NAMES
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| n.contains(name))
+        .map(|(i, n)| (code_at(i), *n))
+        .collect();
+    found.sort_by_key(|(_, n)| !n.ends_with(name));
+    found
+}
+
+/// Searches the regions by a Chinese or pinyin name with small typos tolerated,
+/// e.g. both `hangzhou` and `杭洲` give `杭州市`. The name is matched against
+/// the beginning of the region names without their ancestors, returns the
+/// pairs of code and full name, the closest first.
+pub fn search(name: &str) -> Vec<(&'static str, &'static str)> {
+    let query: Vec<char> = name
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let pinyin = query.iter().all(char::is_ascii_lowercase);
+    let tolerance = if pinyin {
+        query.len() / 4
+    } else {
+        query.len() / 2
+    };
+
+    let mut found: Vec<_> = (0..NAMES.len())
+        .filter_map(|i| {
+            let (distance, prefix) = if pinyin {
+                edit_distance(&query, PINYIN[i].split(' ').map(str::chars))
+            } else {
+                edit_distance(&query, short_name(i).chars().map(std::iter::once))
+            };
+            if prefix > tolerance {
+                return None;
+            }
+            Some(((prefix, key_level(KEYS[i]), distance), i))
+        })
+        .collect();
+    found.sort();
+    found
+        .into_iter()
+        .map(|(_, i)| (code_at(i), NAMES[i]))
+        .collect()
+}
+
+/// Returns the edit distance between the query and the candidate, and the
+/// smallest one between the query and the prefixes of the candidate which end
+/// at a syllable(or character) boundary.
+fn edit_distance<T, S>(query: &[T], syllables: impl Iterator<Item = S>) -> (usize, usize)
+where
+    T: PartialEq,
+    S: IntoIterator<Item = T>,
+{
+    let mut column: Vec<usize> = (0..=query.len()).collect();
+    let mut prefix = query.len();
+    for syllable in syllables {
+        for c in syllable {
+            let mut diagonal = column[0];
+            column[0] += 1;
+            for (i, q) in query.iter().enumerate() {
+                let above = column[i + 1];
+                column[i + 1] = (diagonal + usize::from(*q != c))
+                    .min(above + 1)
+                    .min(column[i] + 1);
+                diagonal = above;
+            }
+        }
+        prefix = prefix.min(column[query.len()]);
+    }
+    (column[query.len()], prefix)
+}
+
+/// Returns the name of the i-th region without its ancestors, e.g. `通川区`.
+fn short_name(i: usize) -> &'static str {
+    let parent = parent_key(KEYS[i])
+        .and_then(name_by_key)
+        .unwrap_or_default();
+    NAMES[i].strip_prefix(parent).unwrap_or(NAMES[i])
+}
+
+/// The administrative level of a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Level {
+    /// A province, an autonomous region, a municipality or an SAR, e.g. `33`.
+    Province,
+    /// A prefecture-level city, prefecture, league or autonomous prefecture,
+    /// e.g. `3301`.
+    Prefecture,
+    /// A county-level division, e.g. `330106`.
+    County,
+}
+
+/// Returns the level of the given 2/4/6-digit code, or `None` if it's
+/// unknown, e.g. `3301` is `Level::Prefecture`.
+pub fn level(code: &str) -> Option<Level> {
+    padded_key(code).and_then(region_by_key).map(|r| r.level())
+}
+
+/// Returns the level by the trailing zeros of the key.
+fn key_level(key: u32) -> Level {
+    if key / 10000 * 10000 == key {
+        Level::Province
+    } else if key / 100 * 100 == key {
+        Level::Prefecture
+    } else {
+        Level::County
+    }
+}
+
+/// Returns the parent region of the given 2/4/6-digit code, i.e. the city of
+/// a district(or the province if the district doesn't belong to any city) and
+/// the province of a city.
+pub fn parent(code: &str) -> Option<Region> {
+    let key = padded_key(code)?;
+    parent_key(key).and_then(region_by_key)
+}
+
+/// Returns the direct children of the given 2/4/6-digit code, e.g. `3301`
+/// gives all the districts of Hangzhou.
+pub fn children(code: &str) -> impl Iterator<Item = Region> {
+    let key = padded_key(code);
+    let range = match key {
+        Some(key) if key % 10000 == 0 => key_range(key, key + 10000),
+        Some(key) if key % 100 == 0 => key_range(key, key + 100),
+        _ => 0..0,
+    };
+    range
+        .map(|i| Region {
+            code: code_at(i),
+            key: KEYS[i],
+        })
+        .filter(move |r| key.is_some() && parent_key(r.key) == key)
+}
+
+fn region_by_key(key: u32) -> Option<Region> {
+    let i = KEYS.binary_search(&key).ok()?;
+    Some(Region {
+        code: code_at(i),
+        key,
+    })
+}
+
+/// Returns the key of the nearest existing ancestor.
+fn parent_key(key: u32) -> Option<u32> {
+    let city = key / 100 * 100;
+    if city != key && KEYS.binary_search(&city).is_ok() {
+        return Some(city);
+    }
+    let province = key / 10000 * 10000;
+    if province != key && KEYS.binary_search(&province).is_ok() {
+        return Some(province);
+    }
+    None
+}
+
+/// Parses a 2/4/6-digit code, the missing digits are filled with zeros.
+fn padded_key(code: &str) -> Option<u32> {
+    match code.len() {
+        2 => code_key(&format!("{}0000", code)),
+        4 => code_key(&format!("{}00", code)),
+        _ => code_key(code),
+    }
+}
+
+/// Returns the i-th code in the table.
+fn code_at(i: usize) -> &'static str {
+    &CODES[i * CODE_LEN..(i + 1) * CODE_LEN]
+}
+
+/// Parses a 6-digit code into its numeric value.
+fn code_key(code: &str) -> Option<u32> {
+    if code.len() != CODE_LEN {
         return None;
     }
-    match REGIONS.get(code) {
-        Some(name) => Some(*name),
-        None => None,
+    code.bytes().try_fold(0, |acc, b| {
+        if b.is_ascii_digit() {
+            Some(acc * 10 + u32::from(b - b'0'))
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the index range of the codes which start with the given prefix,
+/// they are adjacent in the sorted table.
+fn with_prefix(prefix: &str) -> Range<usize> {
+    if prefix.len() > CODE_LEN || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+        return 0..0;
     }
+    let value = prefix
+        .bytes()
+        .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'));
+    let scale = 10u32.pow((CODE_LEN - prefix.len()) as u32);
+    key_range(value * scale, (value + 1) * scale)
+}
+
+/// Returns the index range of the keys in `start..end`.
+fn key_range(start: u32, end: u32) -> Range<usize> {
+    KEYS.partition_point(|&k| k < start)..KEYS.partition_point(|&k| k < end)
 }
 
 /// Returns a random region code.
+///
+/// # Panics
+///
+/// Panics if the crate is built without the region table, see `region-minimal`.
+#[cfg(feature = "fake")]
 pub fn rand_code() -> &'static str {
     let mut rng = thread_rng();
-    let i = rng.gen_range(0..CODES.len());
-    CODES[i]
+    code_at(rng.gen_range(0..KEYS.len()))
 }
 
 /// Returns a random region code that matches the given prefix.
+#[cfg(feature = "fake")]
 pub fn rand_code_starts_with(prefix: &str) -> Option<&str> {
     if prefix.is_empty() {
         return None;
     }
-    let found = CODES
-        .iter()
-        .filter(|x| x.starts_with(prefix))
-        .collect::<Vec<_>>();
+    let found = with_prefix(prefix);
     if found.is_empty() {
         return None;
     }
     let mut rng = thread_rng();
-    let i = rng.gen_range(0..found.len());
-    Some(found[i])
+    Some(code_at(rng.gen_range(found)))
 }
 
 #[cfg(test)]
@@ -3259,6 +596,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(all(feature = "region-minimal", feature = "fake"))]
     fn test_rand_code() {
         for i in 1..=10 {
             println!("{}: {}", i, rand_code());
@@ -3266,13 +604,400 @@ mod tests {
     }
 
     #[test]
+    #[cfg(all(feature = "region-minimal", feature = "fake"))]
     fn test_rand_code_starts_with() {
         assert!(rand_code_starts_with("33").unwrap().starts_with("33"));
     }
 
     #[test]
+    fn test_table() {
+        assert_eq!(CODES.len(), KEYS.len() * CODE_LEN);
+        let names = if cfg!(feature = "region-names") {
+            KEYS.len()
+        } else {
+            0
+        };
+        assert_eq!(NAMES.len(), names);
+        assert!((0..KEYS.len()).all(|i| code_key(code_at(i)) == Some(KEYS[i])));
+        assert!(CODES.bytes().all(|b| b.is_ascii_digit()));
+        assert!((1..KEYS.len()).all(|i| code_at(i - 1) < code_at(i)));
+        assert_eq!(PINYIN.len(), names);
+        assert!((0..names).all(|i| PINYIN[i].split(' ').count() == short_name(i).chars().count()));
+        assert_eq!(POSTCODES.len(), names);
+        assert!(POSTCODES.iter().all(|p| p.is_empty() || p.len() == 6));
+        assert_eq!(AREA_CODES.len(), names);
+        assert!(AREA_CODES
+            .iter()
+            .all(|a| a.is_empty() || a.starts_with('0')));
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_area_code() {
+        assert_eq!(area_code("330106"), Some("0571"));
+        assert_eq!(area_code("330200"), Some("0574"));
+        assert_eq!(area_code("110101"), Some("010"));
+        assert_eq!(area_code("469001"), Some("0898"));
+        assert_eq!(area_code("659001"), Some("0993"));
+        assert_eq!(area_code("810000"), None);
+        assert_eq!(area_code("999999"), None);
+        assert!(iter().all(|(code, _)| level(code) == Some(Level::Province)
+            || code.starts_with("71")
+            || code.starts_with('8')
+            || area_code(code).is_some()));
+    }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn test_centroid() {
+        assert_eq!(data::CENTROIDS.len(), KEYS.len());
+        assert_eq!(centroid("330106"), Some((30.27, 120.16)));
+        assert_eq!(centroid("330100"), Some((30.27, 120.16)));
+        assert_eq!(centroid("810000"), Some((22.32, 114.17)));
+        assert_eq!(centroid("999999"), None);
+        assert!(iter().all(|(code, _)| centroid(code).is_some()));
+        assert_eq!(
+            centroid_with_level("330106"),
+            Some(((30.27, 120.16), Level::Prefecture))
+        );
+        assert_eq!(
+            centroid_with_level("330100"),
+            Some(((30.27, 120.16), Level::Prefecture))
+        );
+        assert_eq!(
+            centroid_with_level("419001"),
+            Some(((35.07, 112.60), Level::County))
+        );
+        assert_eq!(centroid_with_level("999999"), None);
+        assert!(data::CENTROIDS
+            .iter()
+            .flatten()
+            .all(|&(lat, lng)| (15.0..54.0).contains(&lat) && (73.0..136.0).contains(&lng)));
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_city_postal_code() {
+        assert_eq!(city_postal_code("330100"), Some("310000"));
+        assert_eq!(city_postal_code("110000"), Some("100000"));
+        assert_eq!(city_postal_code("419001"), Some("459000"));
+        assert_eq!(city_postal_code("810000"), Some("999077"));
+        assert_eq!(city_postal_code("330106"), None);
+        assert_eq!(city_postal_code("110101"), None);
+        assert_eq!(city_postal_code("330000"), None);
+        assert_eq!(city_postal_code("999999"), None);
+        // Every prefecture has a postcode.
+        assert!(iter()
+            .filter(|(code, _)| level(code) == Some(Level::Prefecture))
+            .all(|(code, _)| city_postal_code(code).is_some()));
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_query_en() {
+        assert_eq!(
+            query_en("511702").as_deref(),
+            Some("Sichuan, Dazhou, Tongchuan District")
+        );
+        assert_eq!(
+            query_en("110101").as_deref(),
+            Some("Beijing, Dongcheng District")
+        );
+        assert_eq!(query_en("610100").as_deref(), Some("Shaanxi, Xi'an"));
+        assert_eq!(query_en("810000").as_deref(), Some("Hong Kong"));
+        assert_eq!(
+            query_en("532900").as_deref(),
+            Some("Yunnan, Dali Bai Autonomous Prefecture")
+        );
+        assert_eq!(
+            query_en("530825").as_deref(),
+            Some("Yunnan, Pu'er, Zhenyuan Yi Hani Lahu Autonomous County")
+        );
+        assert_eq!(
+            query_en("652800").as_deref(),
+            Some("Xinjiang, Bayinguoleng Mongol Autonomous Prefecture")
+        );
+        assert_eq!(query_en("999999"), None);
+
+        let r = lookup("511702").unwrap();
+        assert_eq!(
+            r.name_pinyin().as_deref(),
+            Some("Sichuan Sheng Dazhou Shi Tongchuan Qu")
+        );
+        let r = lookup("450000").unwrap();
+        assert_eq!(r.name_pinyin().as_deref(), Some("Guangxi Zhuangzu Zizhiqu"));
+        let r = lookup("610000").unwrap();
+        assert_eq!(r.name_pinyin().as_deref(), Some("Shaanxi Sheng"));
+        let r = lookup("150000").unwrap();
+        assert_eq!(r.name_pinyin().as_deref(), Some("Neimenggu Zizhiqu"));
+        let r = lookup("650000").unwrap();
+        assert_eq!(
+            r.name_pinyin().as_deref(),
+            Some("Xinjiang Weiwu'er Zizhiqu")
+        );
+        assert_eq!(
+            query_en("150724").as_deref(),
+            Some("Inner Mongolia, Hulunbei'er, Ewenki Autonomous Banner")
+        );
+        assert_eq!(query_en("440600").as_deref(), Some("Guangdong, Foshan"));
+
+        assert!((0..KEYS.len()).all(|i| roman::name_en(i).is_some()));
+        assert!((0..KEYS.len()).all(|i| !roman::name_en(i).unwrap().starts_with(' ')));
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
     fn test_query() {
         assert_eq!(query("640000"), Some("宁夏回族自治区"));
         assert_eq!(query("620000"), Some("甘肃省"));
+        assert_eq!(query("110000"), Some("北京市"));
+        assert_eq!(query("820000"), Some("澳门特别行政区"));
+        assert_eq!(query("999999"), None);
+        assert_eq!(query("11"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_iter() {
+        assert_eq!(iter().count(), KEYS.len());
+        assert_eq!(iter().next(), Some(("110000", "北京市")));
+        assert!(iter().any(|r| r == ("511702", "四川省达州市通川区")));
+        assert!(iter().all(|(code, name)| query(code) == Some(name)));
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_codes_with_prefix() {
+        let codes: Vec<_> = codes_with_prefix("3301").collect();
+        assert!(codes.contains(&"330102"));
+        assert!(!codes.contains(&"330100"));
+        assert!(codes.iter().all(|c| c.starts_with("3301")));
+        assert!(codes_with_prefix("33").all(|c| !c.ends_with("00")));
+        assert!(codes_with_prefix("11").any(|c| c == "110101"));
+        assert_eq!(codes_with_prefix("511702").collect::<Vec<_>>(), ["511702"]);
+        assert_eq!(codes_with_prefix("99").count(), 0);
+        assert_eq!(codes_with_prefix("33a").count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_query_at() {
+        assert!(HISTORY
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        assert!(HISTORY.iter().all(|&(c, _, _, _)| code_key(c).is_some()));
+        assert_eq!(query_at("110103", 1990), Some("北京市崇文区"));
+        assert_eq!(query_at("110103", 2010), None);
+        assert_eq!(query_at("330104", 2000), Some("浙江省杭州市江干区"));
+        assert_eq!(query_at("500110", 2000), Some("重庆市万盛区"));
+        assert_eq!(query_at("500110", 2020), query("500110"));
+        assert_eq!(query_at("632123", 2012), Some("青海省海东地区乐都县"));
+        assert_eq!(query_at("632123", 2013), None);
+        assert_eq!(query_at("341421", 2000), Some("安徽省巢湖市庐江县"));
+        assert_eq!(query_at("511702", 1990), Some("四川省达州市通川区"));
+        assert_eq!(query_at("999999", 1990), None);
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_status() {
+        assert_eq!(status("511702"), Some(CodeStatus::Active));
+        assert_eq!(status("500110"), Some(CodeStatus::Active));
+        assert_eq!(status("110228"), Some(CodeStatus::Renamed("110118")));
+        assert_eq!(status("110103"), Some(CodeStatus::MergedInto("110101")));
+        assert_eq!(status("330104"), Some(CodeStatus::Abolished));
+        assert_eq!(status("999999"), None);
+
+        assert_eq!(successor("511702"), Some("511702"));
+        assert_eq!(successor("110228"), Some("110118"));
+        assert_eq!(successor("310225"), Some("310115"));
+        assert_eq!(successor("330104"), None);
+        assert_eq!(successor("999999"), None);
+        assert_eq!(status("632123"), Some(CodeStatus::Renamed("630202")));
+        assert_eq!(successor("341421"), Some("340124"));
+        assert_eq!(successor("341400"), None);
+        // A successor may be abolished later, e.g. `130421`(邯郸县).
+        assert!(HISTORY.iter().all(|&(code, _, _, s)| match s {
+            CodeStatus::Renamed(next) | CodeStatus::MergedInto(next) => {
+                next != code && status(next).is_some()
+            }
+            _ => true,
+        }));
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_provider() {
+        struct Extra;
+
+        impl RegionProvider for Extra {
+            fn name(&self, code: &str) -> Option<&str> {
+                match code {
+                    "710101" => Some("测试区"),
+                    _ => BuiltinProvider.name(code),
+                }
+            }
+
+            fn codes_with_prefix<'a>(
+                &'a self,
+                prefix: &str,
+            ) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+                let extra = Some("710101").filter(|c| c.starts_with(prefix));
+                Box::new(BuiltinProvider.codes_with_prefix(prefix).chain(extra))
+            }
+        }
+
+        assert_eq!(BuiltinProvider.name("511702"), query("511702"));
+        assert_eq!(
+            BuiltinProvider.name_at("110103", 2000),
+            Some("北京市崇文区")
+        );
+        assert_eq!(
+            BuiltinProvider.codes_with_prefix("3301").count(),
+            with_prefix("3301").len()
+        );
+        assert_eq!(Extra.name_at("710101", 2000), Some("测试区"));
+        assert_eq!(
+            Extra.codes_with_prefix("7101").collect::<Vec<_>>(),
+            ["710101"]
+        );
+
+        static EXTRA: Extra = Extra;
+        set_provider(&EXTRA);
+        assert_eq!(provider().name("710101"), Some("测试区"));
+        let id = crate::Identity::new("710101199001010017");
+        assert_eq!(id.region(), Some("测试区"));
+        #[cfg(feature = "fake")]
+        {
+            let id =
+                crate::fake::rand_with(&crate::fake::FakeOptions::new().region("7101")).unwrap();
+            assert!(id.starts_with("710101"));
+        }
+        reset_provider();
+        assert_eq!(provider().name("710101"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_lookup() {
+        let r = lookup("511702").unwrap();
+        assert_eq!(r.code(), "511702");
+        assert_eq!(r.name(), "四川省达州市通川区");
+        assert_eq!((r.province_code(), r.city_code()), ("51", "5117"));
+        assert_eq!(r.district_code(), "511702");
+        assert_eq!(r.province(), Some("四川省"));
+        assert_eq!(r.city(), Some("达州市"));
+        assert_eq!(r.district(), Some("通川区"));
+
+        let r = lookup("110101").unwrap();
+        assert_eq!(r.province(), Some("北京市"));
+        assert_eq!(r.city(), None);
+        assert_eq!(r.district(), Some("东城区"));
+
+        let r = lookup("330100").unwrap();
+        assert_eq!(r.province(), Some("浙江省"));
+        assert_eq!(r.city(), Some("杭州市"));
+        assert_eq!(r.district(), None);
+
+        let r = lookup("330000").unwrap();
+        assert_eq!(r.province(), Some("浙江省"));
+        assert_eq!(r.city(), None);
+        assert_eq!(r.district(), None);
+
+        assert!(lookup("999999").is_none());
+        assert!(lookup("3301").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_parent_children() {
+        assert_eq!(parent("330102").unwrap().code(), "330100");
+        assert_eq!(parent("330100").unwrap().code(), "330000");
+        assert_eq!(parent("3301").unwrap().code(), "330000");
+        assert!(parent("330000").is_none());
+        assert_eq!(parent("110101").unwrap().code(), "110000");
+        assert!(parent("999999").is_none());
+
+        let districts: Vec<_> = children("3301").collect();
+        assert!(!districts.is_empty());
+        assert!(districts.iter().all(|r| r.code().starts_with("3301")));
+        assert!(districts.iter().any(|r| r.code() == "330102"));
+        assert!(districts.iter().all(|r| r.code() != "330100"));
+
+        let cities: Vec<_> = children("33").collect();
+        assert!(cities.iter().any(|r| r.code() == "330100"));
+        assert!(cities.iter().all(|r| r.code().ends_with("00")));
+
+        let districts: Vec<_> = children("110000").collect();
+        assert!(districts.iter().any(|r| r.code() == "110101"));
+        assert_eq!(children("330102").count(), 0);
+        assert_eq!(children("abc").count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_level() {
+        assert_eq!(level("33"), Some(Level::Province));
+        assert_eq!(level("330000"), Some(Level::Province));
+        assert_eq!(level("3301"), Some(Level::Prefecture));
+        assert_eq!(level("330106"), Some(Level::County));
+        assert_eq!(level("3399"), None);
+        assert_eq!(level("abc"), None);
+        assert!(lookup("330106").unwrap().is_leaf());
+        assert!(!lookup("330100").unwrap().is_leaf());
+        assert!(!lookup("330000").unwrap().is_leaf());
+        assert!(lookup("441900").unwrap().is_leaf());
+        assert!(codes_with_prefix("33").all(|c| lookup(c).unwrap().is_leaf()));
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_find_by_name() {
+        assert_eq!(
+            find_by_name("通川区"),
+            vec![("511702", "四川省达州市通川区")]
+        );
+        let found = find_by_name("杭州市");
+        assert_eq!(found[0], ("330100", "浙江省杭州市"));
+        assert!(found.len() > 1);
+        assert!(found.iter().all(|(c, _)| c.starts_with("3301")));
+        assert!(find_by_name("达州").iter().any(|(c, _)| *c == "511702"));
+        assert!(find_by_name("不存在的地方").is_empty());
+        assert!(find_by_name(" ").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "region-names")]
+    fn test_search() {
+        let hangzhou = ("330100", "浙江省杭州市");
+        assert_eq!(search("hangzhou")[0], hangzhou);
+        assert_eq!(search("HangZhou")[0], hangzhou);
+        assert_eq!(search("hangzou")[0], hangzhou);
+        assert_eq!(search("杭洲")[0], hangzhou);
+        assert_eq!(search("杭州市")[0], hangzhou);
+        assert_eq!(search("xi'an")[0].0, "610100");
+        assert_eq!(search("chang sha")[0].0, "430100");
+        assert_eq!(search("通川")[0].0, "511702");
+        assert!(search("chaoyang").iter().any(|(c, _)| *c == "110105"));
+        assert!(search("").is_empty());
+        assert!(search("zzzzzzzz").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "region-minimal")]
+    fn test_with_prefix() {
+        let found = with_prefix("3301");
+        assert!(!found.is_empty());
+        assert!(found.clone().all(|i| code_at(i).starts_with("3301")));
+        assert_eq!(
+            found.len(),
+            (0..KEYS.len())
+                .filter(|&i| code_at(i).starts_with("3301"))
+                .count()
+        );
+        assert!(with_prefix("99").is_empty());
+        assert!(with_prefix("33a").is_empty());
+        assert_eq!(with_prefix(""), 0..KEYS.len());
+        assert_eq!(with_prefix("110101").len(), 1);
     }
 }
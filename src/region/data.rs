@@ -0,0 +1,28 @@
+//! The region data(GB/T 2260), sorted by code.
+//!
+//! The codes are stored as sorted integers(and packed in a single string, 6
+//! bytes each, for borrowing), the names are stored in a parallel table. So a
+//! lookup is a binary search over a contiguous array without any heap
+//! allocation or lazy initialization.
+//!
+//! The tables are generated by the build script from `data/regions.csv`, set
+//! `IDCARD_REGION_DATA` to the path of another file in the same format to
+//! build with a different dataset. The pinyin in `data/regions.csv` is
+//! transliterated by ICU(`Han-Latin; Latin-ASCII`), with the readings special
+//! to place names(e.g. `六安`, `厦门`) corrected by hand. The postcodes, the
+//! area codes and the coordinates are given for the prefecture-level divisions
+//! and the county-level cities directly under the provinces, the other
+//! counties share their city's.
+//!
+//! The history of the codes is generated from `data/region_history.csv`(or
+//! `IDCARD_REGION_HISTORY`). It's derived from the revisions of GB/T 2260
+//! from 2002 to 2016 and the current table, with the codes abolished before
+//! 2002 added, see `data/NOTICE` for the sources. A name dropped between two
+//! revisions is accepted until the year of the later one, e.g. the codes
+//! abolished after 2016 are accepted until 2022 unless the year is known. The
+//! successors are matched by name where a code was renamed, the merges are
+//! recorded by hand.
+
+use super::CodeStatus;
+
+include!(concat!(env!("OUT_DIR"), "/region_data.rs"));
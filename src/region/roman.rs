@@ -0,0 +1,204 @@
+//! Romanization of the region names, based on the pinyin table.
+
+use crate::Province;
+
+use super::{key_level, short_name, Level, KEYS, PINYIN};
+
+/// The generic parts of the names with their English translations, the longer
+/// ones first.
+static SUFFIXES: [(&str, &str); 16] = [
+    ("特别行政区", "Special Administrative Region"),
+    ("自治区", "Autonomous Region"),
+    ("自治州", "Autonomous Prefecture"),
+    ("自治县", "Autonomous County"),
+    ("自治旗", "Autonomous Banner"),
+    ("新区", "New Area"),
+    ("特区", "Special District"),
+    ("林区", "Forestry District"),
+    ("矿区", "Mining District"),
+    ("地区", "Prefecture"),
+    ("省", "Province"),
+    ("市", "City"),
+    ("区", "District"),
+    ("县", "County"),
+    ("旗", "Banner"),
+    ("盟", "League"),
+];
+
+/// The ethnic groups in the names of the autonomous regions, with the English
+/// names which differ from the pinyin.
+static ETHNIC_GROUPS: [(&str, Option<&str>); 45] = [
+    ("壮", None),
+    ("回", None),
+    ("维吾尔", Some("Uygur")),
+    ("藏", Some("Tibetan")),
+    ("蒙古", Some("Mongol")),
+    ("朝鲜", Some("Korean")),
+    ("满", None),
+    ("彝", None),
+    ("白", None),
+    ("哈尼", None),
+    ("傣", None),
+    ("傈僳", None),
+    ("佤", None),
+    ("拉祜", None),
+    ("纳西", None),
+    ("景颇", None),
+    ("布朗", None),
+    ("普米", None),
+    ("怒", None),
+    ("独龙", None),
+    ("苗", None),
+    ("瑶", None),
+    ("土家", None),
+    ("侗", None),
+    ("布依", None),
+    ("水", None),
+    ("仡佬", None),
+    ("黎", None),
+    ("畲", None),
+    ("羌", None),
+    ("土", None),
+    ("撒拉", None),
+    ("保安", None),
+    ("东乡", None),
+    ("裕固", None),
+    ("哈萨克", Some("Kazak")),
+    ("柯尔克孜", Some("Kirgiz")),
+    ("锡伯", Some("Xibe")),
+    ("塔吉克", Some("Tajik")),
+    ("达斡尔", Some("Daur")),
+    ("鄂温克", Some("Ewenki")),
+    ("鄂伦春", Some("Oroqen")),
+    ("仫佬", None),
+    ("毛南", None),
+    ("各", Some("Multi-Ethnic")),
+];
+
+/// The code of `陕西省`, which is spelled `Shaanxi` to tell from `山西省`.
+const SHAANXI: u32 = 610000;
+
+/// A name split into the place, the ethnic groups(with whether followed by
+/// `族`) and the generic part, each as its syllables.
+struct Parts {
+    place: Vec<&'static str>,
+    groups: Vec<(Option<&'static str>, Vec<&'static str>, bool)>,
+    suffix: Option<(&'static str, Vec<&'static str>)>,
+}
+
+/// Splits the name of the i-th region without its ancestors.
+fn parts(i: usize) -> Option<Parts> {
+    let mut name: Vec<char> = short_name(i).chars().collect();
+    let mut syllables: Vec<&'static str> = PINYIN.get(i)?.split(' ').collect();
+    if syllables.len() != name.len() {
+        return None;
+    }
+
+    let suffix = SUFFIXES.iter().find_map(|&(zh, en)| {
+        let len = zh.chars().count();
+        let matched = name.len() > len && name.ends_with(&zh.chars().collect::<Vec<_>>());
+        matched.then_some((en, len))
+    });
+    let suffix = suffix.map(|(en, len)| {
+        name.truncate(name.len() - len);
+        (en, syllables.split_off(syllables.len() - len))
+    });
+
+    // The ethnic groups are stripped from the end, only the last one may go
+    // without `族`(e.g. `新疆维吾尔自治区`), and then at least 2 characters are
+    // left for the place(e.g. not `内蒙古自治区`).
+    let mut groups = Vec::new();
+    if matches!(&suffix, Some((en, _)) if en.starts_with("Autonomous")) {
+        loop {
+            let zu = name.last() == Some(&'族');
+            let end = name.len() - usize::from(zu);
+            let group = ETHNIC_GROUPS
+                .iter()
+                .filter(|(zh, _)| name[..end].ends_with(&zh.chars().collect::<Vec<_>>()))
+                .max_by_key(|(zh, _)| zh.chars().count());
+            let (len, en) = match group {
+                Some(&(zh, en)) if zu || (groups.is_empty() && end >= zh.chars().count() + 2) => {
+                    (zh.chars().count(), en)
+                }
+                _ => break,
+            };
+            let start = end - len;
+            let mut group_syllables = syllables.split_off(start);
+            group_syllables.truncate(len);
+            name.truncate(start);
+            groups.insert(0, (en, group_syllables, zu));
+        }
+    }
+    Some(Parts {
+        place: syllables,
+        groups,
+        suffix,
+    })
+}
+
+/// Joins the syllables into a capitalized word, with the apostrophes before
+/// the syllables starting with a vowel, e.g. `Xi'an`.
+fn word(syllables: &[&str]) -> String {
+    let mut word = String::new();
+    for (i, syllable) in syllables.iter().enumerate() {
+        if i > 0 && syllable.starts_with(['a', 'e', 'o']) {
+            word.push('\'');
+        }
+        word.push_str(syllable);
+    }
+    capitalize(&word)
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Returns the pinyin of the name of the i-th region without its ancestors,
+/// e.g. `Tongchuan Qu`.
+pub(super) fn name_pinyin(i: usize) -> Option<String> {
+    let parts = parts(i)?;
+    let mut words = vec![word(&parts.place)];
+    if KEYS[i] == SHAANXI {
+        words[0] = "Shaanxi".to_owned();
+    }
+    for (_, syllables, zu) in &parts.groups {
+        let mut group = word(syllables);
+        if *zu {
+            group.push_str("zu");
+        }
+        words.push(group);
+    }
+    if let Some((_, syllables)) = &parts.suffix {
+        words.push(capitalize(&syllables.concat()));
+    }
+    words.retain(|w| !w.is_empty());
+    Some(words.join(" "))
+}
+
+/// Returns the English name of the i-th region without its ancestors, e.g.
+/// `Tongchuan District`. The generic part is omitted for the provinces and the
+/// prefecture-level cities.
+pub(super) fn name_en(i: usize) -> Option<String> {
+    let key = *KEYS.get(i)?;
+    if key_level(key) == Level::Province {
+        let code = format!("{}", key / 10000);
+        return Province::from_code(&code).map(|p| p.name_en().to_owned());
+    }
+
+    let parts = parts(i)?;
+    let mut words = vec![word(&parts.place)];
+    for (en, syllables, _) in &parts.groups {
+        words.push(en.map_or_else(|| word(syllables), str::to_owned));
+    }
+    match parts.suffix {
+        Some(("City", _)) if key_level(key) == Level::Prefecture => {}
+        Some((en, _)) => words.push(en.to_owned()),
+        None => {}
+    }
+    words.retain(|w| !w.is_empty());
+    Some(words.join(" "))
+}
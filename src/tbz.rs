@@ -0,0 +1,68 @@
+//! Utilities for Mainland Travel Permit for Taiwan Residents(台湾居民来往大陆
+//! 通行证, a.k.a. 台胞证)
+//!
+//! The holder keeps the same 8-digit number across the booklets and the cards
+//! issued since 2015, e.g. `12345678`. The card shows it followed by the
+//! 2-digit issuance count, e.g. `12345678(01)`, which is accepted with or
+//! without the parentheses.
+
+use crate::normalize;
+
+/// Validates the number, with or without the issuance count.
+pub fn validate(number: &str) -> bool {
+    digits(number).is_some()
+}
+
+/// Returns the 8-digit personal number, without the issuance count.
+pub fn personal_number(number: &str) -> Option<String> {
+    let mut digits = digits(number)?;
+    digits.truncate(8);
+    Some(digits)
+}
+
+/// Returns the issuance count of a card number, or `None` if the number is
+/// invalid or without it.
+pub fn issuance_count(number: &str) -> Option<u32> {
+    digits(number)?.get(8..)?.parse().ok()
+}
+
+/// Returns the digits of a valid number, with the parentheses around the
+/// issuance count removed.
+fn digits(number: &str) -> Option<String> {
+    let number = normalize(number);
+    let digits = match number.find('(') {
+        Some(8) if number.len() == 12 && number.ends_with(')') => {
+            format!("{}{}", &number[0..8], &number[9..11])
+        }
+        _ => number,
+    };
+    let valid = matches!(digits.len(), 8 | 10) && digits.bytes().all(|b| b.is_ascii_digit());
+    valid.then_some(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("12345678"));
+        assert!(validate("1234567801"));
+        assert!(validate("12345678(01)"));
+        assert!(validate("１２３４５６７８"));
+        assert!(!validate("1234567"));
+        assert!(!validate("123456789"));
+        assert!(!validate("12345678(1)"));
+        assert!(!validate("1234567A"));
+    }
+
+    #[test]
+    fn test_info() {
+        assert_eq!(personal_number("12345678(01)").as_deref(), Some("12345678"));
+        assert_eq!(personal_number("12345678").as_deref(), Some("12345678"));
+        assert_eq!(issuance_count("12345678(02)"), Some(2));
+        assert_eq!(issuance_count("1234567802"), Some(2));
+        assert_eq!(issuance_count("12345678"), None);
+        assert_eq!(issuance_count("1234567"), None);
+    }
+}
@@ -0,0 +1,157 @@
+//! Utilities for finding ID numbers in free text
+
+use crate::{mask_number, validate, Identity, MaskStyle};
+use std::ops::Range;
+
+/// An ID number found in text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found {
+    /// The byte range of the number in the text.
+    pub range: Range<usize>,
+    /// The number as it appears in the text.
+    pub number: String,
+    /// The identity parsed from the number.
+    pub identity: Identity,
+}
+
+/// Extracts all the valid 15/18-digit ID numbers from the given text.
+///
+/// A candidate is a run of digits(the 18th character may be `X` or `x`) that
+/// is not adjacent to other digits.
+pub fn extract(text: &str) -> Vec<Found> {
+    candidates(text.as_bytes())
+        .filter_map(|range| {
+            let number = &text[range.clone()];
+            if !validate(number) {
+                return None;
+            }
+            Some(Found {
+                range,
+                number: number.to_owned(),
+                identity: Identity::new(number),
+            })
+        })
+        .collect()
+}
+
+/// Replaces every valid ID number in the given text with its masked form, the
+/// surrounding text is preserved.
+pub fn redact(text: &str, style: MaskStyle) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for found in extract(text) {
+        result.push_str(&text[last..found.range.start]);
+        result.push_str(&mask_number(&found.number, style));
+        last = found.range.end;
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
+/// Replaces every valid ID number in the given bytes like `redact`, the text
+/// may be in any ASCII compatible encoding, e.g. GBK.
+pub fn redact_bytes(text: &[u8], style: MaskStyle) -> Vec<u8> {
+    let mut result = Vec::with_capacity(text.len());
+    let mut last = 0;
+    for range in candidates(text) {
+        // The candidates are all ASCII.
+        let number = std::str::from_utf8(&text[range.clone()]).unwrap_or_default();
+        if !validate(number) {
+            continue;
+        }
+        result.extend_from_slice(&text[last..range.start]);
+        result.extend_from_slice(mask_number(number, style).as_bytes());
+        last = range.end;
+    }
+    result.extend_from_slice(&text[last..]);
+    result
+}
+
+/// Returns the byte ranges of the candidate numbers in the text.
+fn candidates(bytes: &[u8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            let mut end = i;
+            if end - start == 17 && end < bytes.len() && (bytes[end] == b'X' || bytes[end] == b'x')
+            {
+                end += 1;
+                i = end;
+                if i < bytes.len() && bytes[i].is_ascii_digit() {
+                    continue;
+                }
+            }
+            if end - start == 15 || end - start == 18 {
+                return Some(start..end);
+            }
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract() {
+        let text = "张三，身份证号码：230127197908177456，李四(21021119810503545x)。";
+        let found = extract(text);
+        assert_eq!(found.len(), 2);
+        assert_eq!(&text[found[0].range.clone()], "230127197908177456");
+        assert_eq!(found[0].number, "230127197908177456");
+        assert_eq!(found[1].number, "21021119810503545x");
+        assert_eq!(found[1].identity.number(), "21021119810503545X");
+
+        let found = extract("old: 632123820927051, phone: 13800138000");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].identity.number(), "632123198209270518");
+        assert_eq!(found[0].range, 5..20);
+
+        assert!(extract("2301271979081774560").is_empty());
+        assert!(extract("1230127197908177456").is_empty());
+        assert!(extract("21021119810503545X1").is_empty());
+        assert!(extract("230127197908177457").is_empty());
+        assert!(extract("").is_empty());
+    }
+
+    #[test]
+    fn test_redact() {
+        let text = "张三，身份证号码：230127197908177456，李四(21021119810503545x)。";
+        assert_eq!(
+            redact(text, MaskStyle::KeepFirst6Last4),
+            "张三，身份证号码：230127********7456，李四(210211********545x)。"
+        );
+        assert_eq!(
+            redact(
+                "id=632123820927051&n=230127197908177457",
+                MaskStyle::KeepBirthYearOnly
+            ),
+            "id=******82*******&n=230127197908177457"
+        );
+        assert_eq!(redact("no id here", MaskStyle::default()), "no id here");
+    }
+
+    #[test]
+    fn test_redact_bytes() {
+        let text = "张三，身份证号码：230127197908177456，李四(21021119810503545x)。";
+        assert_eq!(
+            redact_bytes(text.as_bytes(), MaskStyle::KeepFirst6Last4),
+            redact(text, MaskStyle::KeepFirst6Last4).as_bytes()
+        );
+        // `身份证：` in GBK.
+        let gbk = b"\xc9\xed\xb7\xdd\xd6\xa4\xa3\xba632123198209270518";
+        assert_eq!(
+            redact_bytes(gbk, MaskStyle::default()),
+            b"\xc9\xed\xb7\xdd\xd6\xa4\xa3\xba632123********0518"
+        );
+    }
+}
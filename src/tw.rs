@@ -1,121 +1,461 @@
 //! Utilities for Taiwan Identity Card
 
-use crate::Gender;
-use regex::Regex;
-use std::collections::HashMap;
-
-lazy_static! {
-    static ref PREFIX_LETTERS: HashMap<&'static str, (u32, &'static str)> = {
-        let mut map = HashMap::new();
-        map.insert("A", (10,  "台北市"));
-        map.insert("B", (11,  "台中市"));
-        map.insert("C", (12,  "基隆市"));
-        map.insert("D", (13,  "台南市"));
-        map.insert("E", (14,  "高雄市"));
-        map.insert("F", (15,  "新北市"));
-        map.insert("G", (16,  "宜兰县"));
-        map.insert("H", (17,  "桃园市"));
-        map.insert("J", (18,  "新竹县"));
-        map.insert("K", (19,  "苗栗县"));
-        map.insert("L", (20,  "台中县")); // obsoleted
-        map.insert("M", (21,  "南投县"));
-        map.insert("N", (22,  "彰化县"));
-        map.insert("P", (23,  "云林县"));
-        map.insert("Q", (24,  "嘉义县"));
-        map.insert("R", (25,  "台南县")); // obsoleted
-        map.insert("S", (26,  "高雄县")); // obsoleted
-        map.insert("T", (27,  "屏东县"));
-        map.insert("U", (28,  "花莲县"));
-        map.insert("V", (29,  "台东县"));
-        map.insert("X", (30,  "澎湖县"));
-        map.insert("Y", (31,  "阳明山管理局")); // obsoleted
-        map.insert("W", (32,  "金门县"));
-        map.insert("Z", (33,  "连江县"));
-        map.insert("I", (34,  "嘉义市"));
-        map.insert("O", (35,  "新竹市"));
-        map
-    };
-    static ref PATTERN: Regex = Regex::new(r"^[a-zA-Z][0-9]{9}$").unwrap();
+use crate::{Error, Gender};
+#[cfg(feature = "fake")]
+use rand::seq::IteratorRandom;
+#[cfg(feature = "fake")]
+use rand::{thread_rng, Rng};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// The places by the prefix letters, in the order of the letters. The
+/// obsolete ones were merged or abolished, and are no longer used in the new
+/// numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Region {
+    /// `A`, 台北市
+    TaipeiCity,
+    /// `B`, 台中市
+    TaichungCity,
+    /// `C`, 基隆市
+    KeelungCity,
+    /// `D`, 台南市
+    TainanCity,
+    /// `E`, 高雄市
+    KaohsiungCity,
+    /// `F`, 新北市
+    NewTaipeiCity,
+    /// `G`, 宜兰县
+    YilanCounty,
+    /// `H`, 桃园市
+    TaoyuanCity,
+    /// `I`, 嘉义市
+    ChiayiCity,
+    /// `J`, 新竹县
+    HsinchuCounty,
+    /// `K`, 苗栗县
+    MiaoliCounty,
+    /// `L`, 台中县(obsolete)
+    TaichungCounty,
+    /// `M`, 南投县
+    NantouCounty,
+    /// `N`, 彰化县
+    ChanghuaCounty,
+    /// `O`, 新竹市
+    HsinchuCity,
+    /// `P`, 云林县
+    YunlinCounty,
+    /// `Q`, 嘉义县
+    ChiayiCounty,
+    /// `R`, 台南县(obsolete)
+    TainanCounty,
+    /// `S`, 高雄县(obsolete)
+    KaohsiungCounty,
+    /// `T`, 屏东县
+    PingtungCounty,
+    /// `U`, 花莲县
+    HualienCounty,
+    /// `V`, 台东县
+    TaitungCounty,
+    /// `W`, 金门县
+    KinmenCounty,
+    /// `X`, 澎湖县
+    PenghuCounty,
+    /// `Y`, 阳明山管理局(obsolete)
+    Yangmingshan,
+    /// `Z`, 连江县
+    LienchiangCounty,
 }
 
-/// Validates the number.
+/// The prefix letter, numeric value, simplified name, traditional name and
+/// whether obsolete of each place, in the order of the variants.
+static PREFIX_LETTERS: [(char, u32, &str, &str, bool); 26] = [
+    ('A', 10, "台北市", "臺北市", false),
+    ('B', 11, "台中市", "臺中市", false),
+    ('C', 12, "基隆市", "基隆市", false),
+    ('D', 13, "台南市", "臺南市", false),
+    ('E', 14, "高雄市", "高雄市", false),
+    ('F', 15, "新北市", "新北市", false),
+    ('G', 16, "宜兰县", "宜蘭縣", false),
+    ('H', 17, "桃园市", "桃園市", false),
+    ('I', 34, "嘉义市", "嘉義市", false),
+    ('J', 18, "新竹县", "新竹縣", false),
+    ('K', 19, "苗栗县", "苗栗縣", false),
+    ('L', 20, "台中县", "臺中縣", true),
+    ('M', 21, "南投县", "南投縣", false),
+    ('N', 22, "彰化县", "彰化縣", false),
+    ('O', 35, "新竹市", "新竹市", false),
+    ('P', 23, "云林县", "雲林縣", false),
+    ('Q', 24, "嘉义县", "嘉義縣", false),
+    ('R', 25, "台南县", "臺南縣", true),
+    ('S', 26, "高雄县", "高雄縣", true),
+    ('T', 27, "屏东县", "屏東縣", false),
+    ('U', 28, "花莲县", "花蓮縣", false),
+    ('V', 29, "台东县", "臺東縣", false),
+    ('W', 32, "金门县", "金門縣", false),
+    ('X', 30, "澎湖县", "澎湖縣", false),
+    ('Y', 31, "阳明山管理局", "陽明山管理局", true),
+    ('Z', 33, "连江县", "連江縣", false),
+];
+
+impl Region {
+    /// All the places, in the order of the prefix letters.
+    pub const ALL: [Region; 26] = [
+        Region::TaipeiCity,
+        Region::TaichungCity,
+        Region::KeelungCity,
+        Region::TainanCity,
+        Region::KaohsiungCity,
+        Region::NewTaipeiCity,
+        Region::YilanCounty,
+        Region::TaoyuanCity,
+        Region::ChiayiCity,
+        Region::HsinchuCounty,
+        Region::MiaoliCounty,
+        Region::TaichungCounty,
+        Region::NantouCounty,
+        Region::ChanghuaCounty,
+        Region::HsinchuCity,
+        Region::YunlinCounty,
+        Region::ChiayiCounty,
+        Region::TainanCounty,
+        Region::KaohsiungCounty,
+        Region::PingtungCounty,
+        Region::HualienCounty,
+        Region::TaitungCounty,
+        Region::KinmenCounty,
+        Region::PenghuCounty,
+        Region::Yangmingshan,
+        Region::LienchiangCounty,
+    ];
+
+    /// Returns the place by the prefix letter, in either case.
+    pub fn from_letter(letter: char) -> Option<Region> {
+        let letter = letter.to_ascii_uppercase();
+        if letter.is_ascii_uppercase() {
+            Some(Region::ALL[usize::from(letter as u8 - b'A')])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the prefix letter, e.g. `A`.
+    pub fn letter(self) -> char {
+        PREFIX_LETTERS[self as usize].0
+    }
+
+    /// Returns the simplified Chinese name, e.g. `台北市`.
+    pub fn name(self) -> &'static str {
+        PREFIX_LETTERS[self as usize].2
+    }
+
+    /// Returns the traditional Chinese name, e.g. `臺北市`.
+    pub fn name_traditional(self) -> &'static str {
+        PREFIX_LETTERS[self as usize].3
+    }
+
+    /// Checks if the place is obsolete, i.e. `L`, `R`, `S` and `Y`.
+    pub fn is_obsolete(self) -> bool {
+        PREFIX_LETTERS[self as usize].4
+    }
+
+    /// Returns the numeric value of the prefix letter in the check digit.
+    fn value(self) -> u32 {
+        PREFIX_LETTERS[self as usize].1
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Validates the number, either of a national(the second digit is `1` or
+/// `2`) or of a foreign resident in the unified format since 2021(the second
+/// digit is `8` or `9`).
 pub fn validate(number: &str) -> bool {
     let number = number.trim().to_ascii_uppercase();
-    if number.len() == 10 && PATTERN.is_match(&number) {
-        let start = &number[0..1];
-        let sex = &number[1..2];
-        let mid = &number[1..9];
-        let end = &number[9..];
-
-        if sex != "1" && sex != "2" {
+    let bytes = number.as_bytes();
+    if bytes.len() == 10
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1..].iter().all(u8::is_ascii_digit)
+    {
+        if !matches!(&number[1..2], "1" | "2" | "8" | "9") {
             return false;
         }
+        check_digit(&number[0..9]) == number.chars().nth(9)
+    } else {
+        false
+    }
+}
 
-        let start = match PREFIX_LETTERS.get(start) {
-            Some(value) => value,
-            _ => return false,
-        };
-
-        let mut sum = start.0 / 10 + (start.0 % 10) * 9;
-        let mut flag = 8;
-
-        for ch in mid.chars() {
-            let i = match ch.to_digit(10) {
-                Some(value) => value,
-                _ => return false,
-            };
-            sum = sum + i * flag;
-            flag -= 1;
-        }
+/// Computes the check digit of the letter and the 8 digits before it, e.g.
+/// `A12345678` gives `9`.
+pub fn check_digit(first_nine: &str) -> Option<char> {
+    if first_nine.len() != 9 {
+        return None;
+    }
+    let mut chars = first_nine.chars();
+    let value = Region::from_letter(chars.next()?)?.value();
+    let mut sum = value / 10 + (value % 10) * 9;
+    for (ch, weight) in chars.zip((1..=8).rev()) {
+        sum += ch.to_digit(10)? * weight;
+    }
+    std::char::from_digit((10 - sum % 10) % 10, 10)
+}
 
-        let end = match end.chars().nth(0) {
-            Some(ch) => match ch.to_digit(10) {
-                Some(value) => value,
-                _ => return false,
-            },
-            _ => return false,
-        };
-        let checksum = if sum % 10 == 0 { 0 } else { 10 - sum % 10 };
-        checksum == end
+/// Recomputes the check digit of a number and returns the corrected one in
+/// uppercase, the first 9 characters must be well-formed.
+pub fn repair_check_digit(number: &str) -> Result<String, Error> {
+    let number = crate::normalize(number);
+    if number.len() != 10 || !number.is_ascii() {
+        return Err(Error::InvalidNumber);
+    }
+    let first_nine = &number[0..9];
+    if !matches!(&first_nine[1..2], "1" | "2" | "8" | "9") {
+        return Err(Error::InvalidNumber);
+    }
+    match check_digit(first_nine) {
+        Some(check) => Ok(format!("{}{}", first_nine, check)),
+        None => Err(Error::InvalidNumber),
+    }
+}
+
+/// Generates a random number with a valid check digit, the obsolete prefix
+/// letters are excluded.
+#[cfg(feature = "fake")]
+pub fn fake() -> String {
+    let mut rng = thread_rng();
+    let letter = Region::ALL
+        .iter()
+        .filter(|r| !r.is_obsolete())
+        .choose(&mut rng)
+        .unwrap()
+        .letter();
+    let gender = if rng.gen() {
+        Gender::Male
     } else {
-        false
+        Gender::Female
+    };
+    fake_with(letter, gender).unwrap()
+}
+
+/// Generates a random number with the given prefix letter(the obsolete ones
+/// are accepted) and gender.
+#[cfg(feature = "fake")]
+pub fn fake_with(region_letter: char, gender: Gender) -> Result<String, Error> {
+    let letter = match Region::from_letter(region_letter) {
+        Some(region) => region.letter(),
+        None => {
+            return Err(Error::GenerateFakeIDError(format!(
+                "Invalid prefix letter: {}",
+                region_letter
+            )))
+        }
+    };
+    let mut rng = thread_rng();
+    let mut first_nine = String::with_capacity(10);
+    first_nine.push(letter);
+    first_nine.push(if gender == Gender::Male { '1' } else { '2' });
+    for _ in 0..7 {
+        first_nine.push(char::from(b'0' + rng.gen_range(0..10)));
     }
+    let check = check_digit(&first_nine).unwrap();
+    first_nine.push(check);
+    Ok(first_nine)
 }
 
-/// Returns the gender.
+/// Returns the gender, the second digit is `1` or `8` for male, and `2` or
+/// `9` for female.
 pub fn gender(number: &str) -> Option<Gender> {
     if !validate(number) {
         return None;
     }
 
-    if let Some(sex) = number.chars().nth(1) {
-        if sex == '1' {
-            Some(Gender::Male)
-        } else if sex == '2' {
-            Some(Gender::Female)
-        } else {
-            None
-        }
-    } else {
-        None
+    match number.trim().chars().nth(1)? {
+        '1' | '8' => Some(Gender::Male),
+        '2' | '9' => Some(Gender::Female),
+        _ => None,
+    }
+}
+
+/// Checks if the number is a valid one of a foreign resident in the unified
+/// format since 2021.
+pub fn is_foreign_resident(number: &str) -> bool {
+    validate(number) && matches!(number.trim().chars().nth(1), Some('8' | '9'))
+}
+
+/// The fields of a legacy Alien Resident Certificate number, in the format of
+/// two letters and 8 digits issued before 2021, e.g. `AA00123454`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyArc {
+    /// The place by the initial letter.
+    pub region: Region,
+    /// The gender by the second letter, `A` or `C` for male, and `B` or `D`
+    /// for female.
+    pub gender: Gender,
+    /// Whether the holder is a foreigner(`C` or `D`), rather than a national
+    /// without household registration or a resident of the mainland, Hong Kong
+    /// or Macau(`A` or `B`).
+    pub is_foreigner: bool,
+}
+
+/// Validates a legacy Alien Resident Certificate number. The check digit is
+/// computed like the national one, with the last digit of the value of the
+/// second letter in place of the gender digit.
+pub fn validate_legacy_arc(number: &str) -> bool {
+    legacy_arc(number).is_some()
+}
+
+/// Returns the fields of a valid legacy Alien Resident Certificate number.
+pub fn legacy_arc(number: &str) -> Option<LegacyArc> {
+    let number = number.trim().to_ascii_uppercase();
+    let bytes = number.as_bytes();
+    if bytes.len() != 10 || !bytes[2..].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let region = Region::from_letter(char::from(bytes[0]))?;
+    let value = region.value();
+    let (gender, is_foreigner) = match bytes[1] {
+        b'A' => (Gender::Male, false),
+        b'B' => (Gender::Female, false),
+        b'C' => (Gender::Male, true),
+        b'D' => (Gender::Female, true),
+        _ => return None,
+    };
+    let second = Region::from_letter(char::from(bytes[1]))?.value();
+    let sum = value / 10
+        + (value % 10) * 9
+        + (second % 10) * 8
+        + bytes[2..9]
+            .iter()
+            .zip((1..=7).rev())
+            .map(|(&b, w)| u32::from(b - b'0') * w)
+            .sum::<u32>();
+    if u32::from(bytes[9] - b'0') != (10 - sum % 10) % 10 {
+        return None;
     }
+    Some(LegacyArc {
+        region,
+        gender,
+        is_foreigner,
+    })
 }
 
 /// Returns the place by the initial letter
-pub fn region(number: &str) -> Option<&str> {
+pub fn region(number: &str) -> Option<Region> {
     if !validate(number) {
         return None;
     }
 
-    let code = &number[0..1];
-    if !code.is_empty() {
-        if let Some((_, name)) = PREFIX_LETTERS.get(code) {
-            Some(*name)
+    Region::from_letter(number.trim().chars().next()?)
+}
+
+/// An object representation of the Taiwan ID, e.g. `A123456789`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity {
+    number: String,
+    valid: bool,
+}
+
+impl Identity {
+    /// Creates an identity object from the given number, the number is
+    /// normalized by `crate::normalize` first.
+    pub fn new(number: &str) -> Self {
+        let number = crate::normalize(number);
+        let valid = validate(&number);
+        Identity { number, valid }
+    }
+
+    /// Creates an identity object from the given number, returns an error if
+    /// the number is invalid.
+    pub fn try_new(number: &str) -> Result<Self, Error> {
+        let id = Identity::new(number);
+        if id.is_valid() {
+            Ok(id)
         } else {
-            None
+            Err(Error::InvalidNumber)
         }
-    } else {
-        None
+    }
+
+    /// Returns the number in uppercase.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// Returns the place by the initial letter.
+    pub fn region(&self) -> Option<Region> {
+        region(&self.number)
+    }
+
+    /// Returns the gender.
+    pub fn gender(&self) -> Option<Gender> {
+        gender(&self.number)
+    }
+
+    /// Checks if the holder is a foreign resident, numbered in the unified
+    /// format since 2021.
+    pub fn is_foreign_resident(&self) -> bool {
+        self.is_valid() && is_foreign_resident(&self.number)
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> Option<char> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.number.chars().last()
+    }
+
+    /// Checks if the number is valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.number())
+    }
+}
+
+impl FromStr for Identity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Identity::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for Identity {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Identity::try_new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Identity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.number())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Identity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let number = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        Identity::try_new(&number)
+            .map_err(|e| serde::de::Error::custom(format!("invalid Taiwan ID {:?}: {}", number, e)))
     }
 }
 
@@ -129,18 +469,151 @@ mod tests {
         assert_eq!(validate("B142610160"), true);
         assert_eq!(validate("Q155304682"), true);
         assert_eq!(validate("Q155304680"), false);
+        assert!(validate("A800000014"));
+        assert!(validate("F923456780"));
+        assert!(!validate("A300000014"));
+        assert!(!validate("A800000015"));
+    }
+
+    #[test]
+    fn test_legacy_arc() {
+        assert!(validate_legacy_arc("AA00123454"));
+        assert!(validate_legacy_arc(" fd12345676"));
+        assert!(!validate_legacy_arc("AA00123455"));
+        assert!(!validate_legacy_arc("AE00123454"));
+        assert!(!validate_legacy_arc("A123456789"));
+        assert!(!validate("AA00123454"));
+
+        assert_eq!(
+            legacy_arc("AB12345677"),
+            Some(LegacyArc {
+                region: Region::TaipeiCity,
+                gender: Gender::Female,
+                is_foreigner: false,
+            })
+        );
+        assert_eq!(
+            legacy_arc("AC01000007"),
+            Some(LegacyArc {
+                region: Region::TaipeiCity,
+                gender: Gender::Male,
+                is_foreigner: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_foreign_resident() {
+        assert!(is_foreign_resident("A800000014"));
+        assert!(!is_foreign_resident("A123456789"));
+        assert_eq!(gender("B812345672"), Some(Gender::Male));
+        assert_eq!(gender("F923456780"), Some(Gender::Female));
+
+        let id = Identity::new("f923456780");
+        assert!(id.is_valid());
+        assert!(id.is_foreign_resident());
+        assert_eq!(id.gender(), Some(Gender::Female));
+        assert_eq!(id.region(), Some(Region::NewTaipeiCity));
+        assert!(!Identity::new("A123456789").is_foreign_resident());
+    }
+
+    #[test]
+    fn test_region() {
+        for (i, region) in Region::ALL.iter().enumerate() {
+            assert_eq!(*region as usize, i);
+            assert_eq!(region.letter(), char::from(b'A' + i as u8));
+            assert_eq!(Region::from_letter(region.letter()), Some(*region));
+        }
+        let r = Region::from_letter('a').unwrap();
+        assert_eq!(r, Region::TaipeiCity);
+        assert_eq!(r.name(), "台北市");
+        assert_eq!(r.name_traditional(), "臺北市");
+        assert_eq!(r.to_string(), "台北市");
+        assert_eq!(r.value(), 10);
+        assert_eq!(Region::HsinchuCity.value(), 35);
+        assert_eq!(Region::PenghuCounty.name_traditional(), "澎湖縣");
+        assert!(Region::Yangmingshan.is_obsolete());
+        assert!(!Region::TaipeiCity.is_obsolete());
+        assert_eq!(Region::ALL.iter().filter(|r| r.is_obsolete()).count(), 4);
+        assert_eq!(Region::from_letter('1'), None);
     }
 
     #[test]
     fn test_get_region() {
         let r = region("B142610160");
-        assert_eq!(r, Some("台中市"));
+        assert_eq!(r, Some(Region::TaichungCity));
         let r = region("0142610160");
         assert_eq!(r, None);
         let r = region("Q155304680");
         assert_eq!(r, None);
     }
 
+    #[test]
+    fn test_check_digit() {
+        assert_eq!(check_digit("A12345678"), Some('9'));
+        assert_eq!(check_digit("Q15530468"), Some('2'));
+        assert_eq!(check_digit("a12345678"), Some('9'));
+        assert_eq!(check_digit("A1234567"), None);
+        assert_eq!(check_digit("112345678"), None);
+        assert_eq!(check_digit("A1234567X"), None);
+    }
+
+    #[test]
+    fn test_repair_check_digit() {
+        assert_eq!(repair_check_digit("A123456780").unwrap(), "A123456789");
+        assert_eq!(repair_check_digit("q 155304680").unwrap(), "Q155304682");
+        assert_eq!(repair_check_digit("A800000010").unwrap(), "A800000014");
+        assert!(repair_check_digit("A323456780").is_err());
+        assert!(repair_check_digit("A12345678").is_err());
+        assert!(repair_check_digit("AX23456780").is_err());
+        assert!(repair_check_digit("中1234567").is_err());
+        assert!(repair_check_digit("A中1234567").is_err());
+    }
+
+    #[cfg(feature = "fake")]
+    #[test]
+    fn test_fake() {
+        for _ in 0..100 {
+            let number = fake();
+            assert!(validate(&number));
+            assert!(!region(&number).unwrap().is_obsolete());
+        }
+        let id = Identity::new(&fake_with('y', Gender::Female).unwrap());
+        assert!(id.is_valid());
+        assert_eq!(id.region(), Some(Region::Yangmingshan));
+        assert_eq!(id.gender(), Some(Gender::Female));
+        assert!(fake_with('1', Gender::Male).is_err());
+    }
+
+    #[test]
+    fn test_identity() {
+        let id = Identity::new(" b142610160 ");
+        assert!(id.is_valid());
+        assert_eq!(id.number(), "B142610160");
+        assert_eq!(id.to_string(), "B142610160");
+        assert_eq!(id.region(), Some(Region::TaichungCity));
+        assert_eq!(id.gender(), Some(Gender::Male));
+        assert_eq!(id.check_digit(), Some('0'));
+        assert_eq!(id, "B142610160".parse().unwrap());
+
+        let id = Identity::new("Q155304680");
+        assert!(!id.is_valid());
+        assert_eq!(id.region(), None);
+        assert_eq!(id.gender(), None);
+        assert_eq!(id.check_digit(), None);
+        assert!(Identity::try_new("Q155304680").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let id = Identity::new("A123456789");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"A123456789\"");
+        let back: Identity = serde_json::from_str("\"a123456789\"").unwrap();
+        assert_eq!(back, id);
+        assert!(serde_json::from_str::<Identity>("\"Q155304680\"").is_err());
+    }
+
     #[test]
     fn test_get_gender() {
         let g = gender("Q155304682");
@@ -0,0 +1,240 @@
+//! Utilities for Unified Social Credit Code(统一社会信用代码, GB 32100-2015)
+//!
+//! The code consists of 18 characters, e.g. `91350100M000100Y43`:
+//!
+//! - 1: the registration authority
+//! - 2: the type of the organization, by the registration authority
+//! - 3~8: the region code of the registration authority
+//! - 9~17: the organization code
+//! - 18: the check character
+//!
+//! The characters are the digits and the uppercase letters except `I`, `O`,
+//! `S`, `V` and `Z`.
+
+use crate::normalize;
+
+const CODE_LEN: usize = 18;
+
+/// The characters in the order of their values.
+static CHARSET: &[u8; 31] = b"0123456789ABCDEFGHJKLMNPQRTUWXY";
+
+/// The weights of the first 17 characters in the check character.
+static WEIGHTS: [u32; 17] = [
+    1, 3, 9, 27, 19, 26, 16, 17, 20, 29, 25, 13, 8, 24, 10, 30, 28,
+];
+
+/// A registration authority with its code, name and the types of the
+/// organizations it registers.
+type Authority = (char, &'static str, &'static [(char, &'static str)]);
+
+/// The registration authorities sorted by code.
+static AUTHORITIES: [Authority; 12] = [
+    (
+        '1',
+        "机构编制",
+        &[
+            ('1', "机关"),
+            ('2', "事业单位"),
+            ('3', "中央编办直接管理机构编制的群众团体"),
+            ('9', "其他"),
+        ],
+    ),
+    ('2', "外交", &[('1', "外国常驻新闻机构"), ('9', "其他")]),
+    (
+        '3',
+        "司法行政",
+        &[
+            ('1', "律师执业机构"),
+            ('2', "公证处"),
+            ('3', "基层法律服务所"),
+            ('4', "司法鉴定机构"),
+            ('5', "仲裁委员会"),
+            ('9', "其他"),
+        ],
+    ),
+    ('4', "文化", &[('1', "外国在华文化中心"), ('9', "其他")]),
+    (
+        '5',
+        "民政",
+        &[
+            ('1', "社会团体"),
+            ('2', "民办非企业单位"),
+            ('3', "基金会"),
+            ('9', "其他"),
+        ],
+    ),
+    (
+        '6',
+        "旅游",
+        &[
+            ('1', "外国旅游部门常驻代表机构"),
+            ('2', "港澳台地区旅游部门常驻内地（大陆）代表机构"),
+            ('9', "其他"),
+        ],
+    ),
+    (
+        '7',
+        "宗教",
+        &[('1', "宗教活动场所"), ('2', "宗教院校"), ('9', "其他")],
+    ),
+    ('8', "工会", &[('1', "基层工会"), ('9', "其他")]),
+    (
+        '9',
+        "工商",
+        &[('1', "企业"), ('2', "个体工商户"), ('3', "农民专业合作社")],
+    ),
+    (
+        'A',
+        "中央军委改革和编制办公室",
+        &[('1', "军队事业单位"), ('9', "其他")],
+    ),
+    (
+        'N',
+        "农业",
+        &[
+            ('1', "组级集体经济组织"),
+            ('2', "村级集体经济组织"),
+            ('3', "乡镇级集体经济组织"),
+            ('9', "其他"),
+        ],
+    ),
+    ('Y', "其他", &[('1', "其他")]),
+];
+
+/// Validates the code, including the check character and the types of the
+/// registration authority and the organization.
+pub fn validate(code: &str) -> bool {
+    let code = normalize(code);
+    code.len() == CODE_LEN
+        && code.bytes().all(|b| CHARSET.contains(&b))
+        && check_char(&code[0..17]) == code.chars().nth(17)
+        && organization_type_of(&code).is_some()
+}
+
+/// Validates the code, and also the organization code embedded in it.
+pub fn validate_strict(code: &str) -> bool {
+    validate(code) && crate::orgcode::validate(&normalize(code)[8..17])
+}
+
+/// Computes the check character of the first 17 characters, e.g.
+/// `91350100M000100Y4` gives `3`.
+pub fn check_char(first_seventeen: &str) -> Option<char> {
+    if first_seventeen.len() != 17 {
+        return None;
+    }
+    let mut sum = 0;
+    for (b, w) in first_seventeen.bytes().zip(WEIGHTS.iter()) {
+        sum += value(b.to_ascii_uppercase())? * w;
+    }
+    Some(char::from(CHARSET[((31 - sum % 31) % 31) as usize]))
+}
+
+/// Returns the registration authority of a valid code, e.g. `工商`.
+pub fn registration_authority(code: &str) -> Option<&'static str> {
+    if !validate(code) {
+        return None;
+    }
+    authority(normalize(code).chars().next()?).map(|a| a.1)
+}
+
+/// Returns the type of the organization of a valid code, e.g. `企业`.
+pub fn organization_type(code: &str) -> Option<&'static str> {
+    if !validate(code) {
+        return None;
+    }
+    organization_type_of(&normalize(code))
+}
+
+/// Returns the region code of the registration authority of a valid code, e.g.
+/// `350100`, which may be looked up by `region::query`.
+pub fn region_code(code: &str) -> Option<String> {
+    if !validate(code) {
+        return None;
+    }
+    Some(normalize(code)[2..8].to_owned())
+}
+
+/// Returns the organization code embedded in a valid code, e.g. `M000100Y4`,
+/// which may be validated by `orgcode::validate`.
+pub fn organization_code(code: &str) -> Option<String> {
+    if !validate(code) {
+        return None;
+    }
+    Some(normalize(code)[8..17].to_owned())
+}
+
+fn authority(ch: char) -> Option<&'static Authority> {
+    AUTHORITIES
+        .binary_search_by_key(&ch, |a| a.0)
+        .ok()
+        .map(|i| &AUTHORITIES[i])
+}
+
+/// Returns the type of the organization by the first 2 characters.
+fn organization_type_of(code: &str) -> Option<&'static str> {
+    let mut chars = code.chars();
+    let (authority, ty) = (authority(chars.next()?)?, chars.next()?);
+    authority
+        .2
+        .iter()
+        .find(|&&(c, _)| c == ty)
+        .map(|&(_, name)| name)
+}
+
+/// Returns the value of a character.
+fn value(b: u8) -> Option<u32> {
+    CHARSET.iter().position(|&c| c == b).map(|i| i as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("91350100M000100Y43"));
+        assert!(validate("9144030071526726XG"));
+        assert!(validate("12100000400000624D"));
+        assert!(validate("51100000500000890x"));
+        assert!(!validate("91350100M000100Y44"));
+        assert!(!validate("91350100M000100Y4"));
+        assert!(!validate("91350100M00010OY43"));
+        // unknown types of the organization
+        assert!(!validate("94350100M000100Y4R"));
+    }
+
+    #[test]
+    fn test_validate_strict() {
+        assert!(validate_strict("91350100M000100Y43"));
+        assert!(validate_strict("9144030071526726XG"));
+        assert!(validate("51100000500000890X"));
+        assert!(!validate_strict("51100000500000890X"));
+    }
+
+    #[test]
+    fn test_check_char() {
+        assert_eq!(check_char("91350100M000100Y4"), Some('3'));
+        assert_eq!(check_char("9144030071526726X"), Some('G'));
+        assert_eq!(check_char("9144030071526726"), None);
+        assert_eq!(check_char("9144030071526726I"), None);
+    }
+
+    #[test]
+    fn test_info() {
+        assert!(AUTHORITIES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(registration_authority("9144030071526726XG"), Some("工商"));
+        assert_eq!(organization_type("9144030071526726XG"), Some("企业"));
+        assert_eq!(region_code("9144030071526726XG").as_deref(), Some("440300"));
+        assert_eq!(
+            registration_authority("12100000400000624D"),
+            Some("机构编制")
+        );
+        assert_eq!(organization_type("12100000400000624D"), Some("事业单位"));
+        assert_eq!(organization_type("51100000500000890X"), Some("社会团体"));
+        assert_eq!(organization_type("91350100M000100Y44"), None);
+        assert_eq!(
+            organization_code("9144030071526726XG").as_deref(),
+            Some("71526726X")
+        );
+    }
+}
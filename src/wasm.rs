@@ -0,0 +1,116 @@
+//! JavaScript bindings via wasm-bindgen
+//!
+//! Build the `cdylib` for `wasm32-unknown-unknown` with `--features wasm`
+//! and generate the JavaScript module by `wasm-bindgen`, e.g.
+//!
+//! ```sh
+//! cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target bundler --out-dir pkg target/wasm32-unknown-unknown/release/idcard.wasm
+//! ```
+//!
+//! The exported functions are:
+//!
+//! ```js
+//! import { validate, info, fake, upgrade } from "idcard";
+//!
+//! validate("632123198209270518"); // true
+//! info("632123820927051");        // { number: "632123198209270518", gender: "Male", ... }, or null
+//! fake({ region: "3301", gender: "female", minYear: 1990, maxYear: 2000 });
+//! upgrade("310112850409522");     // "310112198504095227", throws if invalid
+//! ```
+
+use crate::fake::{self, FakeOptions};
+use crate::Identity;
+use wasm_bindgen::prelude::*;
+
+/// Checks if the 15/18-digit number is valid.
+#[wasm_bindgen]
+pub fn validate(number: &str) -> bool {
+    crate::validate(number)
+}
+
+/// Returns the detailed information of the number as an object with the same
+/// fields as `IdentityInfo`, or `null` if the number is invalid.
+#[wasm_bindgen]
+pub fn info(number: &str) -> Result<JsValue, JsError> {
+    match Identity::new(number).info() {
+        Some(info) => Ok(serde_wasm_bindgen::to_value(&info)?),
+        None => Ok(JsValue::NULL),
+    }
+}
+
+/// Generates a fake number, the options object is optional and all of its
+/// fields(`region`, `gender`, `minYear` and `maxYear`) are optional too.
+#[wasm_bindgen]
+pub fn fake(options: JsValue) -> Result<String, JsError> {
+    let args: FakeArgs = if options.is_undefined() || options.is_null() {
+        FakeArgs::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+    Ok(fake::rand_with(&args.to_options()?)?)
+}
+
+/// Upgrades the number from 15-digit to 18-digit.
+#[wasm_bindgen]
+pub fn upgrade(number: &str) -> Result<String, JsError> {
+    Ok(crate::upgrade(number)?)
+}
+
+/// The options object of `fake`.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct FakeArgs {
+    region: Option<String>,
+    gender: Option<String>,
+    min_year: Option<u32>,
+    max_year: Option<u32>,
+}
+
+impl FakeArgs {
+    fn to_options(&self) -> Result<FakeOptions, crate::Error> {
+        let mut options = FakeOptions::new();
+        if let Some(region) = &self.region {
+            options = options.region(region);
+        }
+        options = match self.gender.as_deref() {
+            None => options,
+            Some("male") | Some("m") | Some("M") => options.male(),
+            Some("female") | Some("f") | Some("F") => options.female(),
+            Some(gender) => {
+                return Err(crate::Error::GenerateFakeIDError(format!(
+                    "Unknown gender {:?}",
+                    gender
+                )))
+            }
+        };
+        if let Some(year) = self.min_year {
+            options = options.min_year(year);
+        }
+        if let Some(year) = self.max_year {
+            options = options.max_year(year);
+        }
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fake_args() {
+        let args: FakeArgs = serde_json::from_str(
+            r#"{"region":"3301","gender":"female","minYear":1990,"maxYear":2000}"#,
+        )
+        .unwrap();
+        let id = Identity::new(&fake::rand_with(&args.to_options().unwrap()).unwrap());
+        assert!(id.is_valid());
+        assert!(id.number().starts_with("3301"));
+        assert_eq!(id.gender(), Some(crate::Gender::Female));
+        assert!((1990..=2000).contains(&id.year().unwrap()));
+
+        let args: FakeArgs = serde_json::from_str(r#"{"gender":"x"}"#).unwrap();
+        assert!(args.to_options().is_err());
+    }
+}
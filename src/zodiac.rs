@@ -0,0 +1,260 @@
+//! The constellations(星座) and the Chinese Zodiac animals(生肖).
+
+use std::fmt;
+
+/// The twelve constellations of the zodiac, in the order starting from Aries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Constellation {
+    /// 白羊座
+    Aries,
+    /// 金牛座
+    Taurus,
+    /// 双子座
+    Gemini,
+    /// 巨蟹座
+    Cancer,
+    /// 狮子座
+    Leo,
+    /// 处女座
+    Virgo,
+    /// 天秤座
+    Libra,
+    /// 天蝎座
+    Scorpio,
+    /// 射手座
+    Sagittarius,
+    /// 摩羯座
+    Capricorn,
+    /// 水瓶座
+    Aquarius,
+    /// 双鱼座
+    Pisces,
+}
+
+/// The Chinese name, English name and the first day(month, day) of each
+/// constellation, in the order of the variants.
+static CONSTELLATIONS: [(&str, &str, (u32, u32)); 12] = [
+    ("白羊座", "Aries", (3, 21)),
+    ("金牛座", "Taurus", (4, 20)),
+    ("双子座", "Gemini", (5, 21)),
+    ("巨蟹座", "Cancer", (6, 22)),
+    ("狮子座", "Leo", (7, 23)),
+    ("处女座", "Virgo", (8, 23)),
+    ("天秤座", "Libra", (9, 23)),
+    ("天蝎座", "Scorpio", (10, 24)),
+    ("射手座", "Sagittarius", (11, 23)),
+    ("摩羯座", "Capricorn", (12, 22)),
+    ("水瓶座", "Aquarius", (1, 20)),
+    ("双鱼座", "Pisces", (2, 19)),
+];
+
+impl Constellation {
+    /// All the constellations, in the order starting from Aries.
+    pub const ALL: [Constellation; 12] = [
+        Constellation::Aries,
+        Constellation::Taurus,
+        Constellation::Gemini,
+        Constellation::Cancer,
+        Constellation::Leo,
+        Constellation::Virgo,
+        Constellation::Libra,
+        Constellation::Scorpio,
+        Constellation::Sagittarius,
+        Constellation::Capricorn,
+        Constellation::Aquarius,
+        Constellation::Pisces,
+    ];
+
+    /// Returns the constellation by the month and the day, `None` if the month
+    /// is not between 1 and 12.
+    pub fn from_date(month: u32, day: u32) -> Option<Constellation> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        // The constellation beginning in the month, Aries begins in March.
+        let i = (month as usize + 9) % 12;
+        let (_, _, start) = CONSTELLATIONS[i];
+        if (month, day) >= start {
+            Some(Constellation::ALL[i])
+        } else {
+            Some(Constellation::ALL[(i + 11) % 12])
+        }
+    }
+
+    /// Returns the constellation by the Chinese name, e.g. `白羊座`. The
+    /// variant spelling `魔羯座` is accepted for `摩羯座`.
+    pub fn from_name(name: &str) -> Option<Constellation> {
+        let name = if name == "魔羯座" {
+            "摩羯座"
+        } else {
+            name
+        };
+        CONSTELLATIONS
+            .iter()
+            .position(|c| c.0 == name)
+            .map(|i| Constellation::ALL[i])
+    }
+
+    /// Returns the Chinese name, e.g. `白羊座`.
+    pub fn name_zh(self) -> &'static str {
+        CONSTELLATIONS[self as usize].0
+    }
+
+    /// Returns the English name, e.g. `Aries`.
+    pub fn name_en(self) -> &'static str {
+        CONSTELLATIONS[self as usize].1
+    }
+}
+
+impl fmt::Display for Constellation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_zh())
+    }
+}
+
+/// The twelve Chinese Zodiac animals, in the order of the cycle starting from
+/// the Rat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Zodiac {
+    /// 鼠
+    Rat,
+    /// 牛
+    Ox,
+    /// 虎
+    Tiger,
+    /// 兔
+    Rabbit,
+    /// 龙
+    Dragon,
+    /// 蛇
+    Snake,
+    /// 马
+    Horse,
+    /// 羊
+    Goat,
+    /// 猴
+    Monkey,
+    /// 鸡
+    Rooster,
+    /// 狗
+    Dog,
+    /// 猪
+    Pig,
+}
+
+/// The Chinese and English names of the animals, in the order of the variants.
+static ZODIACS: [(&str, &str); 12] = [
+    ("鼠", "Rat"),
+    ("牛", "Ox"),
+    ("虎", "Tiger"),
+    ("兔", "Rabbit"),
+    ("龙", "Dragon"),
+    ("蛇", "Snake"),
+    ("马", "Horse"),
+    ("羊", "Goat"),
+    ("猴", "Monkey"),
+    ("鸡", "Rooster"),
+    ("狗", "Dog"),
+    ("猪", "Pig"),
+];
+
+impl Zodiac {
+    /// All the animals, in the order of the cycle.
+    pub const ALL: [Zodiac; 12] = [
+        Zodiac::Rat,
+        Zodiac::Ox,
+        Zodiac::Tiger,
+        Zodiac::Rabbit,
+        Zodiac::Dragon,
+        Zodiac::Snake,
+        Zodiac::Horse,
+        Zodiac::Goat,
+        Zodiac::Monkey,
+        Zodiac::Rooster,
+        Zodiac::Dog,
+        Zodiac::Pig,
+    ];
+
+    /// Returns the animal of the year, the year 4 is the Rat. Note that the
+    /// year is reckoned from January 1st here, see also `chinese_zodiac_on`.
+    pub fn from_year(year: i32) -> Zodiac {
+        Zodiac::ALL[(year - 4).rem_euclid(12) as usize]
+    }
+
+    /// Returns the Chinese name, e.g. `鼠`.
+    pub fn name_zh(self) -> &'static str {
+        ZODIACS[self as usize].0
+    }
+
+    /// Returns the English name, e.g. `Rat`.
+    pub fn name_en(self) -> &'static str {
+        ZODIACS[self as usize].1
+    }
+}
+
+impl fmt::Display for Zodiac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_zh())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Locale;
+
+    #[test]
+    fn test_constellation() {
+        assert_eq!(Constellation::from_date(3, 21), Some(Constellation::Aries));
+        assert_eq!(Constellation::from_date(3, 20), Some(Constellation::Pisces));
+        assert_eq!(
+            Constellation::from_date(1, 1),
+            Some(Constellation::Capricorn)
+        );
+        assert_eq!(
+            Constellation::from_date(12, 31),
+            Some(Constellation::Capricorn)
+        );
+        assert_eq!(
+            Constellation::from_date(1, 20),
+            Some(Constellation::Aquarius)
+        );
+        assert_eq!(Constellation::from_date(0, 1), None);
+        assert_eq!(Constellation::from_date(13, 1), None);
+        assert_eq!(Constellation::Libra.name_zh(), "天秤座");
+        assert_eq!(Constellation::Libra.name_en(), "Libra");
+        assert_eq!(Constellation::Libra.to_string(), "天秤座");
+        assert_eq!(Constellation::Capricorn.name_zh(), "摩羯座");
+        assert_eq!(
+            Constellation::from_name("魔羯座"),
+            Some(Constellation::Capricorn)
+        );
+        assert_eq!(Constellation::from_name("火星"), None);
+        assert!(Constellation::Aries < Constellation::Pisces);
+        for (i, &c) in Constellation::ALL.iter().enumerate() {
+            assert_eq!(c as usize, i);
+            assert_eq!(Constellation::from_name(c.name_zh()), Some(c));
+            let translated = Locale::En.translate(c.name_zh());
+            assert_eq!(translated.as_deref(), Some(c.name_en()));
+        }
+    }
+
+    #[test]
+    fn test_zodiac() {
+        assert_eq!(Zodiac::from_year(1984), Zodiac::Rat);
+        assert_eq!(Zodiac::from_year(2021), Zodiac::Ox);
+        assert_eq!(Zodiac::from_year(3), Zodiac::Pig);
+        assert_eq!(Zodiac::from_year(-8), Zodiac::Rat);
+        assert_eq!(Zodiac::Goat.name_zh(), "羊");
+        assert_eq!(Zodiac::Goat.name_en(), "Goat");
+        assert_eq!(Zodiac::Dragon.to_string(), "龙");
+        assert!(Zodiac::Rat < Zodiac::Pig);
+        for (i, &z) in Zodiac::ALL.iter().enumerate() {
+            assert_eq!(z as usize, i);
+            assert_eq!(
+                Locale::En.translate(z.name_zh()).as_deref(),
+                Some(z.name_en())
+            );
+        }
+    }
+}